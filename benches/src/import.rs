@@ -49,6 +49,7 @@ pub fn provision_import_test(
     let shared_notify = Arc::new(Notify::new());
     let params = Config {
         header_batch_size: header_batch_size as usize,
+        max_parallel_peers: 1,
         block_stream_buffer_size,
     };
     let p2p = Arc::new(PressurePeerToPeer::new(
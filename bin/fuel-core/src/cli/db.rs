@@ -0,0 +1,94 @@
+use crate::cli::DEFAULT_DB_PATH;
+use clap::{
+    Parser,
+    Subcommand,
+};
+use std::path::PathBuf;
+
+/// Manage the database of the node.
+#[derive(Debug, Clone, Parser)]
+pub struct Command {
+    /// The path to the database.
+    #[clap(
+        name = "DB_PATH",
+        long = "db-path",
+        value_parser,
+        default_value = (*DEFAULT_DB_PATH).to_str().unwrap()
+    )]
+    database_path: PathBuf,
+
+    /// The sub-command of the database operation.
+    #[command(subcommand)]
+    subcommand: SubCommands,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum SubCommands {
+    /// Migrates the database to the schema version of this build.
+    /// The migrations also run automatically when the node starts.
+    Migrate {
+        /// Reports the migrations that would run without applying them.
+        #[clap(long = "dry-run")]
+        dry_run: bool,
+    },
+}
+
+#[cfg(not(any(feature = "rocksdb", feature = "rocksdb-production")))]
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    Err(anyhow::anyhow!(
+        "Rocksdb must be enabled to use the database at {}",
+        command.database_path.display()
+    ))
+}
+
+#[cfg(any(feature = "rocksdb", feature = "rocksdb-production"))]
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    use anyhow::Context;
+    use fuel_core::database::{
+        migration::{
+            latest_version,
+            MIGRATIONS,
+        },
+        Database,
+    };
+    let path = command.database_path;
+    let data_source = fuel_core::state::rocks_db::RocksDb::default_open(&path, None)
+        .map_err(Into::<anyhow::Error>::into)
+        .context(format!(
+            "failed to open database at path {}",
+            path.display()
+        ))?;
+    let mut db = Database::new(std::sync::Arc::new(data_source));
+
+    match command.subcommand {
+        SubCommands::Migrate { dry_run } => {
+            let version = match db.schema_version()? {
+                Some(version) => version,
+                None => {
+                    println!("The database is not initialized");
+                    return Ok(())
+                }
+            };
+            let pending = db.pending_migrations(MIGRATIONS)?;
+            if pending.is_empty() {
+                println!("The database schema is up to date at the version {version}");
+                return Ok(())
+            }
+
+            println!(
+                "The database schema version is {version}, the latest version is {}",
+                latest_version(MIGRATIONS)
+            );
+            for migration in &pending {
+                println!("  {}: {}", migration.version, migration.description);
+            }
+
+            if !dry_run {
+                db.migrate(MIGRATIONS)
+                    .context("failed to migrate the database")?;
+                println!("Applied {} migrations", pending.len());
+            }
+        }
+    }
+    Ok(())
+}
@@ -25,7 +25,10 @@ use fuel_core::{
         ServiceTrait,
         VMConfig,
     },
-    txpool::Config as TxPoolConfig,
+    txpool::{
+        config::JournalConfig,
+        Config as TxPoolConfig,
+    },
     types::{
         blockchain::primitives::SecretKeyWrapper,
         fuel_tx::ContractId,
@@ -113,6 +116,21 @@ pub struct Command {
     )]
     pub chain_config: String,
 
+    /// The path to the genesis state written by `snapshot everything --state-file`.
+    /// If set, it replaces the initial state of the chain config.
+    #[arg(long = "genesis-state", value_parser, env)]
+    pub genesis_state: Option<PathBuf>,
+
+    /// Enables the pruning mode: only the given number of the latest blocks keep
+    /// transactions, receipts and statuses. Block headers and the state are never pruned.
+    #[arg(long = "pruning-depth", env)]
+    pub pruning_depth: Option<u32>,
+
+    /// Records the history of the state modified by each block. It allows creating
+    /// snapshots of the state at historical heights, but requires additional storage.
+    #[arg(long = "state-history", env)]
+    pub state_history: bool,
+
     /// Should be used for local development only. Enabling debug mode:
     /// - Allows GraphQL Endpoints to arbitrarily advance blocks.
     /// - Enables debugger GraphQL Endpoints.
@@ -190,6 +208,34 @@ pub struct Command {
     #[clap(long = "tx-number-active-subscriptions", default_value = "4064", env)]
     pub tx_number_active_subscriptions: usize,
 
+    /// The minimum increase of the gas price, in percent, required to replace
+    /// a conflicting transaction inside of the `TxPool`.
+    #[clap(long = "tx-replacement-price-bump-percent", default_value = "10", env)]
+    pub tx_replacement_price_bump_percent: u64,
+
+    /// The max number of transactions of a single owner inside of the `TxPool`.
+    #[clap(long = "tx-max-number-per-owner", default_value = "1024", env)]
+    pub tx_max_number_per_owner: usize,
+
+    /// The max sum of the max gas of the transactions of a single owner inside of
+    /// the `TxPool`. By default, it is ten times the block gas limit.
+    #[clap(long = "tx-max-gas-per-owner", env)]
+    pub tx_max_gas_per_owner: Option<u64>,
+
+    /// The path to the journal of the pending transactions of the `TxPool`.
+    /// If set, the pending transactions are restored after the restart of the node.
+    #[clap(long = "tx-pool-journal", value_parser, env)]
+    pub tx_pool_journal: Option<PathBuf>,
+
+    /// Transactions older than this are not restored from the journal.
+    /// By default, it is equal to `tx-pool-ttl`.
+    #[clap(long = "tx-pool-journal-max-age", env)]
+    pub tx_pool_journal_max_age: Option<humantime::Duration>,
+
+    /// How often the pending transactions are written to the journal.
+    #[clap(long = "tx-pool-journal-interval", default_value = "1m", env)]
+    pub tx_pool_journal_interval: humantime::Duration,
+
     /// The number of reserved peers to connect to before starting to sync.
     #[clap(long = "min-connected-reserved-peers", default_value = "0", env)]
     pub min_connected_reserved_peers: usize,
@@ -220,6 +266,9 @@ impl Command {
             database_path,
             database_type,
             chain_config,
+            genesis_state,
+            pruning_depth,
+            state_history,
             vm_backtrace,
             debug,
             utxo_validation,
@@ -241,6 +290,12 @@ impl Command {
             tx_max_number,
             tx_max_depth,
             tx_number_active_subscriptions,
+            tx_replacement_price_bump_percent,
+            tx_max_number_per_owner,
+            tx_max_gas_per_owner,
+            tx_pool_journal,
+            tx_pool_journal_max_age,
+            tx_pool_journal_interval,
             min_connected_reserved_peers,
             time_until_synced,
             query_log_threshold_time,
@@ -251,6 +306,13 @@ impl Command {
         let addr = net::SocketAddr::new(ip, port);
 
         let chain_conf: ChainConfig = chain_config.as_str().parse()?;
+        let tx_max_gas_per_owner = tx_max_gas_per_owner
+            .unwrap_or_else(|| chain_conf.block_gas_limit.saturating_mul(10));
+        let tx_pool_journal = tx_pool_journal.map(|path| JournalConfig {
+            path,
+            max_age: tx_pool_journal_max_age.unwrap_or(tx_pool_ttl).into(),
+            flush_interval: tx_pool_journal_interval.into(),
+        });
 
         #[cfg(feature = "relayer")]
         let relayer_cfg = relayer_args.into_config();
@@ -300,8 +362,10 @@ impl Command {
             max_wait_time: max_wait_time.into(),
         };
 
-        let block_importer =
+        let mut block_importer =
             fuel_core::service::config::fuel_core_importer::Config::new(&chain_conf);
+        block_importer.state_history = state_history;
+        block_importer.pruning_depth = pruning_depth;
 
         let config = Config {
             addr,
@@ -310,6 +374,7 @@ impl Command {
             database_path,
             database_type,
             chain_conf: chain_conf.clone(),
+            genesis_state,
             debug,
             utxo_validation,
             block_production: trigger,
@@ -325,6 +390,10 @@ impl Command {
                 metrics,
                 tx_pool_ttl.into(),
                 tx_number_active_subscriptions,
+                tx_replacement_price_bump_percent,
+                tx_max_number_per_owner,
+                tx_max_gas_per_owner,
+                tx_pool_journal,
             ),
             block_producer: ProducerConfig {
                 utxo_validation,
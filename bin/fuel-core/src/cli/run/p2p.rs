@@ -9,9 +9,15 @@ use fuel_core::{
             convert_to_libp2p_keypair,
             Config,
             NotInitialized,
+            MAX_REQUESTS_PER_SECOND,
+            MAX_RESPONSE_BYTES_PER_WINDOW,
             MAX_RESPONSE_SIZE,
         },
         gossipsub_config::default_gossipsub_builder,
+        peer_manager::{
+            PeerReportConfig,
+            ReputationConfig,
+        },
         HeartbeatConfig,
         Multiaddr,
     },
@@ -32,6 +38,10 @@ use std::{
 };
 
 const MAX_RESPONSE_SIZE_STR: &str = const_format::formatcp!("{MAX_RESPONSE_SIZE}");
+const MAX_REQUESTS_PER_SECOND_STR: &str =
+    const_format::formatcp!("{MAX_REQUESTS_PER_SECOND}");
+const MAX_RESPONSE_BYTES_PER_WINDOW_STR: &str =
+    const_format::formatcp!("{MAX_RESPONSE_BYTES_PER_WINDOW}");
 
 #[derive(Debug, Clone, Args)]
 pub struct P2PArgs {
@@ -58,6 +68,11 @@ pub struct P2PArgs {
     #[clap(long = "peering-port", default_value = "30333", env)]
     pub peering_port: u16,
 
+    /// p2p network's UDP Port for the QUIC transport.
+    /// If it's not set, the QUIC transport will be disabled.
+    #[clap(long = "quic-port", env)]
+    pub quic_port: Option<u16>,
+
     /// Max Block size
     #[clap(long = "max-block-size", default_value = MAX_RESPONSE_SIZE_STR, env)]
     pub max_block_size: usize,
@@ -66,6 +81,10 @@ pub struct P2PArgs {
     #[clap(long = "max-headers-per-request", default_value = "100", env)]
     pub max_headers_per_request: u32,
 
+    /// Max number of transactions in a single request by transaction ids
+    #[clap(long = "max-txs-per-request", default_value = "10000", env)]
+    pub max_txs_per_request: u32,
+
     /// Addresses of the bootstrap nodes
     /// They should contain PeerId within their `Multiaddr`
     #[clap(long = "bootstrap-nodes", value_delimiter = ',', env)]
@@ -156,6 +175,20 @@ pub struct P2PArgs {
     #[clap(long = "connection-keep-alive", default_value = "20", env)]
     pub connection_keep_alive: u64,
 
+    /// Max number of inbound requests served to a single peer per second.
+    /// The requests above the quota are refused, and the reputation of the peer is lowered.
+    #[clap(long = "max-requests-per-second", default_value = MAX_REQUESTS_PER_SECOND_STR, env)]
+    pub max_requests_per_second: u32,
+
+    /// Max number of bytes of responses served to a single peer within the `response-bytes-window`.
+    /// The requests above the quota are refused, and the reputation of the peer is lowered.
+    #[clap(long = "max-response-bytes-per-window", default_value = MAX_RESPONSE_BYTES_PER_WINDOW_STR, env)]
+    pub max_response_bytes_per_window: u64,
+
+    /// The window of the `max-response-bytes-per-window` quota
+    #[clap(long = "response-bytes-window", default_value = "10s", env)]
+    pub response_bytes_window: humantime::Duration,
+
     /// Sending of `BlockHeight` should not take longer than this duration, in seconds.
     #[clap(long = "heartbeat-send-duration", default_value = "2", env)]
     pub heartbeat_send_duration: u64,
@@ -181,6 +214,113 @@ pub struct P2PArgs {
     /// For peer reputations, the maximum time since last heartbeat before penalty
     #[clap(long = "heartbeat-max-time-since-last", default_value = "40", env)]
     pub heartbeat_max_time_since_last: u64,
+
+    /// For peer reputations, the maximum application score a peer can reach
+    #[clap(long = "reputation-max-app-score", default_value = "150", env)]
+    pub reputation_max_app_score: f64,
+
+    /// For peer reputations, the peer is banned when its application score falls below this threshold
+    #[clap(
+        long = "reputation-min-app-score",
+        default_value = "-50",
+        allow_negative_numbers = true,
+        env
+    )]
+    pub reputation_min_app_score: f64,
+
+    /// For peer reputations, the peer is banned when its gossipsub score falls below this threshold
+    #[clap(
+        long = "reputation-min-gossip-score",
+        default_value = "-16000",
+        allow_negative_numbers = true,
+        env
+    )]
+    pub reputation_min_gossip_score: f64,
+
+    /// For peer reputations, the duration of the automatic bans.
+    /// The bans made with the admin API are permanent unless their duration is set.
+    #[clap(long = "reputation-ban-duration", default_value = "1h", env)]
+    pub reputation_ban_duration: humantime::Duration,
+
+    /// For peer reputations, the factor applied to the scores of non-reserved peers at each decay
+    #[clap(long = "reputation-decay-factor", default_value = "0.9", env)]
+    pub reputation_decay_factor: f64,
+
+    /// For peer reputations, time between the decays of the scores
+    #[clap(long = "reputation-decay-interval", default_value = "1s", env)]
+    pub reputation_decay_interval: humantime::Duration,
+
+    /// For peer reputations, the penalty for the peer that hasn't sent a heartbeat for too long
+    #[clap(
+        long = "reputation-old-heartbeat-penalty",
+        default_value = "-5",
+        allow_negative_numbers = true,
+        env
+    )]
+    pub reputation_old_heartbeat_penalty: f64,
+
+    /// For peer reputations, the penalty for the peer that sends heartbeats too rarely
+    #[clap(
+        long = "reputation-low-heartbeat-frequency-penalty",
+        default_value = "-5",
+        allow_negative_numbers = true,
+        env
+    )]
+    pub reputation_low_heartbeat_frequency_penalty: f64,
+
+    /// For peer reputations, the penalty for the peer that exceeds the quota of the inbound requests
+    #[clap(
+        long = "reputation-request-quota-exceeded-penalty",
+        default_value = "-10",
+        allow_negative_numbers = true,
+        env
+    )]
+    pub reputation_request_quota_exceeded_penalty: f64,
+
+    /// For peer reputations, the score change for the peer that provided an imported block
+    #[clap(
+        long = "reputation-successful-block-import",
+        default_value = "5",
+        allow_negative_numbers = true,
+        env
+    )]
+    pub reputation_successful_block_import: f64,
+
+    /// For peer reputations, the score change for the peer that didn't provide the advertised block headers
+    #[clap(
+        long = "reputation-missing-block-headers",
+        default_value = "-100",
+        allow_negative_numbers = true,
+        env
+    )]
+    pub reputation_missing_block_headers: f64,
+
+    /// For peer reputations, the score change for the peer that provided a bad block header
+    #[clap(
+        long = "reputation-bad-block-header",
+        default_value = "-100",
+        allow_negative_numbers = true,
+        env
+    )]
+    pub reputation_bad_block_header: f64,
+
+    /// For peer reputations, the score change for the peer that didn't provide the advertised transactions
+    #[clap(
+        long = "reputation-missing-transactions",
+        default_value = "-100",
+        allow_negative_numbers = true,
+        env
+    )]
+    pub reputation_missing_transactions: f64,
+
+    /// For peer reputations, the score change for the peer that provided invalid transactions
+    #[clap(
+        long = "reputation-invalid-transactions",
+        default_value = "-100",
+        allow_negative_numbers = true,
+        env
+    )]
+    pub reputation_invalid_transactions: f64,
 }
 
 #[derive(Debug, Clone, Args)]
@@ -191,6 +331,11 @@ pub struct SyncArgs {
     /// The maximum number of headers to request in a single batch.
     #[clap(long = "sync-header-batch-size", default_value = "10", env)]
     pub header_batch_size: u32,
+    /// The maximum number of peers to download the header batches from in parallel.
+    /// The range is split between the peers with good heartbeats, and the failed
+    /// batches are retried on the other peers. `1` disables the parallel download.
+    #[clap(long = "sync-max-parallel-peers", default_value = "1", env)]
+    pub max_parallel_peers: usize,
 }
 
 #[derive(Clone, Debug)]
@@ -223,6 +368,7 @@ impl From<SyncArgs> for fuel_core::sync::Config {
         Self {
             block_stream_buffer_size: value.block_stream_buffer_size,
             header_batch_size: value.header_batch_size as usize,
+            max_parallel_peers: value.max_parallel_peers,
         }
     }
 }
@@ -283,6 +429,27 @@ impl P2PArgs {
             )
         };
 
+        let reputation_config = ReputationConfig {
+            max_app_score: self.reputation_max_app_score,
+            min_app_score: self.reputation_min_app_score,
+            min_gossip_score: self.reputation_min_gossip_score,
+            ban_duration: self.reputation_ban_duration.into(),
+            decay_factor: self.reputation_decay_factor,
+            decay_interval: self.reputation_decay_interval.into(),
+            old_heartbeat_penalty: self.reputation_old_heartbeat_penalty,
+            low_heartbeat_frequency_penalty: self
+                .reputation_low_heartbeat_frequency_penalty,
+            request_quota_exceeded_penalty: self
+                .reputation_request_quota_exceeded_penalty,
+            peer_report_config: PeerReportConfig {
+                successful_block_import: self.reputation_successful_block_import,
+                missing_block_headers: self.reputation_missing_block_headers,
+                bad_block_header: self.reputation_bad_block_header,
+                missing_transactions: self.reputation_missing_transactions,
+                invalid_transactions: self.reputation_invalid_transactions,
+            },
+        };
+
         let config = Config {
             keypair: local_keypair,
             network_name,
@@ -292,8 +459,10 @@ impl P2PArgs {
                 .unwrap_or_else(|| IpAddr::V4(Ipv4Addr::from([0, 0, 0, 0]))),
             public_address: self.public_address,
             tcp_port: self.peering_port,
+            quic_port: self.quic_port,
             max_block_size: self.max_block_size,
             max_headers_per_request: self.max_headers_per_request,
+            max_txs_per_request: self.max_txs_per_request,
             bootstrap_nodes: self.bootstrap_nodes,
             reserved_nodes: self.reserved_nodes,
             reserved_nodes_only_mode: self.reserved_nodes_only_mode,
@@ -309,6 +478,9 @@ impl P2PArgs {
             heartbeat_config,
             set_request_timeout: Duration::from_secs(self.request_timeout),
             set_connection_keep_alive: Duration::from_secs(self.connection_keep_alive),
+            max_requests_per_second: self.max_requests_per_second,
+            max_response_bytes_per_window: self.max_response_bytes_per_window,
+            response_bytes_window: self.response_bytes_window.into(),
             heartbeat_check_interval: Duration::from_secs(self.heartbeat_check_interval),
             heartbeat_max_avg_interval: Duration::from_secs(
                 self.heartbeat_max_avg_interval,
@@ -318,6 +490,7 @@ impl P2PArgs {
             ),
             info_interval: Some(Duration::from_secs(self.info_interval)),
             identify_interval: Some(Duration::from_secs(self.identify_interval)),
+            reputation_config,
             metrics,
             state: NotInitialized,
         };
@@ -3,7 +3,12 @@ use clap::{
     Parser,
     Subcommand,
 };
-use fuel_core::types::fuel_types::ContractId;
+use fuel_core::types::fuel_types::{
+    Address,
+    AssetId,
+    Bytes32,
+    ContractId,
+};
 use std::path::PathBuf;
 
 /// Print a snapshot of blockchain state to stdout.
@@ -31,6 +36,41 @@ pub enum SubCommands {
         /// Specify either an alias to a built-in configuration or filepath to a JSON file.
         #[clap(name = "CHAIN_CONFIG", long = "chain", default_value = "local_testnet")]
         chain_config: String,
+        /// Writes the state into the file in the streaming format instead of
+        /// embedding it into the chain config. It keeps the memory usage bounded
+        /// for large states. The file can be used with `run --genesis-state`.
+        #[clap(long = "state-file")]
+        state_file: Option<PathBuf>,
+        /// Creates a snapshot of the state at the historical height instead of the latest one.
+        /// Requires the node to run with `--state-history`, the history starts from
+        /// the first block executed with it.
+        #[clap(long = "height")]
+        height: Option<u32>,
+    },
+    /// Creates a partial snapshot of the state with the entries matching all specified filters
+    /// and prints it to stdout. A filter excludes the entries it is not applicable to:
+    /// owners and assets select coins and messages, contracts and contract roots select
+    /// contracts, and the DA heights select messages.
+    #[command(arg_required_else_help = true)]
+    Filtered {
+        /// Selects coins owned by and messages sent to the address. Can be repeated.
+        #[clap(long = "owner")]
+        owners: Vec<Address>,
+        /// Selects coins of the asset. Can be repeated.
+        #[clap(long = "asset")]
+        assets: Vec<AssetId>,
+        /// Selects the contract. Can be repeated.
+        #[clap(long = "contract")]
+        contracts: Vec<ContractId>,
+        /// Selects contracts with the bytecode root. Can be repeated.
+        #[clap(long = "contract-root")]
+        contract_roots: Vec<Bytes32>,
+        /// Selects messages relayed at or above the DA height.
+        #[clap(long = "da-height-from")]
+        da_height_from: Option<u64>,
+        /// Selects messages relayed at or below the DA height.
+        #[clap(long = "da-height-to")]
+        da_height_to: Option<u64>,
     },
     /// Creates a config for the contract.
     #[command(arg_required_else_help = true)]
@@ -56,6 +96,7 @@ pub async fn exec(command: Command) -> anyhow::Result<()> {
         chain_config::{
             ChainConfig,
             StateConfig,
+            StateFilter,
         },
         database::Database,
     };
@@ -69,9 +110,22 @@ pub async fn exec(command: Command) -> anyhow::Result<()> {
     let db = Database::new(std::sync::Arc::new(data_source));
 
     match command.subcommand {
-        SubCommands::Everything { chain_config } => {
+        SubCommands::Everything {
+            chain_config,
+            state_file,
+            height,
+        } => {
             let config: ChainConfig = chain_config.parse()?;
-            let state_conf = StateConfig::generate_state_config(db)?;
+            let state_conf = match height {
+                Some(height) => {
+                    let state = db
+                        .state_at_height(height.into())
+                        .map_err(Into::<anyhow::Error>::into)
+                        .context(format!("failed to restore the state at {height}"))?;
+                    generate_state(state, state_file)?
+                }
+                None => generate_state(db, state_file)?,
+            };
 
             let chain_conf = ChainConfig {
                 initial_state: Some(state_conf),
@@ -83,6 +137,34 @@ pub async fn exec(command: Command) -> anyhow::Result<()> {
             serde_json::to_writer_pretty(stdout, &chain_conf)
                 .context("failed to dump snapshot to JSON")?;
         }
+        SubCommands::Filtered {
+            owners,
+            assets,
+            contracts,
+            contract_roots,
+            da_height_from,
+            da_height_to,
+        } => {
+            let da_heights = if da_height_from.is_some() || da_height_to.is_some() {
+                let from = da_height_from.unwrap_or(u64::MIN);
+                let to = da_height_to.unwrap_or(u64::MAX);
+                Some(from.into()..=to.into())
+            } else {
+                None
+            };
+            let filter = StateFilter {
+                owners: (!owners.is_empty()).then_some(owners),
+                assets: (!assets.is_empty()).then_some(assets),
+                contracts: (!contracts.is_empty()).then_some(contracts),
+                contract_roots: (!contract_roots.is_empty()).then_some(contract_roots),
+                da_heights,
+            };
+            let state_conf = StateConfig::generate_filtered_state_config(db, &filter)?;
+            let stdout = std::io::stdout().lock();
+
+            serde_json::to_writer_pretty(stdout, &state_conf)
+                .context("failed to dump filtered snapshot to JSON")?;
+        }
         SubCommands::Contract { contract_id } => {
             let config = db.get_contract_config_by_id(contract_id)?;
             let stdout = std::io::stdout().lock();
@@ -93,3 +175,39 @@ pub async fn exec(command: Command) -> anyhow::Result<()> {
     }
     Ok(())
 }
+
+#[cfg(any(feature = "rocksdb", feature = "rocksdb-production"))]
+fn generate_state<T>(
+    db: T,
+    state_file: Option<PathBuf>,
+) -> anyhow::Result<fuel_core::chain_config::StateConfig>
+where
+    T: fuel_core::chain_config::ChainConfigDb,
+{
+    use anyhow::Context;
+    use fuel_core::chain_config::{
+        StateConfig,
+        StateWriter,
+    };
+
+    let state_conf = match state_file {
+        Some(state_file) => {
+            let file = std::fs::File::create(&state_file).context(format!(
+                "failed to create the state file {}",
+                state_file.display()
+            ))?;
+            let mut writer = StateWriter::new(std::io::BufWriter::new(file));
+            let height = StateConfig::write_state_config(&db, &mut writer)
+                .context("failed to write the state file")?;
+            writer.finish()?;
+
+            // The chain config keeps only the height of the state.
+            StateConfig {
+                height: Some(height),
+                ..Default::default()
+            }
+        }
+        None => StateConfig::generate_state_config(db)?,
+    };
+    Ok(state_conf)
+}
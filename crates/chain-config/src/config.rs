@@ -2,6 +2,7 @@ mod chain;
 mod coin;
 mod consensus;
 mod contract;
+mod filter;
 mod message;
 mod state;
 
@@ -9,6 +10,7 @@ pub use chain::*;
 pub use coin::*;
 pub use consensus::*;
 pub use contract::*;
+pub use filter::*;
 pub use message::*;
 pub use state::*;
 
@@ -40,10 +42,16 @@ mod tests {
         path::PathBuf,
     };
 
+    #[cfg(feature = "std")]
+    use super::state::{
+        StateReader,
+        StateWriter,
+    };
     use super::{
         chain::ChainConfig,
         coin::CoinConfig,
         contract::ContractConfig,
+        filter::StateFilter,
         message::MessageConfig,
         state::StateConfig,
     };
@@ -221,6 +229,100 @@ mod tests {
         assert_eq!(config, deserialized_config);
     }
 
+    #[cfg(feature = "std")]
+    #[test]
+    fn can_roundtrip_streaming_state() {
+        let state = StateConfig {
+            coins: test_config_coin_state().initial_state.unwrap().coins,
+            contracts: test_config_contract(true, true, true, true)
+                .initial_state
+                .unwrap()
+                .contracts,
+            messages: test_message_config().initial_state.unwrap().messages,
+            height: None,
+        };
+
+        let mut writer = StateWriter::new(vec![]);
+        for entry in state.entries() {
+            writer.write(&entry).unwrap();
+        }
+        let bytes = writer.finish().unwrap();
+
+        // Each entry is written on its own line, including the contract's state and balance.
+        assert_eq!(bytes.iter().filter(|byte| **byte == b'\n').count(), 5);
+        let entries = StateReader::new(bytes.as_slice())
+            .collect::<anyhow::Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(entries, state.entries().collect::<Vec<_>>());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn streaming_state_reader_fails_on_malformed_entry() {
+        let bytes = b"{\"unknown\":{}}\n";
+
+        let result = StateReader::new(bytes.as_slice()).next().unwrap();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn state_filter_selects_only_applicable_entries() {
+        let coin = test_config_coin_state()
+            .initial_state
+            .unwrap()
+            .coins
+            .unwrap()
+            .remove(0);
+        let contract = test_config_contract(false, false, false, false)
+            .initial_state
+            .unwrap()
+            .contracts
+            .unwrap()
+            .remove(0);
+        let message = test_message_config()
+            .initial_state
+            .unwrap()
+            .messages
+            .unwrap()
+            .remove(0);
+
+        let everything = StateFilter::default();
+        assert!(everything.matches_coin(&coin));
+        assert!(everything.matches_contract(&contract));
+        assert!(everything.matches_message(&message));
+
+        let by_owner = StateFilter {
+            owners: Some(vec![coin.owner, message.recipient]),
+            ..Default::default()
+        };
+        assert!(by_owner.matches_coin(&coin));
+        assert!(!by_owner.matches_contract(&contract));
+        assert!(by_owner.matches_message(&message));
+
+        let by_root = StateFilter {
+            contract_roots: Some(vec![Contract::root_from_code(&contract.code)]),
+            ..Default::default()
+        };
+        assert!(!by_root.matches_coin(&coin));
+        assert!(by_root.matches_contract(&contract));
+        assert!(!by_root.matches_message(&message));
+
+        let at_da_height = StateFilter {
+            da_heights: Some(message.da_height..=message.da_height),
+            ..Default::default()
+        };
+        assert!(at_da_height.matches_message(&message));
+        let above_da_height = StateFilter {
+            da_heights: Some(
+                DaBlockHeight(message.da_height.0.saturating_add(1))
+                    ..=DaBlockHeight(u64::MAX),
+            ),
+            ..Default::default()
+        };
+        assert!(!above_da_height.matches_message(&message));
+    }
+
     fn test_config_contract(
         state: bool,
         balances: bool,
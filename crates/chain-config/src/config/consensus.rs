@@ -1,17 +1,34 @@
 use fuel_core_types::{
     fuel_tx::Input,
-    fuel_types::Address,
+    fuel_types::{
+        Address,
+        BlockHeight,
+    },
+    tai64::Tai64,
 };
 use serde::{
     Deserialize,
     Serialize,
 };
+use std::num::{
+    NonZeroU32,
+    NonZeroU64,
+};
 
 use crate::default_consensus_dev_key;
 
 #[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 pub enum ConsensusConfig {
-    PoA { signing_key: Address },
+    PoA {
+        signing_key: Address,
+    },
+    /// The PoA with several authorized producers that take turns producing blocks.
+    PoARoundRobin(ProducerSchedule),
+    /// The BFT consensus where the set of `validators` commits blocks by
+    /// the quorum of votes.
+    Bft {
+        validators: Vec<Address>,
+    },
 }
 
 impl ConsensusConfig {
@@ -20,4 +37,179 @@ impl ConsensusConfig {
             signing_key: Input::owner(&default_consensus_dev_key().public_key()),
         }
     }
+
+    /// Returns the producer that is allowed to sign the block with the `height` and `time`.
+    /// Returns `None` if nobody is allowed to sign it, as in the case of BFT consensus
+    /// where blocks are committed by the quorum of validators.
+    pub fn scheduled_producer(
+        &self,
+        height: BlockHeight,
+        time: Tai64,
+    ) -> Option<&Address> {
+        match self {
+            ConsensusConfig::PoA { signing_key } => Some(signing_key),
+            ConsensusConfig::PoARoundRobin(schedule) => {
+                schedule.scheduled_producer(height, time)
+            }
+            ConsensusConfig::Bft { .. } => None,
+        }
+    }
+}
+
+/// The ordered set of authorized block producers and the way how slots
+/// are assigned to them.
+#[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+pub struct ProducerSchedule {
+    /// The authorized producers in the order of their slots.
+    pub producers: Vec<Address>,
+    pub slot: SlotAssignment,
+}
+
+/// Defines what is the slot of the producer.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, Eq, PartialEq)]
+pub enum SlotAssignment {
+    /// The slot is `blocks_per_slot` consecutive block heights.
+    Height { blocks_per_slot: NonZeroU32 },
+    /// The slot is `slot_seconds` seconds of the block time.
+    Time { slot_seconds: NonZeroU64 },
+}
+
+impl ProducerSchedule {
+    /// Returns the producer of the slot that contains the block with the `height` and `time`.
+    pub fn scheduled_producer(
+        &self,
+        height: BlockHeight,
+        time: Tai64,
+    ) -> Option<&Address> {
+        let slot = match self.slot {
+            SlotAssignment::Height { blocks_per_slot } => {
+                u64::from(height.checked_div(blocks_per_slot.get())?)
+            }
+            SlotAssignment::Time { slot_seconds } => {
+                time.0.checked_div(slot_seconds.get())?
+            }
+        };
+        let producers = u64::try_from(self.producers.len()).ok()?;
+        let index = slot.checked_rem(producers)?;
+        self.producers.get(usize::try_from(index).ok()?)
+    }
+
+    /// Returns `true` if the slots are assigned by the block time. The producer
+    /// chooses the time of its block, so the time has to be verified separately.
+    pub fn is_time_slotted(&self) -> bool {
+        matches!(self.slot, SlotAssignment::Time { .. })
+    }
+
+    /// Returns the earliest time at or after the `time` that is in the slot of
+    /// the `producer`. Returns `None` if the slots are not assigned by the block time.
+    pub fn next_slot_time(&self, producer: &Address, time: Tai64) -> Option<Tai64> {
+        let slot_seconds = match self.slot {
+            SlotAssignment::Height { .. } => return None,
+            SlotAssignment::Time { slot_seconds } => slot_seconds.get(),
+        };
+        let current_slot = time.0.checked_div(slot_seconds)?;
+        let producers = u64::try_from(self.producers.len()).ok()?;
+        (0..producers).find_map(|offset| {
+            let slot = current_slot.checked_add(offset)?;
+            let index = usize::try_from(slot.checked_rem(producers)?).ok()?;
+            (self.producers.get(index)? == producer)
+                .then(|| Tai64(slot.saturating_mul(slot_seconds).max(time.0)))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schedule(slot: SlotAssignment) -> ProducerSchedule {
+        ProducerSchedule {
+            producers: vec![
+                Address::from([1; 32]),
+                Address::from([2; 32]),
+                Address::from([3; 32]),
+            ],
+            slot,
+        }
+    }
+
+    #[test]
+    fn height_slots_rotate_producers() {
+        let schedule = schedule(SlotAssignment::Height {
+            blocks_per_slot: NonZeroU32::new(2).unwrap(),
+        });
+        let producers: Vec<_> = (0u32..8)
+            .map(|height| {
+                *schedule
+                    .scheduled_producer(height.into(), Tai64::UNIX_EPOCH)
+                    .unwrap()
+            })
+            .collect();
+
+        let [a, b, c] = [1, 2, 3].map(|i| Address::from([i; 32]));
+        assert_eq!(producers, vec![a, a, b, b, c, c, a, a]);
+    }
+
+    #[test]
+    fn time_slots_rotate_producers() {
+        let schedule = schedule(SlotAssignment::Time {
+            slot_seconds: NonZeroU64::new(10).unwrap(),
+        });
+        let producer = |time: u64| {
+            *schedule
+                .scheduled_producer(1u32.into(), Tai64(time))
+                .unwrap()
+        };
+
+        assert_eq!(producer(0), Address::from([1; 32]));
+        assert_eq!(producer(9), Address::from([1; 32]));
+        assert_eq!(producer(10), Address::from([2; 32]));
+        assert_eq!(producer(25), Address::from([3; 32]));
+        assert_eq!(producer(30), Address::from([1; 32]));
+    }
+
+    #[test]
+    fn next_slot_time_is_the_start_of_the_next_own_slot() {
+        let schedule = schedule(SlotAssignment::Time {
+            slot_seconds: NonZeroU64::new(10).unwrap(),
+        });
+        let [a, b, c] = [1, 2, 3].map(|i| Address::from([i; 32]));
+
+        // the time in the own slot is returned as is
+        assert_eq!(schedule.next_slot_time(&a, Tai64(5)), Some(Tai64(5)));
+        assert_eq!(schedule.next_slot_time(&b, Tai64(5)), Some(Tai64(10)));
+        assert_eq!(schedule.next_slot_time(&c, Tai64(5)), Some(Tai64(20)));
+        assert_eq!(schedule.next_slot_time(&a, Tai64(15)), Some(Tai64(30)));
+        assert_eq!(
+            schedule.next_slot_time(&Address::from([4; 32]), Tai64(5)),
+            None
+        );
+    }
+
+    #[test]
+    fn height_slots_have_no_slot_time() {
+        let schedule = schedule(SlotAssignment::Height {
+            blocks_per_slot: NonZeroU32::new(2).unwrap(),
+        });
+
+        assert_eq!(
+            schedule.next_slot_time(&Address::from([1; 32]), Tai64(5)),
+            None
+        );
+    }
+
+    #[test]
+    fn empty_schedule_has_no_producers() {
+        let schedule = ProducerSchedule {
+            producers: vec![],
+            slot: SlotAssignment::Height {
+                blocks_per_slot: NonZeroU32::new(1).unwrap(),
+            },
+        };
+
+        assert_eq!(
+            schedule.scheduled_producer(1u32.into(), Tai64::UNIX_EPOCH),
+            None
+        );
+    }
 }
@@ -82,3 +82,30 @@ impl ContractConfig {
         self.contract_id = contract_id;
     }
 }
+
+/// The entry of the contract's storage. In the streaming format of the state,
+/// the storage is written separately from the [`ContractConfig`],
+/// so the large contracts are never loaded into memory at once.
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+pub struct ContractStateConfig {
+    #[serde_as(as = "HexType")]
+    pub contract_id: ContractId,
+    #[serde_as(as = "HexType")]
+    pub key: Bytes32,
+    #[serde_as(as = "HexType")]
+    pub value: Bytes32,
+}
+
+/// The balance of the contract. In the streaming format of the state,
+/// the balances are written separately from the [`ContractConfig`].
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+pub struct ContractBalanceConfig {
+    #[serde_as(as = "HexType")]
+    pub contract_id: ContractId,
+    #[serde_as(as = "HexType")]
+    pub asset_id: AssetId,
+    #[serde_as(as = "HexNumber")]
+    pub amount: u64,
+}
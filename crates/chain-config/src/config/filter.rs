@@ -0,0 +1,87 @@
+use super::{
+    coin::CoinConfig,
+    contract::ContractConfig,
+    message::MessageConfig,
+};
+use core::ops::RangeInclusive;
+use fuel_core_types::{
+    blockchain::primitives::DaBlockHeight,
+    fuel_tx::Contract,
+    fuel_types::{
+        Address,
+        AssetId,
+        Bytes32,
+        ContractId,
+    },
+};
+
+/// Selects the entries of the state for the partial snapshot.
+///
+/// The entry is selected if it matches all specified filters. A filter that is not
+/// applicable to the kind of the entry excludes it. For example, the owner filter
+/// selects only coins and messages, while the DA height filter selects only messages.
+/// The empty filter selects the whole state.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StateFilter {
+    /// The owners of the coins and the recipients of the messages.
+    pub owners: Option<Vec<Address>>,
+    /// The assets of the coins.
+    pub assets: Option<Vec<AssetId>>,
+    /// The ids of the contracts.
+    pub contracts: Option<Vec<ContractId>>,
+    /// The roots of the contracts' bytecode.
+    pub contract_roots: Option<Vec<Bytes32>>,
+    /// The range of the DA heights of the messages.
+    pub da_heights: Option<RangeInclusive<DaBlockHeight>>,
+}
+
+impl StateFilter {
+    /// Returns `true` if the filter can select coins.
+    pub fn selects_coins(&self) -> bool {
+        self.contracts.is_none()
+            && self.contract_roots.is_none()
+            && self.da_heights.is_none()
+    }
+
+    /// Returns `true` if the filter can select contracts.
+    pub fn selects_contracts(&self) -> bool {
+        self.owners.is_none() && self.assets.is_none() && self.da_heights.is_none()
+    }
+
+    /// Returns `true` if the filter can select messages.
+    pub fn selects_messages(&self) -> bool {
+        self.assets.is_none() && self.contracts.is_none() && self.contract_roots.is_none()
+    }
+
+    pub fn matches_coin(&self, coin: &CoinConfig) -> bool {
+        self.selects_coins()
+            && contains(&self.owners, &coin.owner)
+            && contains(&self.assets, &coin.asset_id)
+    }
+
+    pub fn matches_contract(&self, contract: &ContractConfig) -> bool {
+        self.selects_contracts()
+            && contains(&self.contracts, &contract.contract_id)
+            && self.contract_roots.as_ref().map_or(true, |roots| {
+                roots.contains(&Contract::root_from_code(&contract.code))
+            })
+    }
+
+    pub fn matches_message(&self, message: &MessageConfig) -> bool {
+        self.selects_messages()
+            && contains(&self.owners, &message.recipient)
+            && self
+                .da_heights
+                .as_ref()
+                .map_or(true, |range| range.contains(&message.da_height))
+    }
+}
+
+fn contains<T>(filter: &Option<Vec<T>>, value: &T) -> bool
+where
+    T: PartialEq,
+{
+    filter
+        .as_ref()
+        .map_or(true, |values| values.contains(value))
+}
@@ -1,7 +1,14 @@
 use crate::serialization::HexNumber;
 
-use fuel_core_storage::Result as StorageResult;
-use fuel_core_types::fuel_types::BlockHeight;
+use fuel_core_storage::{
+    iter::BoxedIter,
+    Result as StorageResult,
+};
+use fuel_core_types::fuel_types::{
+    BlockHeight,
+    ContractId,
+};
+use itertools::Itertools;
 
 use serde::{
     Deserialize,
@@ -11,14 +18,26 @@ use serde_with::{
     serde_as,
     skip_serializing_none,
 };
+use std::collections::BTreeMap;
+#[cfg(feature = "std")]
+use std::io::{
+    Read,
+    Write,
+};
 
 use super::{
     coin::CoinConfig,
-    contract::ContractConfig,
+    contract::{
+        ContractBalanceConfig,
+        ContractConfig,
+        ContractStateConfig,
+    },
+    filter::StateFilter,
     message::MessageConfig,
 };
 
-// TODO: do streaming deserialization to handle large state configs
+/// The state embedded into the chain config. Large states should use the streaming format
+/// of [`StateWriter`] and [`StateReader`] instead.
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Eq, PartialEq)]
@@ -35,27 +54,270 @@ pub struct StateConfig {
     pub height: Option<BlockHeight>,
 }
 
+/// The entry of the state in the streaming format. The storage and balances
+/// of the contracts are separate entries keyed by the contract id.
+#[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum StateEntry {
+    Coin(CoinConfig),
+    Contract(ContractConfig),
+    ContractState(ContractStateConfig),
+    ContractBalance(ContractBalanceConfig),
+    Message(MessageConfig),
+}
+
 impl StateConfig {
     pub fn generate_state_config<T>(db: T) -> StorageResult<Self>
     where
         T: ChainConfigDb,
     {
         Ok(StateConfig {
-            coins: db.get_coin_config()?,
-            contracts: db.get_contract_config()?,
-            messages: db.get_message_config()?,
+            coins: Some(db.iter_coin_configs().collect::<StorageResult<_>>()?),
+            contracts: Some(contract_configs(&db, |_| true)?),
+            messages: Some(db.iter_message_configs().collect::<StorageResult<_>>()?),
+            height: Some(db.get_block_height()?),
+        })
+    }
+
+    /// Generates the partial state with the entries selected by the `filter`.
+    /// Tables that the filter can't select are not iterated.
+    pub fn generate_filtered_state_config<T>(
+        db: T,
+        filter: &StateFilter,
+    ) -> StorageResult<Self>
+    where
+        T: ChainConfigDb,
+    {
+        let mut state = StateConfig {
             height: Some(db.get_block_height()?),
+            ..Default::default()
+        };
+        if filter.selects_coins() {
+            state.coins = Some(
+                db.iter_coin_configs()
+                    .filter_ok(|coin| filter.matches_coin(coin))
+                    .try_collect()?,
+            );
+        }
+        if filter.selects_contracts() {
+            state.contracts = Some(contract_configs(&db, |contract| {
+                filter.matches_contract(contract)
+            })?);
+        }
+        if filter.selects_messages() {
+            state.messages = Some(
+                db.iter_message_configs()
+                    .filter_ok(|message| filter.matches_message(message))
+                    .try_collect()?,
+            );
+        }
+        Ok(state)
+    }
+
+    /// Writes the state of the database into the `writer` entry by entry,
+    /// so the whole state is never loaded into memory.
+    /// Returns the height of the written state.
+    #[cfg(feature = "std")]
+    pub fn write_state_config<T, W>(
+        db: &T,
+        writer: &mut StateWriter<W>,
+    ) -> anyhow::Result<BlockHeight>
+    where
+        T: ChainConfigDb,
+        W: Write,
+    {
+        for coin in db.iter_coin_configs() {
+            writer.write(&StateEntry::Coin(coin?))?;
+        }
+        for contract in db.iter_contract_configs() {
+            writer.write(&StateEntry::Contract(contract?))?;
+        }
+        for state in db.iter_contract_state_configs() {
+            writer.write(&StateEntry::ContractState(state?))?;
+        }
+        for balance in db.iter_contract_balance_configs() {
+            writer.write(&StateEntry::ContractBalance(balance?))?;
+        }
+        for message in db.iter_message_configs() {
+            writer.write(&StateEntry::Message(message?))?;
+        }
+        Ok(db.get_block_height()?)
+    }
+
+    /// Returns the entries of the state in the same order as they are written
+    /// in the streaming format.
+    pub fn entries(&self) -> impl Iterator<Item = StateEntry> + '_ {
+        let coins = self.coins.iter().flatten().cloned().map(StateEntry::Coin);
+        let contracts = self.contracts.iter().flatten();
+        let contract_entries = contracts.clone().map(|contract| {
+            StateEntry::Contract(ContractConfig {
+                state: None,
+                balances: None,
+                ..contract.clone()
+            })
+        });
+        let state = contracts.clone().flat_map(|contract| {
+            contract.state.iter().flatten().map(|(key, value)| {
+                StateEntry::ContractState(ContractStateConfig {
+                    contract_id: contract.contract_id,
+                    key: *key,
+                    value: *value,
+                })
+            })
+        });
+        let balances = contracts.flat_map(|contract| {
+            contract
+                .balances
+                .iter()
+                .flatten()
+                .map(|(asset_id, amount)| {
+                    StateEntry::ContractBalance(ContractBalanceConfig {
+                        contract_id: contract.contract_id,
+                        asset_id: *asset_id,
+                        amount: *amount,
+                    })
+                })
+        });
+        let messages = self
+            .messages
+            .iter()
+            .flatten()
+            .cloned()
+            .map(StateEntry::Message);
+
+        coins
+            .chain(contract_entries)
+            .chain(state)
+            .chain(balances)
+            .chain(messages)
+    }
+}
+
+/// Returns the contracts selected by the `filter` along with their storage and balances.
+fn contract_configs<T, F>(db: &T, mut filter: F) -> StorageResult<Vec<ContractConfig>>
+where
+    T: ChainConfigDb,
+    F: FnMut(&ContractConfig) -> bool,
+{
+    let mut contracts: BTreeMap<ContractId, ContractConfig> = db
+        .iter_contract_configs()
+        .filter_ok(|contract| filter(contract))
+        .map_ok(|contract| {
+            let contract = ContractConfig {
+                state: Some(vec![]),
+                balances: Some(vec![]),
+                ..contract
+            };
+            (contract.contract_id, contract)
+        })
+        .try_collect()?;
+    for entry in db.iter_contract_state_configs() {
+        let entry = entry?;
+        let state = contracts
+            .get_mut(&entry.contract_id)
+            .and_then(|contract| contract.state.as_mut());
+        if let Some(state) = state {
+            state.push((entry.key, entry.value));
+        }
+    }
+    for entry in db.iter_contract_balance_configs() {
+        let entry = entry?;
+        let balances = contracts
+            .get_mut(&entry.contract_id)
+            .and_then(|contract| contract.balances.as_mut());
+        if let Some(balances) = balances {
+            balances.push((entry.asset_id, entry.amount));
+        }
+    }
+    Ok(contracts.into_values().collect())
+}
+
+/// Writes the state as newline-delimited JSON, one [`StateEntry`] per line.
+#[cfg(feature = "std")]
+pub struct StateWriter<W> {
+    writer: W,
+}
+
+#[cfg(feature = "std")]
+impl<W> StateWriter<W>
+where
+    W: Write,
+{
+    pub fn new(writer: W) -> Self {
+        Self { writer }
+    }
+
+    /// Appends the `entry` to the end of the state.
+    pub fn write(&mut self, entry: &StateEntry) -> anyhow::Result<()> {
+        serde_json::to_writer(&mut self.writer, entry)?;
+        self.writer.write_all(b"\n")?;
+        Ok(())
+    }
+
+    /// Flushes all written entries and returns the underlying writer.
+    pub fn finish(mut self) -> anyhow::Result<W> {
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+/// Reads the state written by the [`StateWriter`] entry by entry.
+///
+/// The reader doesn't buffer the input, so it is better to wrap files into `BufReader`.
+#[cfg(feature = "std")]
+pub struct StateReader<R>
+where
+    R: Read,
+{
+    entries:
+        serde_json::StreamDeserializer<'static, serde_json::de::IoRead<R>, StateEntry>,
+}
+
+#[cfg(feature = "std")]
+impl<R> StateReader<R>
+where
+    R: Read,
+{
+    pub fn new(reader: R) -> Self {
+        Self {
+            entries: serde_json::Deserializer::from_reader(reader).into_iter(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R> Iterator for StateReader<R>
+where
+    R: Read,
+{
+    type Item = anyhow::Result<StateEntry>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.entries.next().map(|entry| {
+            entry.map_err(|e| {
+                anyhow::Error::new(e).context("failed to read the state entry")
+            })
         })
     }
 }
 
 pub trait ChainConfigDb {
     /// Returns *all* unspent coin configs available in the database.
-    fn get_coin_config(&self) -> StorageResult<Option<Vec<CoinConfig>>>;
-    /// Returns *alive* contract configs available in the database.
-    fn get_contract_config(&self) -> StorageResult<Option<Vec<ContractConfig>>>;
+    fn iter_coin_configs(&self) -> BoxedIter<StorageResult<CoinConfig>>;
+    /// Returns *alive* contract configs available in the database without their
+    /// storage and balances, which are returned by [`Self::iter_contract_state_configs`]
+    /// and [`Self::iter_contract_balance_configs`].
+    fn iter_contract_configs(&self) -> BoxedIter<StorageResult<ContractConfig>>;
+    /// Returns the storage entries of *all* contracts ordered by the contract id.
+    fn iter_contract_state_configs(
+        &self,
+    ) -> BoxedIter<StorageResult<ContractStateConfig>>;
+    /// Returns the balances of *all* contracts ordered by the contract id.
+    fn iter_contract_balance_configs(
+        &self,
+    ) -> BoxedIter<StorageResult<ContractBalanceConfig>>;
     /// Returns *all* unspent message configs available in the database.
-    fn get_message_config(&self) -> StorageResult<Option<Vec<MessageConfig>>>;
+    fn iter_message_configs(&self) -> BoxedIter<StorageResult<MessageConfig>>;
     /// Returns the last available block height.
     fn get_block_height(&self) -> StorageResult<BlockHeight>;
 }
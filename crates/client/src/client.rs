@@ -678,6 +678,45 @@ impl FuelClient {
         Ok(transactions)
     }
 
+    /// Returns a paginated set of transactions inside of the txpool in the `order`.
+    /// If the `owner` is set, returns only transactions that spend its coins or messages.
+    pub async fn pool_transactions(
+        &self,
+        order: types::PoolTransactionsOrder,
+        owner: Option<&Address>,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<TransactionResponse, String>> {
+        let owner: Option<schema::Address> = owner.map(|owner| (*owner).into());
+        let query =
+            schema::txpool::PoolTransactionsQuery::build((order, owner, request).into());
+
+        let transactions = self.query(query).await?.pool_transactions.try_into()?;
+        Ok(transactions)
+    }
+
+    /// Returns the statistics of the transactions inside of the txpool.
+    pub async fn pool_stats(&self) -> io::Result<types::TxPoolStats> {
+        let query = schema::txpool::PoolStatsQuery::build(());
+        let stats = self.query(query).await?.pool_stats.into();
+        Ok(stats)
+    }
+
+    /// Suggests gas prices for a new transaction based on the latest `block_horizon`
+    /// blocks and the transactions inside of the txpool. The node uses its default
+    /// horizon if `block_horizon` is `None`.
+    pub async fn estimate_gas_price(
+        &self,
+        block_horizon: Option<u32>,
+    ) -> io::Result<types::GasPriceEstimate> {
+        let query = schema::gas_price::EstimateGasPrice::build(
+            schema::gas_price::EstimateGasPriceArgs {
+                block_horizon: block_horizon.map(Into::into),
+            },
+        );
+        let estimate = self.query(query).await?.estimate_gas_price.into();
+        Ok(estimate)
+    }
+
     pub async fn receipts(&self, id: &TxId) -> io::Result<Option<Vec<Receipt>>> {
         let query = schema::tx::TransactionQuery::build(TxIdArgs { id: (*id).into() });
 
@@ -758,6 +797,59 @@ impl FuelClient {
         Ok(blocks)
     }
 
+    #[cfg(feature = "subscriptions")]
+    /// Subscribe to the blocks starting from the `from_height`.
+    /// If the `from_height` is `None`, the stream starts from the next block.
+    ///
+    /// Already produced blocks are returned first, and after that the stream
+    /// returns new blocks as soon as they are imported by the node.
+    pub async fn subscribe_blocks(
+        &self,
+        from_height: Option<BlockHeight>,
+    ) -> io::Result<impl futures::Stream<Item = io::Result<types::Block>>> {
+        use cynic::SubscriptionBuilder;
+        use schema::block::NewBlocksArgs;
+        let s = schema::block::NewBlocksSubscription::build(NewBlocksArgs {
+            from_height: from_height.map(Into::into),
+        });
+
+        let stream = self.subscribe(s).await?.map(
+            |r: io::Result<schema::block::NewBlocksSubscription>| {
+                let block: types::Block = r?.new_blocks.into();
+                Ok(block)
+            },
+        );
+
+        Ok(stream)
+    }
+
+    #[cfg(feature = "subscriptions")]
+    /// Subscribe to the coins and messages created or spent for the `owner`.
+    /// If the `asset_id` is specified, only events with this asset are returned.
+    ///
+    /// The stream returns an error and closes if it falls behind the node.
+    pub async fn subscribe_owner_events(
+        &self,
+        owner: &Address,
+        asset_id: Option<&AssetId>,
+    ) -> io::Result<impl futures::Stream<Item = io::Result<types::OwnerEvent>>> {
+        use cynic::SubscriptionBuilder;
+        use schema::owner_events::OwnerEventsArgs;
+        let s = schema::owner_events::OwnerEventsSubscription::build(OwnerEventsArgs {
+            owner: (*owner).into(),
+            asset_id: asset_id.map(|id| (*id).into()),
+        });
+
+        let stream = self.subscribe(s).await?.map(
+            |r: io::Result<schema::owner_events::OwnerEventsSubscription>| {
+                let event: types::OwnerEvent = r?.owner_events.try_into()?;
+                Ok(event)
+            },
+        );
+
+        Ok(stream)
+    }
+
     pub async fn coin(&self, id: &UtxoId) -> io::Result<Option<types::Coin>> {
         let query = schema::coins::CoinByIdQuery::build(CoinByIdArgs {
             utxo_id: (*id).into(),
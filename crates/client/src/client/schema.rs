@@ -31,10 +31,13 @@ pub mod block;
 pub mod chain;
 pub mod coins;
 pub mod contract;
+pub mod gas_price;
 pub mod message;
 pub mod node_info;
+pub mod owner_events;
 pub mod primitives;
 pub mod tx;
+pub mod txpool;
 
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
@@ -58,6 +58,22 @@ pub struct BlocksQuery {
     pub blocks: BlockConnection,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct NewBlocksArgs {
+    pub from_height: Option<U32>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Subscription",
+    variables = "NewBlocksArgs"
+)]
+pub struct NewBlocksSubscription {
+    #[arguments(fromHeight: $from_height)]
+    pub new_blocks: Block,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct BlockConnection {
@@ -124,6 +140,7 @@ pub struct Header {
 pub enum Consensus {
     Genesis(Genesis),
     PoAConsensus(PoAConsensus),
+    BftConsensus(BftConsensus),
     #[cynic(fallback)]
     Unknown,
 }
@@ -143,6 +160,13 @@ pub struct PoAConsensus {
     pub signature: Signature,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct BftConsensus {
+    pub round: U32,
+    pub certificate: Vec<Signature>,
+}
+
 impl Block {
     /// Returns the block producer public key, if any.
     pub fn block_producer(&self) -> Option<fuel_crypto::PublicKey> {
@@ -154,7 +178,8 @@ impl Block {
                 let producer_pub_key = signature.recover(&message);
                 producer_pub_key.ok()
             }
-            Consensus::Unknown => None,
+            // The BFT block is committed by the validator set, not a single producer.
+            Consensus::BftConsensus(_) | Consensus::Unknown => None,
         }
     }
 }
@@ -0,0 +1,30 @@
+use crate::client::schema::{
+    schema,
+    U32,
+    U64,
+};
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct EstimateGasPriceArgs {
+    /// The number of the latest blocks used for the estimation
+    pub block_horizon: Option<U32>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "EstimateGasPriceArgs"
+)]
+pub struct EstimateGasPrice {
+    #[arguments(blockHorizon: $block_horizon)]
+    pub estimate_gas_price: GasPriceEstimate,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct GasPriceEstimate {
+    pub low: U64,
+    pub medium: U64,
+    pub high: U64,
+}
@@ -0,0 +1,49 @@
+use crate::client::schema::{
+    schema,
+    Address,
+    AssetId,
+    Nonce,
+    TransactionId,
+    UtxoId,
+    U32,
+    U64,
+};
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct OwnerEventsArgs {
+    pub owner: Address,
+    pub asset_id: Option<AssetId>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Subscription",
+    variables = "OwnerEventsArgs"
+)]
+pub struct OwnerEventsSubscription {
+    #[arguments(owner: $owner, assetId: $asset_id)]
+    pub owner_events: OwnerEvent,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct OwnerEvent {
+    pub kind: OwnerEventKind,
+    pub owner: Address,
+    pub transaction_id: Option<TransactionId>,
+    pub block_height: U32,
+    pub utxo_id: Option<UtxoId>,
+    pub nonce: Option<Nonce>,
+    pub asset_id: AssetId,
+    pub amount: U64,
+}
+
+#[derive(cynic::Enum, Copy, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum OwnerEventKind {
+    CoinCreated,
+    CoinSpent,
+    MessageCreated,
+    MessageSpent,
+}
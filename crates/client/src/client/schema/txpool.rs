@@ -0,0 +1,101 @@
+use crate::client::{
+    schema::{
+        schema,
+        tx::TransactionConnection,
+        Address,
+        U64,
+    },
+    PageDirection,
+    PaginationRequest,
+};
+
+#[derive(cynic::Enum, Copy, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum PoolTransactionsOrder {
+    GasPrice,
+    ArrivalTime,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct PoolTransactionsConnectionArgs {
+    /// The order of the transactions
+    pub order: PoolTransactionsOrder,
+    /// Select only transactions of the `owner`
+    pub owner: Option<Address>,
+    /// Skip until cursor (forward pagination)
+    pub after: Option<String>,
+    /// Skip until cursor (backward pagination)
+    pub before: Option<String>,
+    /// Retrieve the first n transactions in order (forward pagination)
+    pub first: Option<i32>,
+    /// Retrieve the last n transactions in order (backward pagination).
+    /// Can't be used at the same time as `first`.
+    pub last: Option<i32>,
+}
+
+impl
+    From<(
+        PoolTransactionsOrder,
+        Option<Address>,
+        PaginationRequest<String>,
+    )> for PoolTransactionsConnectionArgs
+{
+    fn from(
+        r: (
+            PoolTransactionsOrder,
+            Option<Address>,
+            PaginationRequest<String>,
+        ),
+    ) -> Self {
+        match r.2.direction {
+            PageDirection::Forward => PoolTransactionsConnectionArgs {
+                order: r.0,
+                owner: r.1,
+                after: r.2.cursor,
+                before: None,
+                first: Some(r.2.results),
+                last: None,
+            },
+            PageDirection::Backward => PoolTransactionsConnectionArgs {
+                order: r.0,
+                owner: r.1,
+                after: None,
+                before: r.2.cursor,
+                first: None,
+                last: Some(r.2.results),
+            },
+        }
+    }
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "PoolTransactionsConnectionArgs"
+)]
+pub struct PoolTransactionsQuery {
+    #[arguments(order: $order, owner: $owner, after: $after, before: $before, first: $first, last: $last)]
+    pub pool_transactions: TransactionConnection,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct PoolStatsQuery {
+    pub pool_stats: TxPoolStats,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TxPoolStats {
+    pub tx_count: U64,
+    pub total_consumable_gas: U64,
+    pub gas_price_histogram: Vec<GasPriceBucket>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct GasPriceBucket {
+    pub gas_price: U64,
+    pub tx_count: U64,
+}
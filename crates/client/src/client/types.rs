@@ -4,9 +4,12 @@ pub mod chain_info;
 pub mod coins;
 pub mod contract;
 pub mod gas_costs;
+pub mod gas_price;
 pub mod merkle_proof;
 pub mod message;
 pub mod node_info;
+pub mod owner_events;
+pub mod txpool;
 
 pub use balance::Balance;
 pub use block::{
@@ -27,12 +30,22 @@ pub use gas_costs::{
     DependentCost,
     GasCosts,
 };
+pub use gas_price::GasPriceEstimate;
 pub use merkle_proof::MerkleProof;
 pub use message::{
     Message,
     MessageProof,
 };
 pub use node_info::NodeInfo;
+pub use owner_events::{
+    OwnerChange,
+    OwnerEvent,
+};
+pub use txpool::{
+    GasPriceBucket,
+    PoolTransactionsOrder,
+    TxPoolStats,
+};
 
 use crate::client::schema::{
     tx::{
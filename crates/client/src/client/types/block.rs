@@ -45,6 +45,7 @@ pub struct Header {
 pub enum Consensus {
     Genesis(Genesis),
     PoAConsensus(PoAConsensus),
+    BftConsensus(BftConsensus),
     Unknown,
 }
 
@@ -61,6 +62,14 @@ pub struct PoAConsensus {
     pub signature: Signature,
 }
 
+#[derive(Debug)]
+pub struct BftConsensus {
+    /// The round in which validators committed the block.
+    pub round: u32,
+    /// The precommit signatures of the validators that committed the block.
+    pub certificate: Vec<Signature>,
+}
+
 // GraphQL Translation
 
 impl From<schema::block::Header> for Header {
@@ -89,6 +98,9 @@ impl From<schema::block::Consensus> for Consensus {
             schema::block::Consensus::PoAConsensus(poa) => {
                 Consensus::PoAConsensus(poa.into())
             }
+            schema::block::Consensus::BftConsensus(bft) => {
+                Consensus::BftConsensus(bft.into())
+            }
             schema::block::Consensus::Unknown => Consensus::Unknown,
         }
     }
@@ -114,6 +126,23 @@ impl From<schema::block::PoAConsensus> for PoAConsensus {
     }
 }
 
+impl From<schema::block::BftConsensus> for BftConsensus {
+    fn from(value: schema::block::BftConsensus) -> Self {
+        let certificate = value
+            .certificate
+            .into_iter()
+            .map(|signature| {
+                let bytes: [u8; 64] = signature.0 .0.into();
+                Signature::from_bytes(bytes)
+            })
+            .collect();
+        Self {
+            round: value.round.into(),
+            certificate,
+        }
+    }
+}
+
 impl From<schema::block::Block> for Block {
     fn from(value: schema::block::Block) -> Self {
         let transactions = value
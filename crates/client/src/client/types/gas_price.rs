@@ -0,0 +1,19 @@
+use crate::client::schema;
+
+pub struct GasPriceEstimate {
+    pub low: u64,
+    pub medium: u64,
+    pub high: u64,
+}
+
+// GraphQL Translation
+
+impl From<schema::gas_price::GasPriceEstimate> for GasPriceEstimate {
+    fn from(value: schema::gas_price::GasPriceEstimate) -> Self {
+        Self {
+            low: value.low.into(),
+            medium: value.medium.into(),
+            high: value.high.into(),
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use crate::client::{
+    schema,
+    schema::ConversionError,
+    types::primitives::{
+        Address,
+        AssetId,
+        Nonce,
+        TransactionId,
+        UtxoId,
+    },
+};
+
+/// The change of the coins or messages owned by the `owner`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OwnerEvent {
+    pub owner: Address,
+    /// It is `None` for messages relayed from the DA layer.
+    pub transaction_id: Option<TransactionId>,
+    pub block_height: u32,
+    pub asset_id: AssetId,
+    pub amount: u64,
+    pub change: OwnerChange,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OwnerChange {
+    CoinCreated(UtxoId),
+    CoinSpent(UtxoId),
+    MessageCreated(Nonce),
+    MessageSpent(Nonce),
+}
+
+// GraphQL Translation
+
+impl TryFrom<schema::owner_events::OwnerEvent> for OwnerEvent {
+    type Error = ConversionError;
+
+    fn try_from(value: schema::owner_events::OwnerEvent) -> Result<Self, Self::Error> {
+        use schema::owner_events::OwnerEventKind;
+
+        let schema::owner_events::OwnerEvent {
+            kind,
+            owner,
+            transaction_id,
+            block_height,
+            utxo_id,
+            nonce,
+            asset_id,
+            amount,
+        } = value;
+        let missing = |field: &str| ConversionError::MissingField(field.to_string());
+
+        let change = match kind {
+            OwnerEventKind::CoinCreated => OwnerChange::CoinCreated(
+                utxo_id.ok_or_else(|| missing("utxo_id"))?.into(),
+            ),
+            OwnerEventKind::CoinSpent => {
+                OwnerChange::CoinSpent(utxo_id.ok_or_else(|| missing("utxo_id"))?.into())
+            }
+            OwnerEventKind::MessageCreated => {
+                OwnerChange::MessageCreated(nonce.ok_or_else(|| missing("nonce"))?.into())
+            }
+            OwnerEventKind::MessageSpent => {
+                OwnerChange::MessageSpent(nonce.ok_or_else(|| missing("nonce"))?.into())
+            }
+        };
+
+        Ok(Self {
+            owner: owner.into(),
+            transaction_id: transaction_id.map(Into::into),
+            block_height: block_height.into(),
+            asset_id: asset_id.into(),
+            amount: amount.into(),
+            change,
+        })
+    }
+}
@@ -0,0 +1,40 @@
+use crate::client::schema;
+
+pub use schema::txpool::PoolTransactionsOrder;
+
+pub struct TxPoolStats {
+    pub tx_count: u64,
+    pub total_consumable_gas: u64,
+    /// The number of transactions for each gas price, from the highest gas price
+    pub gas_price_histogram: Vec<GasPriceBucket>,
+}
+
+pub struct GasPriceBucket {
+    pub gas_price: u64,
+    pub tx_count: u64,
+}
+
+// GraphQL Translation
+
+impl From<schema::txpool::TxPoolStats> for TxPoolStats {
+    fn from(value: schema::txpool::TxPoolStats) -> Self {
+        Self {
+            tx_count: value.tx_count.into(),
+            total_consumable_gas: value.total_consumable_gas.into(),
+            gas_price_histogram: value
+                .gas_price_histogram
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        }
+    }
+}
+
+impl From<schema::txpool::GasPriceBucket> for GasPriceBucket {
+    fn from(value: schema::txpool::GasPriceBucket) -> Self {
+        Self {
+            gas_price: value.gas_price.into(),
+            tx_count: value.tx_count.into(),
+        }
+    }
+}
@@ -8,13 +8,19 @@ use crate::{
 use fuel_core_chain_config::{
     ChainConfigDb,
     CoinConfig,
+    ContractBalanceConfig,
     ContractConfig,
+    ContractStateConfig,
     MessageConfig,
 };
 use fuel_core_storage::{
     blueprint::Blueprint,
     codec::Decode,
-    iter::IterDirection,
+    iter::{
+        BoxedIter,
+        IntoBoxedIter,
+        IterDirection,
+    },
     kv_store::{
         BatchOperations,
         KeyValueStore,
@@ -75,7 +81,11 @@ mod state;
 pub(crate) mod coin;
 
 pub mod balances;
+pub mod history;
 pub mod metadata;
+pub mod migration;
+pub mod peers;
+pub mod pruning;
 pub mod storage;
 pub mod transaction;
 pub mod transactions;
@@ -139,10 +149,15 @@ impl Database {
         use anyhow::Context;
         let db = RocksDb::default_open(path, capacity.into()).map_err(Into::<anyhow::Error>::into).context("Failed to open rocksdb, you may need to wipe a pre-existing incompatible db `rm -rf ~/.fuel/db`")?;
 
-        Ok(Database {
+        let mut database = Database {
             data: StructuredStorage::new(Arc::new(db).into()),
             _drop: Default::default(),
-        })
+        };
+        database
+            .migrate(migration::MIGRATIONS)
+            .map_err(Into::<anyhow::Error>::into)
+            .context("Failed to migrate the database")?;
+        Ok(database)
     }
 
     pub fn in_memory() -> Self {
@@ -366,16 +381,28 @@ impl Default for Database {
 /// Implement `ChainConfigDb` so that `Database` can be passed to
 /// `StateConfig's` `generate_state_config()` method
 impl ChainConfigDb for Database {
-    fn get_coin_config(&self) -> StorageResult<Option<Vec<CoinConfig>>> {
-        Self::get_coin_config(self).map_err(Into::into)
+    fn iter_coin_configs(&self) -> BoxedIter<StorageResult<CoinConfig>> {
+        Self::iter_coin_configs(self).into_boxed()
+    }
+
+    fn iter_contract_configs(&self) -> BoxedIter<StorageResult<ContractConfig>> {
+        Self::iter_contract_configs(self).into_boxed()
     }
 
-    fn get_contract_config(&self) -> StorageResult<Option<Vec<ContractConfig>>> {
-        Self::get_contract_config(self)
+    fn iter_contract_state_configs(
+        &self,
+    ) -> BoxedIter<StorageResult<ContractStateConfig>> {
+        Self::iter_contract_state_configs(self).into_boxed()
+    }
+
+    fn iter_contract_balance_configs(
+        &self,
+    ) -> BoxedIter<StorageResult<ContractBalanceConfig>> {
+        Self::iter_contract_balance_configs(self).into_boxed()
     }
 
-    fn get_message_config(&self) -> StorageResult<Option<Vec<MessageConfig>>> {
-        Self::get_message_config(self).map_err(Into::into)
+    fn iter_message_configs(&self) -> BoxedIter<StorageResult<MessageConfig>> {
+        Self::iter_message_configs(self).into_boxed()
     }
 
     fn get_block_height(&self) -> StorageResult<BlockHeight> {
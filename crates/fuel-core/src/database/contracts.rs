@@ -1,5 +1,9 @@
 use crate::database::Database;
-use fuel_core_chain_config::ContractConfig;
+use fuel_core_chain_config::{
+    ContractBalanceConfig,
+    ContractConfig,
+    ContractStateConfig,
+};
 use fuel_core_storage::{
     iter::IterDirection,
     tables::{
@@ -27,6 +31,42 @@ impl Database {
     pub fn get_contract_config_by_id(
         &self,
         contract_id: ContractId,
+    ) -> StorageResult<ContractConfig> {
+        let config = self.get_contract_config_without_state_by_id(contract_id)?;
+
+        let state = Some(
+            self.iter_all_by_prefix::<ContractsState, _>(Some(contract_id.as_ref()))
+                .map(|res| -> StorageResult<(Bytes32, Bytes32)> {
+                    let (key, value) = res?;
+
+                    Ok((*key.state_key(), value))
+                })
+                .filter(|val| val.is_ok())
+                .collect::<StorageResult<Vec<_>>>()?,
+        );
+
+        let balances = Some(
+            self.iter_all_by_prefix::<ContractsAssets, _>(Some(contract_id.as_ref()))
+                .map(|res| {
+                    let (key, value) = res?;
+
+                    Ok((*key.asset_id(), value))
+                })
+                .filter(|val| val.is_ok())
+                .collect::<StorageResult<Vec<_>>>()?,
+        );
+
+        Ok(ContractConfig {
+            state,
+            balances,
+            ..config
+        })
+    }
+
+    /// Returns the config of the contract without its storage and balances.
+    fn get_contract_config_without_state_by_id(
+        &self,
+        contract_id: ContractId,
     ) -> StorageResult<ContractConfig> {
         let code: Vec<u8> = self
             .storage::<ContractsRawCode>()
@@ -52,34 +92,12 @@ impl Database {
             .expect("contract does not exist")
             .into_owned();
 
-        let state = Some(
-            self.iter_all_by_prefix::<ContractsState, _>(Some(contract_id.as_ref()))
-                .map(|res| -> StorageResult<(Bytes32, Bytes32)> {
-                    let (key, value) = res?;
-
-                    Ok((*key.state_key(), value))
-                })
-                .filter(|val| val.is_ok())
-                .collect::<StorageResult<Vec<_>>>()?,
-        );
-
-        let balances = Some(
-            self.iter_all_by_prefix::<ContractsAssets, _>(Some(contract_id.as_ref()))
-                .map(|res| {
-                    let (key, value) = res?;
-
-                    Ok((*key.asset_id(), value))
-                })
-                .filter(|val| val.is_ok())
-                .collect::<StorageResult<Vec<_>>>()?,
-        );
-
         Ok(ContractConfig {
             contract_id,
             code,
             salt,
-            state,
-            balances,
+            state: None,
+            balances: None,
             tx_id: Some(*utxo_id.tx_id()),
             output_index: Some(utxo_id.output_index()),
             tx_pointer_block_height: Some(tx_pointer.block_height()),
@@ -101,16 +119,42 @@ impl Database {
         .map(|res| res.map(|(key, balance)| (*key.asset_id(), balance)))
     }
 
-    pub fn get_contract_config(&self) -> StorageResult<Option<Vec<ContractConfig>>> {
-        let configs = self
-            .iter_all::<ContractsRawCode>(None)
-            .map(|raw_contract_id| -> StorageResult<ContractConfig> {
+    /// Returns the configs of all contracts without their storage and balances.
+    pub fn iter_contract_configs(
+        &self,
+    ) -> impl Iterator<Item = StorageResult<ContractConfig>> + '_ {
+        self.iter_all::<ContractsRawCode>(None).map(
+            |raw_contract_id| -> StorageResult<ContractConfig> {
                 let contract_id = raw_contract_id?.0;
-                self.get_contract_config_by_id(contract_id)
+                self.get_contract_config_without_state_by_id(contract_id)
+            },
+        )
+    }
+
+    pub fn iter_contract_state_configs(
+        &self,
+    ) -> impl Iterator<Item = StorageResult<ContractStateConfig>> + '_ {
+        self.iter_all::<ContractsState>(None).map(|res| {
+            let (key, value) = res?;
+            Ok(ContractStateConfig {
+                contract_id: *key.contract_id(),
+                key: *key.state_key(),
+                value,
             })
-            .collect::<StorageResult<Vec<ContractConfig>>>()?;
+        })
+    }
 
-        Ok(Some(configs))
+    pub fn iter_contract_balance_configs(
+        &self,
+    ) -> impl Iterator<Item = StorageResult<ContractBalanceConfig>> + '_ {
+        self.iter_all::<ContractsAssets>(None).map(|res| {
+            let (key, amount) = res?;
+            Ok(ContractBalanceConfig {
+                contract_id: *key.contract_id(),
+                asset_id: *key.asset_id(),
+                amount,
+            })
+        })
     }
 }
 
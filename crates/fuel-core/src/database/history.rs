@@ -0,0 +1,305 @@
+//! The database records the previous values of the state modified by each block.
+//! It allows restoring the state at any height after the history start.
+
+use crate::database::{
+    metadata::{
+        MetadataTable,
+        STATE_HISTORY_START,
+    },
+    Column,
+    Database,
+};
+use fuel_core_chain_config::{
+    ChainConfigDb,
+    CoinConfig,
+    ContractBalanceConfig,
+    ContractConfig,
+    ContractStateConfig,
+    MessageConfig,
+};
+use fuel_core_storage::{
+    iter::{
+        BoxedIter,
+        IntoBoxedIter,
+        IterDirection,
+        IteratorableStore,
+    },
+    kv_store::KeyValueStore,
+    not_found,
+    tables::Messages,
+    Error as StorageError,
+    Result as StorageResult,
+    StorageAsMut,
+    StorageAsRef,
+};
+use fuel_core_types::fuel_types::BlockHeight;
+use itertools::Itertools;
+use std::sync::Arc;
+
+/// The columns that form the state exported into the snapshot: coins, contracts,
+/// messages and spent messages. Messages added by the relayer are not a part of
+/// the block, so they are filtered by the DA height instead.
+const HISTORY_COLUMNS: [Column; 8] = [
+    Column::Coins,
+    Column::ContractsRawCode,
+    Column::ContractsInfo,
+    Column::ContractsLatestUtxo,
+    Column::ContractsState,
+    Column::ContractsAssets,
+    Column::Messages,
+    Column::SpentMessages,
+];
+
+const HEIGHT_SIZE: usize = core::mem::size_of::<u32>();
+const COLUMN_SIZE: usize = core::mem::size_of::<u32>();
+
+/// The key of the history entry: `height ++ column ++ original key`.
+/// The big-endian encoding of the height keeps the entries ordered by height.
+fn history_key(height: &BlockHeight, column: Column, key: &[u8]) -> Vec<u8> {
+    let mut history_key = Vec::with_capacity(
+        HEIGHT_SIZE
+            .saturating_add(COLUMN_SIZE)
+            .saturating_add(key.len()),
+    );
+    history_key.extend_from_slice(&height.to_bytes());
+    history_key.extend_from_slice(&column.as_u32().to_be_bytes());
+    history_key.extend_from_slice(key);
+    history_key
+}
+
+fn decode_history_key(key: &[u8]) -> StorageResult<(BlockHeight, Column, &[u8])> {
+    if key.len() < HEIGHT_SIZE.saturating_add(COLUMN_SIZE) {
+        return Err(StorageError::Codec(anyhow::anyhow!(
+            "The state history key is too short"
+        )))
+    }
+    let (height, rest) = key.split_at(HEIGHT_SIZE);
+    let (column, key) = rest.split_at(COLUMN_SIZE);
+    let height = u32::from_be_bytes(height.try_into().expect("Checked above"));
+    let column = u32::from_be_bytes(column.try_into().expect("Checked above"));
+    let column = HISTORY_COLUMNS
+        .into_iter()
+        .find(|c| c.as_u32() == column)
+        .ok_or_else(|| {
+            StorageError::Codec(anyhow::anyhow!(
+                "Unknown column {column} in the state history"
+            ))
+        })?;
+    Ok((height.into(), column, key))
+}
+
+/// The value of the history entry: `[0]` if the key didn't exist before the block,
+/// or `[1] ++ previous value` otherwise.
+fn history_value(value: Option<&[u8]>) -> Vec<u8> {
+    match value {
+        Some(value) => {
+            let mut history_value = Vec::with_capacity(value.len().saturating_add(1));
+            history_value.push(1);
+            history_value.extend_from_slice(value);
+            history_value
+        }
+        None => vec![0],
+    }
+}
+
+fn decode_history_value(value: &[u8]) -> StorageResult<Option<Vec<u8>>> {
+    match value.split_first() {
+        Some((0, [])) => Ok(None),
+        Some((1, value)) => Ok(Some(value.to_vec())),
+        _ => Err(StorageError::Codec(anyhow::anyhow!(
+            "Invalid value in the state history"
+        ))),
+    }
+}
+
+impl Database {
+    /// Records the values of the state before the uncommitted changes of the block
+    /// at the `height`. Must be called on the transaction with the changes of the block.
+    pub(crate) fn record_state_history(
+        &mut self,
+        height: &BlockHeight,
+    ) -> StorageResult<()> {
+        for column in HISTORY_COLUMNS {
+            for (key, value) in self.data.as_ref().previous_values(column)? {
+                self.data.as_ref().put(
+                    &history_key(height, column, &key),
+                    Column::StateHistory,
+                    Arc::new(history_value(value.as_deref().map(Vec::as_slice))),
+                )?;
+            }
+        }
+
+        if self.state_history_start()?.is_none() {
+            let start: u32 = (*height).into();
+            self.storage::<MetadataTable<u32>>()
+                .insert(STATE_HISTORY_START, &start)?;
+        }
+        Ok(())
+    }
+
+    /// Removes the whole state history. The history can't be restored for the blocks
+    /// imported without it, so the history starts again from the next recorded block.
+    pub(crate) fn clear_state_history(&mut self) -> StorageResult<()> {
+        if self.state_history_start()?.is_none() {
+            return Ok(())
+        }
+
+        let keys: Vec<_> = self
+            .data
+            .as_ref()
+            .iter_all(Column::StateHistory, None, None, IterDirection::Forward)
+            .map_ok(|(key, _)| key)
+            .try_collect()?;
+        for key in keys {
+            self.data.as_ref().delete(&key, Column::StateHistory)?;
+        }
+
+        self.storage::<MetadataTable<u32>>()
+            .remove(STATE_HISTORY_START)?;
+        Ok(())
+    }
+
+    /// Removes the state history of the blocks below the `height`.
+    /// The state can't be restored for heights below `height - 1` after that.
+    pub(crate) fn prune_state_history(
+        &mut self,
+        height: BlockHeight,
+    ) -> StorageResult<()> {
+        match self.state_history_start()? {
+            Some(start) if start < height => {}
+            _ => return Ok(()),
+        }
+
+        let pruned_keys: Vec<_> = self
+            .data
+            .as_ref()
+            .iter_all(Column::StateHistory, None, None, IterDirection::Forward)
+            .map_ok(|(key, _)| key)
+            .take_while(|result| {
+                result.as_ref().map_or(true, |key| {
+                    decode_history_key(key)
+                        .map_or(true, |(block_height, _, _)| block_height < height)
+                })
+            })
+            .try_collect()?;
+        for key in pruned_keys {
+            self.data.as_ref().delete(&key, Column::StateHistory)?;
+        }
+
+        let start: u32 = height.into();
+        self.storage::<MetadataTable<u32>>()
+            .insert(STATE_HISTORY_START, &start)?;
+        Ok(())
+    }
+
+    /// Returns the first height with the recorded state history.
+    pub fn state_history_start(&self) -> StorageResult<Option<BlockHeight>> {
+        let start = self
+            .storage::<MetadataTable<u32>>()
+            .get(STATE_HISTORY_START)?
+            .map(|start| start.into_owned().into());
+        Ok(start)
+    }
+
+    /// Restores the state at the `height` by reverting the changes of all blocks after it.
+    /// The changes are applied to the in-memory view and never committed.
+    pub fn state_at_height(&self, height: BlockHeight) -> StorageResult<StateAtHeight> {
+        let latest = self.latest_height()?;
+        if height > latest {
+            return Err(anyhow::anyhow!(
+                "The height {height} is above the latest height {latest}"
+            )
+            .into())
+        }
+        // Without the history, only the latest state is available.
+        let history_start = match self.state_history_start()? {
+            Some(start) => start,
+            None => latest.succ().unwrap_or(latest),
+        };
+        if height.succ().map_or(false, |next| next < history_start) {
+            return Err(anyhow::anyhow!(
+                "The state history is not available for the height {height}, \
+                the history starts at {history_start}"
+            )
+            .into())
+        }
+
+        let transaction = self.transaction();
+        let mut database = transaction.as_ref().clone();
+
+        let history = self.data.as_ref().iter_all(
+            Column::StateHistory,
+            None,
+            None,
+            IterDirection::Reverse,
+        );
+        for entry in history {
+            let (history_key, history_value) = entry?;
+            let (block_height, column, key) = decode_history_key(&history_key)?;
+            if block_height <= height {
+                break
+            }
+            match decode_history_value(&history_value)? {
+                Some(value) => {
+                    database.data.as_ref().put(key, column, Arc::new(value))?
+                }
+                None => database.data.as_ref().delete(key, column)?,
+            }
+        }
+
+        // Messages relayed after the `height` were not a part of the state.
+        let da_height = self
+            .get_sealed_block_header_by_height(&height)?
+            .ok_or(not_found!("SealedBlockHeader"))?
+            .entity
+            .da_height;
+        let relayed_later: Vec<_> = database
+            .all_messages(None, None)
+            .filter_map_ok(|message| {
+                (message.da_height > da_height).then_some(*message.id())
+            })
+            .try_collect()?;
+        for nonce in relayed_later {
+            database.storage::<Messages>().remove(&nonce)?;
+        }
+
+        Ok(StateAtHeight { database, height })
+    }
+}
+
+/// The state of the database at a historical height.
+#[derive(Clone, Debug)]
+pub struct StateAtHeight {
+    database: Database,
+    height: BlockHeight,
+}
+
+impl ChainConfigDb for StateAtHeight {
+    fn iter_coin_configs(&self) -> BoxedIter<StorageResult<CoinConfig>> {
+        self.database.iter_coin_configs().into_boxed()
+    }
+
+    fn iter_contract_configs(&self) -> BoxedIter<StorageResult<ContractConfig>> {
+        self.database.iter_contract_configs().into_boxed()
+    }
+
+    fn iter_contract_state_configs(
+        &self,
+    ) -> BoxedIter<StorageResult<ContractStateConfig>> {
+        self.database.iter_contract_state_configs().into_boxed()
+    }
+
+    fn iter_contract_balance_configs(
+        &self,
+    ) -> BoxedIter<StorageResult<ContractBalanceConfig>> {
+        self.database.iter_contract_balance_configs().into_boxed()
+    }
+
+    fn iter_message_configs(&self) -> BoxedIter<StorageResult<MessageConfig>> {
+        self.database.iter_message_configs().into_boxed()
+    }
+
+    fn get_block_height(&self) -> StorageResult<BlockHeight> {
+        Ok(self.height)
+    }
+}
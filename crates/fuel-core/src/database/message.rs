@@ -8,6 +8,7 @@ use fuel_core_storage::{
     codec::{
         manual::Manual,
         postcard::Postcard,
+        raw::Raw,
         Decode,
         Encode,
     },
@@ -26,6 +27,7 @@ use fuel_core_storage::{
     StorageMutate,
 };
 use fuel_core_types::{
+    blockchain::primitives::DaBlockHeight,
     entities::message::Message,
     fuel_types::{
         Address,
@@ -72,6 +74,39 @@ impl TableWithBlueprint for OwnedMessageIds {
     }
 }
 
+/// The table that stores all relayed messages by the DA height and the nonce.
+/// Unlike the `Messages` table, the spent messages are not removed from it.
+pub struct RelayedMessages;
+
+impl Mappable for RelayedMessages {
+    type Key = [u8; RELAYED_MESSAGE_KEY_SIZE];
+    type OwnedKey = Self::Key;
+    type Value = Message;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for RelayedMessages {
+    type Blueprint = Plain<Raw, Postcard>;
+
+    fn column() -> fuel_core_storage::column::Column {
+        Column::RelayedMessages
+    }
+}
+
+const RELAYED_MESSAGE_KEY_SIZE: usize = 8 + Nonce::LEN;
+
+/// The key of the `RelayedMessages` table. The big-endian DA height goes first,
+/// so the messages are ordered by the DA height.
+pub fn relayed_message_key(
+    da_height: &DaBlockHeight,
+    nonce: &Nonce,
+) -> [u8; RELAYED_MESSAGE_KEY_SIZE] {
+    let mut key = [0u8; RELAYED_MESSAGE_KEY_SIZE];
+    key[..8].copy_from_slice(&da_height.0.to_be_bytes());
+    key[8..].copy_from_slice(nonce.as_ref());
+    key
+}
+
 impl StorageInspect<Messages> for Database {
     type Error = StorageError;
 
@@ -97,6 +132,10 @@ impl StorageMutate<Messages> for Database {
         self.storage_as_mut::<OwnedMessageIds>()
             .insert(&OwnedMessageKey::new(&value.recipient, key), &())?;
 
+        // insert secondary record by DA height, it stays after the message is spent
+        self.storage_as_mut::<RelayedMessages>()
+            .insert(&relayed_message_key(&value.da_height, key), value)?;
+
         Ok(result)
     }
 
@@ -128,6 +167,24 @@ impl Database {
         .map(|res| res.map(|(key, _)| *key.nonce()))
     }
 
+    /// Returns the messages relayed at the DA heights above the `from` and up to
+    /// the `to`, including the already spent ones.
+    pub fn relayed_messages_in_range(
+        &self,
+        from: DaBlockHeight,
+        to: DaBlockHeight,
+    ) -> impl Iterator<Item = StorageResult<Message>> + '_ {
+        let start = from.0.saturating_add(1).to_be_bytes();
+        self.iter_all_by_start::<RelayedMessages, _>(
+            Some(start),
+            Some(IterDirection::Forward),
+        )
+        .map(|res| res.map(|(_, message)| message))
+        .take_while(move |res| {
+            res.as_ref().map_or(true, |message| message.da_height <= to)
+        })
+    }
+
     pub fn all_messages(
         &self,
         start: Option<Nonce>,
@@ -138,9 +195,10 @@ impl Database {
             .map(|res| res.map(|(_, message)| message))
     }
 
-    pub fn get_message_config(&self) -> StorageResult<Option<Vec<MessageConfig>>> {
-        let configs = self
-            .all_messages(None, None)
+    pub fn iter_message_configs(
+        &self,
+    ) -> impl Iterator<Item = StorageResult<MessageConfig>> + '_ {
+        self.all_messages(None, None)
             .filter_map(|msg| {
                 // Return only unspent messages
                 if let Ok(msg) = msg {
@@ -165,9 +223,6 @@ impl Database {
                     da_height: msg.da_height,
                 })
             })
-            .collect::<StorageResult<Vec<MessageConfig>>>()?;
-
-        Ok(Some(configs))
     }
 
     pub fn message_is_spent(&self, id: &Nonce) -> StorageResult<bool> {
@@ -1,5 +1,9 @@
 use crate::{
     database::{
+        migration::{
+            latest_version,
+            MIGRATIONS,
+        },
         storage::UseStructuredImplementation,
         Column,
         Database,
@@ -19,6 +23,7 @@ use fuel_core_storage::{
     Result as StorageResult,
     StorageMutate,
 };
+use fuel_core_types::fuel_types::Bytes32;
 
 /// The table that stores all metadata. Each key is a string, while the value depends on the context.
 /// The tables mostly used to store metadata for correct work of the `fuel-core`.
@@ -55,9 +60,31 @@ pub(crate) const CHAIN_NAME_KEY: &str = "chain_name";
 /// Tracks the total number of transactions written to the chain
 /// It's useful for analyzing TPS or other metrics.
 pub(crate) const TX_COUNT: &str = "total_tx_count";
+/// Track the number of entries imported from the genesis state, so the
+/// interrupted import can be resumed.
+pub(crate) const GENESIS_COINS_PROGRESS: &str = "genesis_coins_progress";
+pub(crate) const GENESIS_CONTRACTS_PROGRESS: &str = "genesis_contracts_progress";
+pub(crate) const GENESIS_CONTRACTS_STATE_PROGRESS: &str =
+    "genesis_contracts_state_progress";
+pub(crate) const GENESIS_CONTRACTS_BALANCES_PROGRESS: &str =
+    "genesis_contracts_balances_progress";
+pub(crate) const GENESIS_MESSAGES_PROGRESS: &str = "genesis_messages_progress";
+/// The hash of the chain config and the genesis state of the interrupted import.
+/// The import is resumed only from the same chain config and state.
+pub(crate) const GENESIS_SOURCE_HASH: &str = "genesis_source_hash";
+/// The first height with the recorded state history.
+pub(crate) const STATE_HISTORY_START: &str = "state_history_start";
+/// The transactions of the blocks below this height are pruned.
+pub(crate) const PRUNED_BELOW: &str = "pruned_below";
+/// The receipts and statuses of the transactions of the blocks below this height
+/// are pruned by the off-chain worker.
+pub(crate) const OFF_CHAIN_PRUNED_BELOW: &str = "off_chain_pruned_below";
+/// The index of the pruned transactions is removed for the blocks below this height.
+pub(crate) const PRUNED_TRANSACTIONS_INDEX_BELOW: &str =
+    "pruned_transactions_index_below";
 
-/// Can be used to perform migrations in the future.
-pub(crate) const DB_VERSION: u32 = 0x00;
+/// The version of the database schema after all migrations.
+pub(crate) const DB_VERSION: u32 = latest_version(MIGRATIONS);
 
 impl Database {
     /// Ensures the database is initialized and that the database version is correct
@@ -124,4 +151,50 @@ impl Database {
             .get(TX_COUNT)
             .map(|v| v.unwrap_or_default().into_owned())
     }
+
+    /// Returns the number of the genesis state entries imported under the `key`.
+    pub fn genesis_progress(&self, key: &str) -> StorageResult<u64> {
+        use fuel_core_storage::StorageAsRef;
+        self.storage::<MetadataTable<u64>>()
+            .get(key)
+            .map(|v| v.unwrap_or_default().into_owned())
+    }
+
+    pub fn update_genesis_progress(
+        &mut self,
+        key: &str,
+        imported: u64,
+    ) -> StorageResult<()> {
+        use fuel_core_storage::StorageAsMut;
+        self.storage::<MetadataTable<u64>>()
+            .insert(key, &imported)?;
+        Ok(())
+    }
+
+    pub fn remove_genesis_progress(&mut self, key: &str) -> StorageResult<()> {
+        use fuel_core_storage::StorageAsMut;
+        self.storage::<MetadataTable<u64>>().remove(key)?;
+        Ok(())
+    }
+
+    pub fn genesis_source_hash(&self) -> StorageResult<Option<Bytes32>> {
+        use fuel_core_storage::StorageAsRef;
+        self.storage::<MetadataTable<Bytes32>>()
+            .get(GENESIS_SOURCE_HASH)
+            .map(|v| v.map(|v| v.into_owned()))
+    }
+
+    pub fn set_genesis_source_hash(&mut self, hash: &Bytes32) -> StorageResult<()> {
+        use fuel_core_storage::StorageAsMut;
+        self.storage::<MetadataTable<Bytes32>>()
+            .insert(GENESIS_SOURCE_HASH, hash)?;
+        Ok(())
+    }
+
+    pub fn remove_genesis_source_hash(&mut self) -> StorageResult<()> {
+        use fuel_core_storage::StorageAsMut;
+        self.storage::<MetadataTable<Bytes32>>()
+            .remove(GENESIS_SOURCE_HASH)?;
+        Ok(())
+    }
 }
@@ -0,0 +1,289 @@
+//! The schema of the database is versioned. The version is stored in the `Metadata` column
+//! and bumped by the migrations from the [`MIGRATIONS`] registry.
+
+use crate::database::{
+    message::{
+        relayed_message_key,
+        RelayedMessages,
+    },
+    metadata::{
+        MetadataTable,
+        DB_VERSION_KEY,
+    },
+    Column,
+    Database,
+    Error as DatabaseError,
+};
+use fuel_core_storage::{
+    iter::{
+        IterDirection,
+        IteratorableStore,
+    },
+    transactional::Transaction,
+    Result as StorageResult,
+    StorageAsMut,
+    StorageAsRef,
+};
+use fuel_core_types::entities::message::Message;
+use itertools::Itertools;
+
+/// The migration of the database schema from the `version - 1` to the `version`.
+#[derive(Clone, Copy, Debug)]
+pub struct Migration {
+    /// The version of the schema after the migration.
+    pub version: u32,
+    /// The human-readable description of the migration.
+    pub description: &'static str,
+    /// Migrates the database. The changes are committed atomically
+    /// with the new version of the schema.
+    pub migrate: fn(&mut Database) -> StorageResult<()>,
+}
+
+/// The ordered registry of the migrations. Each migration bumps the version by one.
+/// New layout changes of the database must be added here along with the migration
+/// of the existing data.
+pub const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        description: "Add the state history, peer store and pruned transactions columns",
+        migrate: add_history_peers_and_pruning_columns,
+    },
+    Migration {
+        version: 2,
+        description: "Index the relayed messages by the DA height",
+        migrate: index_relayed_messages,
+    },
+];
+
+/// The new columns are created empty when the database is opened. The state history
+/// is recorded starting from the next block, the peers are discovered again, and
+/// nothing is pruned yet, so the existing data doesn't need to be changed. The data
+/// in these columns means that the database was written by an incompatible build.
+fn add_history_peers_and_pruning_columns(database: &mut Database) -> StorageResult<()> {
+    for column in [
+        Column::StateHistory,
+        Column::PeerStore,
+        Column::PrunedTransactions,
+    ] {
+        let is_empty = database
+            .data
+            .as_ref()
+            .iter_all(column, None, None, IterDirection::Forward)
+            .next()
+            .is_none();
+        if !is_empty {
+            return Err(anyhow::anyhow!(
+                "The column {column:?} of the database at the version 0 is not empty"
+            )
+            .into())
+        }
+    }
+    Ok(())
+}
+
+/// The unspent messages are indexed by the DA height. The messages spent before
+/// the migration belong to the processed blocks, so they are not required.
+fn index_relayed_messages(database: &mut Database) -> StorageResult<()> {
+    let messages: Vec<Message> = database.all_messages(None, None).try_collect()?;
+    for message in messages {
+        database.storage::<RelayedMessages>().insert(
+            &relayed_message_key(&message.da_height, message.id()),
+            &message,
+        )?;
+    }
+    Ok(())
+}
+
+/// Returns the version of the schema after all `migrations`.
+pub const fn latest_version(migrations: &[Migration]) -> u32 {
+    match migrations.last() {
+        Some(migration) => migration.version,
+        None => 0,
+    }
+}
+
+impl Database {
+    /// Returns the version of the schema, or `None` if the database is not initialized.
+    pub fn schema_version(&self) -> StorageResult<Option<u32>> {
+        let version = self
+            .storage::<MetadataTable<u32>>()
+            .get(DB_VERSION_KEY)?
+            .map(|version| version.into_owned());
+        Ok(version)
+    }
+
+    /// Returns the `migrations` that are not yet applied to the database.
+    /// The uninitialized database doesn't require migrations, because it is
+    /// initialized with the latest version.
+    pub fn pending_migrations<'a>(
+        &self,
+        migrations: &'a [Migration],
+    ) -> StorageResult<Vec<&'a Migration>> {
+        let version = match self.schema_version()? {
+            Some(version) => version,
+            None => return Ok(vec![]),
+        };
+        let latest = latest_version(migrations);
+        if version > latest {
+            return Err(DatabaseError::InvalidDatabaseVersion {
+                found: version,
+                expected: latest,
+            }
+            .into())
+        }
+
+        Ok(migrations
+            .iter()
+            .filter(|migration| migration.version > version)
+            .collect())
+    }
+
+    /// Applies the pending `migrations` in order. Each migration is committed
+    /// separately, so the interrupted migration can be continued later.
+    pub fn migrate(&mut self, migrations: &[Migration]) -> StorageResult<()> {
+        for migration in self.pending_migrations(migrations)? {
+            tracing::info!(
+                "Migrating the database to the version {}: {}",
+                migration.version,
+                migration.description
+            );
+            let mut transaction = self.transaction();
+            (migration.migrate)(transaction.as_mut())?;
+            transaction
+                .as_mut()
+                .storage::<MetadataTable<u32>>()
+                .insert(DB_VERSION_KEY, &migration.version)?;
+            transaction.commit()?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::metadata::DB_VERSION;
+
+    const TEST_KEY: &str = "test_migrations";
+
+    fn append_version(database: &mut Database, version: u32) -> StorageResult<()> {
+        let mut applied = database
+            .storage::<MetadataTable<Vec<u32>>>()
+            .get(TEST_KEY)?
+            .map(|applied| applied.into_owned())
+            .unwrap_or_default();
+        applied.push(version);
+        database
+            .storage::<MetadataTable<Vec<u32>>>()
+            .insert(TEST_KEY, &applied)?;
+        Ok(())
+    }
+
+    const TEST_MIGRATIONS: &[Migration] = &[
+        Migration {
+            version: 1,
+            description: "first",
+            migrate: |database| append_version(database, 1),
+        },
+        Migration {
+            version: 2,
+            description: "second",
+            migrate: |database| append_version(database, 2),
+        },
+    ];
+
+    fn database_at_version(version: u32) -> Database {
+        let mut database = Database::default();
+        database
+            .storage::<MetadataTable<u32>>()
+            .insert(DB_VERSION_KEY, &version)
+            .unwrap();
+        database
+    }
+
+    #[test]
+    fn registry_is_ordered_and_matches_db_version() {
+        for (version, migration) in (1u32..).zip(MIGRATIONS) {
+            assert_eq!(migration.version, version);
+        }
+        assert_eq!(latest_version(MIGRATIONS), DB_VERSION);
+    }
+
+    #[test]
+    fn uninitialized_database_has_no_pending_migrations() {
+        let database = Database::default();
+
+        let pending = database.pending_migrations(TEST_MIGRATIONS).unwrap();
+
+        assert!(pending.is_empty());
+    }
+
+    #[test]
+    fn pending_migrations_skip_applied_ones() {
+        let database = database_at_version(1);
+
+        let pending = database.pending_migrations(TEST_MIGRATIONS).unwrap();
+
+        let versions: Vec<_> = pending.iter().map(|m| m.version).collect();
+        assert_eq!(versions, vec![2]);
+    }
+
+    #[test]
+    fn migrate_applies_pending_migrations_in_order() {
+        let mut database = database_at_version(0);
+
+        database.migrate(TEST_MIGRATIONS).unwrap();
+
+        let applied = database
+            .storage::<MetadataTable<Vec<u32>>>()
+            .get(TEST_KEY)
+            .unwrap()
+            .unwrap()
+            .into_owned();
+        assert_eq!(applied, vec![1, 2]);
+        assert_eq!(database.schema_version().unwrap(), Some(2));
+        assert!(database
+            .pending_migrations(TEST_MIGRATIONS)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn database_at_version_0_is_upgraded_to_latest_version() {
+        let mut database = database_at_version(0);
+
+        database.migrate(MIGRATIONS).unwrap();
+
+        assert_eq!(database.schema_version().unwrap(), Some(DB_VERSION));
+        assert_eq!(database.state_history_start().unwrap(), None);
+        assert_eq!(database.pruned_below().unwrap(), None);
+        assert!(database.peer_records().unwrap().is_empty());
+        assert!(database.pending_migrations(MIGRATIONS).unwrap().is_empty());
+    }
+
+    #[cfg(feature = "rocksdb")]
+    #[test]
+    fn rocksdb_at_version_0_is_upgraded_on_open() {
+        let tmp_dir = tempfile::TempDir::new().unwrap();
+        {
+            let mut database = Database::open(tmp_dir.path(), None).unwrap();
+            database
+                .storage::<MetadataTable<u32>>()
+                .insert(DB_VERSION_KEY, &0)
+                .unwrap();
+        }
+
+        let database = Database::open(tmp_dir.path(), None).unwrap();
+
+        assert_eq!(database.schema_version().unwrap(), Some(DB_VERSION));
+    }
+
+    #[test]
+    fn newer_database_version_is_rejected() {
+        let database = database_at_version(3);
+
+        let result = database.pending_migrations(TEST_MIGRATIONS);
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,112 @@
+use crate::{
+    database::{
+        storage::UseStructuredImplementation,
+        Column,
+        Database,
+    },
+    state::DataSource,
+};
+use fuel_core_storage::{
+    blueprint::plain::Plain,
+    codec::{
+        postcard::Postcard,
+        raw::Raw,
+    },
+    structured_storage::{
+        StructuredStorage,
+        TableWithBlueprint,
+    },
+    transactional::Transaction,
+    Mappable,
+    Result as StorageResult,
+    StorageAsMut,
+};
+use fuel_core_types::services::p2p::{
+    PeerId,
+    PeerRecord,
+};
+use itertools::Itertools;
+
+/// The table stores the known peers of the p2p network by their ids.
+pub struct PeerRecords;
+
+impl Mappable for PeerRecords {
+    type Key = Vec<u8>;
+    type OwnedKey = Self::Key;
+    type Value = PeerRecord;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for PeerRecords {
+    type Blueprint = Plain<Raw, Postcard>;
+
+    fn column() -> Column {
+        Column::PeerStore
+    }
+}
+
+impl UseStructuredImplementation<PeerRecords> for StructuredStorage<DataSource> {}
+
+impl Database {
+    /// Returns all persisted peers.
+    pub fn peer_records(&self) -> StorageResult<Vec<(PeerId, PeerRecord)>> {
+        self.iter_all::<PeerRecords>(None)
+            .map_ok(|(peer_id, record)| (peer_id.into(), record))
+            .try_collect()
+    }
+
+    /// Replaces the persisted peers with the `records` atomically.
+    pub fn replace_peer_records(
+        &mut self,
+        records: Vec<(PeerId, PeerRecord)>,
+    ) -> StorageResult<()> {
+        let stale_peers: Vec<_> = self
+            .iter_all::<PeerRecords>(None)
+            .map_ok(|(peer_id, _)| peer_id)
+            .try_collect()?;
+
+        let mut transaction = self.transaction();
+        let database = transaction.as_mut();
+        for peer_id in stale_peers {
+            database.storage::<PeerRecords>().remove(&peer_id)?;
+        }
+        for (peer_id, record) in records {
+            database
+                .storage::<PeerRecords>()
+                .insert(&peer_id.into(), &record)?;
+        }
+        transaction.commit()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(last_seen: u64) -> PeerRecord {
+        PeerRecord {
+            addresses: vec![vec![1, 2, 3]],
+            last_seen,
+            app_score: 10.0,
+            banned: false,
+            banned_until: None,
+        }
+    }
+
+    #[test]
+    fn replace_peer_records_removes_stale_peers() {
+        let mut database = Database::default();
+        let stale: PeerId = vec![1].into();
+        let fresh: PeerId = vec![2].into();
+
+        database
+            .replace_peer_records(vec![(stale, record(1))])
+            .unwrap();
+        database
+            .replace_peer_records(vec![(fresh.clone(), record(2))])
+            .unwrap();
+
+        assert_eq!(database.peer_records().unwrap(), vec![(fresh, record(2))]);
+    }
+}
@@ -0,0 +1,266 @@
+use crate::database::{
+    metadata::{
+        MetadataTable,
+        OFF_CHAIN_PRUNED_BELOW,
+        PRUNED_BELOW,
+        PRUNED_TRANSACTIONS_INDEX_BELOW,
+    },
+    transactions::TransactionStatuses,
+    Column,
+    Database,
+};
+use fuel_core_storage::{
+    blueprint::plain::Plain,
+    codec::{
+        postcard::Postcard,
+        raw::Raw,
+    },
+    iter::IterDirection,
+    structured_storage::TableWithBlueprint,
+    tables::{
+        FuelBlocks,
+        Receipts,
+        Transactions,
+    },
+    Mappable,
+    Result as StorageResult,
+    StorageAsMut,
+    StorageAsRef,
+};
+use fuel_core_types::{
+    fuel_tx::TxId,
+    fuel_types::BlockHeight,
+};
+use itertools::Itertools;
+
+/// The maximum number of blocks pruned at once. It limits the size of the pruning
+/// when it is enabled for the node with a long history, the rest of the blocks
+/// are pruned with the next blocks.
+const PRUNING_BATCH_SIZE: usize = 64;
+
+/// The number of blocks below the pruned height for which the pruned transactions
+/// are indexed. The older transactions are reported as unknown instead of pruned,
+/// so the index doesn't grow forever.
+const PRUNED_TRANSACTIONS_INDEX_DEPTH: u32 = 100_000;
+
+/// The table stores the height of the block of each pruned transaction.
+pub struct PrunedTransactions;
+
+impl Mappable for PrunedTransactions {
+    type Key = TxId;
+    type OwnedKey = Self::Key;
+    type Value = BlockHeight;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for PrunedTransactions {
+    type Blueprint = Plain<Raw, Postcard>;
+
+    fn column() -> Column {
+        Column::PrunedTransactions
+    }
+}
+
+impl Database {
+    /// Returns the height below which the transactions of the blocks are pruned.
+    pub fn pruned_below(&self) -> StorageResult<Option<BlockHeight>> {
+        self.pruned_below_by_key(PRUNED_BELOW)
+    }
+
+    /// Returns `true` if the transactions of the block at the `height` are pruned.
+    pub fn is_pruned(&self, height: &BlockHeight) -> StorageResult<bool> {
+        let pruned = self
+            .pruned_below()?
+            .map_or(false, |pruned_below| *height < pruned_below);
+        Ok(pruned)
+    }
+
+    /// Returns the height of the block of the transaction, if the transaction is pruned.
+    pub fn pruned_tx_height(&self, tx_id: &TxId) -> StorageResult<Option<BlockHeight>> {
+        let height = self
+            .storage::<PrunedTransactions>()
+            .get(tx_id)?
+            .map(|height| height.into_owned());
+        Ok(height)
+    }
+
+    /// Removes the transactions and the state history of the blocks below the `height`.
+    /// The block headers, consensus data and Merkle nodes are kept, because they are
+    /// required by the executor and `block_header_merkle_root`. The height of the block
+    /// is recorded for each pruned transaction for `PRUNED_TRANSACTIONS_INDEX_DEPTH`
+    /// blocks.
+    pub fn prune_blocks_below(&mut self, height: BlockHeight) -> StorageResult<()> {
+        let pruned_below =
+            self.prune_txs_below(PRUNED_BELOW, height, |db, block_height, tx_id| {
+                db.storage::<Transactions>().remove(tx_id)?;
+                db.storage::<PrunedTransactions>()
+                    .insert(tx_id, block_height)?;
+                Ok(())
+            })?;
+        if let Some(pruned_below) = pruned_below {
+            self.prune_state_history(pruned_below)?;
+            let index_below = u32::from(pruned_below)
+                .saturating_sub(PRUNED_TRANSACTIONS_INDEX_DEPTH)
+                .into();
+            self.prune_pruned_transactions_index_below(index_below)?;
+        }
+        Ok(())
+    }
+
+    /// Removes the records of the pruned transactions of the blocks below the `height`.
+    fn prune_pruned_transactions_index_below(
+        &mut self,
+        height: BlockHeight,
+    ) -> StorageResult<()> {
+        self.prune_txs_below(PRUNED_TRANSACTIONS_INDEX_BELOW, height, |db, _, tx_id| {
+            db.storage::<PrunedTransactions>().remove(tx_id)?;
+            Ok(())
+        })?;
+        Ok(())
+    }
+
+    /// Removes the receipts and statuses of the transactions of the blocks
+    /// below the `height`.
+    pub fn prune_receipts_and_statuses_below(
+        &mut self,
+        height: BlockHeight,
+    ) -> StorageResult<()> {
+        self.prune_txs_below(OFF_CHAIN_PRUNED_BELOW, height, |db, _, tx_id| {
+            db.storage::<Receipts>().remove(tx_id)?;
+            db.storage::<TransactionStatuses>().remove(tx_id)?;
+            Ok(())
+        })?;
+        Ok(())
+    }
+
+    fn pruned_below_by_key(&self, key: &str) -> StorageResult<Option<BlockHeight>> {
+        let height = self
+            .storage::<MetadataTable<u32>>()
+            .get(key)?
+            .map(|height| height.into_owned().into());
+        Ok(height)
+    }
+
+    /// Calls `prune` for the transactions of the next batch of blocks below the `height`
+    /// and records the progress under the `key`. Returns the new pruned height,
+    /// if any block was pruned.
+    fn prune_txs_below<F>(
+        &mut self,
+        key: &str,
+        height: BlockHeight,
+        mut prune: F,
+    ) -> StorageResult<Option<BlockHeight>>
+    where
+        F: FnMut(&mut Self, &BlockHeight, &TxId) -> StorageResult<()>,
+    {
+        let start = self.pruned_below_by_key(key)?;
+        if start.map_or(false, |start| start >= height) {
+            return Ok(None)
+        }
+
+        let blocks: Vec<_> = self
+            .all_block_ids(start, IterDirection::Forward)
+            .take_while(|result| {
+                result
+                    .as_ref()
+                    .map_or(true, |(block_height, _)| *block_height < height)
+            })
+            .take(PRUNING_BATCH_SIZE)
+            .try_collect()?;
+        let last_pruned = match blocks.last() {
+            Some((last_pruned, _)) => *last_pruned,
+            None => return Ok(None),
+        };
+
+        for (block_height, block_id) in blocks {
+            let tx_ids = match self.storage::<FuelBlocks>().get(&block_id)? {
+                Some(block) => block.transactions().to_vec(),
+                None => continue,
+            };
+            for tx_id in tx_ids.iter() {
+                prune(self, &block_height, tx_id)?;
+            }
+        }
+
+        let pruned_below = last_pruned.succ().unwrap_or(last_pruned);
+        self.storage::<MetadataTable<u32>>()
+            .insert(key, &pruned_below.into())?;
+        Ok(Some(pruned_below))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_storage::StorageMutate;
+    use fuel_core_types::{
+        blockchain::{
+            block::PartialFuelBlock,
+            header::{
+                ConsensusHeader,
+                PartialBlockHeader,
+            },
+            primitives::Empty,
+        },
+        fuel_tx::{
+            Transaction,
+            TransactionBuilder,
+            UniqueIdentifier,
+        },
+        fuel_types::ChainId,
+    };
+
+    fn store_blocks(database: &mut Database, count: u32) -> Vec<TxId> {
+        let chain_id = ChainId::default();
+        (0..count)
+            .map(|height| {
+                let tx: Transaction =
+                    TransactionBuilder::script(height.to_be_bytes().to_vec(), vec![])
+                        .finalize_as_transaction();
+                let header = PartialBlockHeader {
+                    application: Default::default(),
+                    consensus: ConsensusHeader::<Empty> {
+                        height: height.into(),
+                        ..Default::default()
+                    },
+                };
+                let block = PartialFuelBlock::new(header, vec![tx.clone()]).generate(&[]);
+                StorageMutate::<FuelBlocks>::insert(
+                    database,
+                    &block.id(),
+                    &block.compress(&chain_id),
+                )
+                .unwrap();
+                let tx_id = tx.id(&chain_id);
+                database
+                    .storage::<Transactions>()
+                    .insert(&tx_id, &tx)
+                    .unwrap();
+                tx_id
+            })
+            .collect()
+    }
+
+    #[test]
+    fn pruned_transactions_index_is_pruned_below_the_height() {
+        let mut database = Database::default();
+        let tx_ids = store_blocks(&mut database, 4);
+
+        database.prune_blocks_below(3.into()).unwrap();
+        database
+            .prune_pruned_transactions_index_below(2.into())
+            .unwrap();
+
+        assert_eq!(database.pruned_tx_height(&tx_ids[0]).unwrap(), None);
+        assert_eq!(database.pruned_tx_height(&tx_ids[1]).unwrap(), None);
+        assert_eq!(
+            database.pruned_tx_height(&tx_ids[2]).unwrap(),
+            Some(2.into())
+        );
+        assert_eq!(database.pruned_tx_height(&tx_ids[3]).unwrap(), None);
+        assert!(database
+            .storage::<Transactions>()
+            .contains_key(&tx_ids[3])
+            .unwrap());
+    }
+}
@@ -4,6 +4,7 @@ use fuel_core_storage::{
     tables::{
         FuelBlocks,
         SealedBlockConsensus,
+        Transactions as TransactionsTable,
     },
     Result as StorageResult,
     StorageAsRef,
@@ -19,6 +20,10 @@ use fuel_core_types::{
         SealedBlock,
         SealedBlockHeader,
     },
+    fuel_tx::{
+        Transaction,
+        TxId,
+    },
     fuel_types::BlockHeight,
     services::p2p::Transactions,
 };
@@ -117,10 +122,15 @@ impl Database {
         }
     }
 
+    /// Returns the transactions of the blocks in the `block_height_range`,
+    /// or `None` if the transactions of any block are pruned.
     pub fn get_transactions_on_blocks(
         &self,
         block_height_range: Range<u32>,
     ) -> StorageResult<Option<Vec<Transactions>>> {
+        if self.is_pruned(&block_height_range.start.into())? {
+            return Ok(None)
+        }
         let transactions = block_height_range
             .into_iter()
             .map(BlockHeight::from)
@@ -134,4 +144,21 @@ impl Database {
             .collect::<StorageResult<_>>()?;
         Ok(transactions)
     }
+
+    /// Returns the transactions with the `tx_ids`, or `None` if any of them is unknown.
+    pub fn get_transactions_by_ids(
+        &self,
+        tx_ids: &[TxId],
+    ) -> StorageResult<Option<Vec<Transaction>>> {
+        tx_ids
+            .iter()
+            .map(|tx_id| {
+                let transaction = self
+                    .storage::<TransactionsTable>()
+                    .get(tx_id)?
+                    .map(|transaction| transaction.into_owned());
+                Ok(transaction)
+            })
+            .collect()
+    }
 }
@@ -2,7 +2,11 @@ use crate::{
     database::{
         block::FuelBlockSecondaryKeyBlockHeights,
         coin::OwnedCoins,
-        message::OwnedMessageIds,
+        message::{
+            OwnedMessageIds,
+            RelayedMessages,
+        },
+        pruning::PrunedTransactions,
         transactions::{
             OwnedTransactions,
             TransactionStatuses,
@@ -88,7 +92,9 @@ use_structured_implementation!(
     TransactionStatuses,
     FuelBlockSecondaryKeyBlockHeights,
     FuelBlockMerkleData,
-    FuelBlockMerkleMetadata
+    FuelBlockMerkleMetadata,
+    PrunedTransactions,
+    RelayedMessages
 );
 #[cfg(feature = "relayer")]
 use_structured_implementation!(fuel_core_relayer::ports::RelayerMetadata);
@@ -25,6 +25,7 @@ pub struct Config {
     pub min_gas_price: u64,
     pub max_tx: usize,
     pub max_depth: usize,
+    pub block_gas_limit: u64,
     pub consensus_parameters: ConsensusParameters,
     pub consensus_key: Option<Secret<SecretKeyWrapper>>,
 }
@@ -12,6 +12,7 @@ use crate::{
             TxPoolPort,
         },
         view_extension::ViewExtension,
+        worker_service,
         Config,
     },
     schema::{
@@ -174,6 +175,7 @@ pub fn new_service<OnChain, OffChain>(
     producer: BlockProducer,
     consensus_module: ConsensusModule,
     p2p_service: P2pService,
+    off_chain_worker: worker_service::SharedState,
     log_threshold_ms: Duration,
     request_timeout: Duration,
 ) -> anyhow::Result<Service>
@@ -191,6 +193,7 @@ where
         .data(producer)
         .data(consensus_module)
         .data(p2p_service)
+        .data(off_chain_worker)
         .extension(async_graphql::extensions::Tracing)
         .extension(MetricsExtension::new(log_threshold_ms))
         .extension(ViewExtension::new())
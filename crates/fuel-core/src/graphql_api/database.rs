@@ -112,6 +112,14 @@ impl DatabaseBlocks for ReadView {
     fn ids_of_latest_block(&self) -> StorageResult<(BlockHeight, BlockId)> {
         self.on_chain.ids_of_latest_block()
     }
+
+    fn pruned_below(&self) -> StorageResult<Option<BlockHeight>> {
+        self.on_chain.pruned_below()
+    }
+
+    fn pruned_tx_height(&self, tx_id: &TxId) -> StorageResult<Option<BlockHeight>> {
+        self.on_chain.pruned_tx_height(tx_id)
+    }
 }
 
 impl<M> StorageInspect<M> for ReadView
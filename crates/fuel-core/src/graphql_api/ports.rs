@@ -20,7 +20,10 @@ use fuel_core_storage::{
     Result as StorageResult,
     StorageInspect,
 };
-use fuel_core_txpool::service::TxStatusMessage;
+use fuel_core_txpool::{
+    service::TxStatusMessage,
+    TxInfo,
+};
 use fuel_core_types::{
     blockchain::primitives::{
         BlockId,
@@ -46,15 +49,22 @@ use fuel_core_types::{
     },
     services::{
         graphql_api::ContractBalance,
-        p2p::PeerInfo,
+        p2p::{
+            PeerId,
+            PeerInfo,
+        },
         txpool::{
+            ArcPoolTx,
             InsertionResult,
             TransactionStatus,
         },
     },
     tai64::Tai64,
 };
-use std::sync::Arc;
+use std::{
+    sync::Arc,
+    time::Duration,
+};
 
 pub trait OffChainDatabase:
     Send + Sync + StorageInspect<Receipts, Error = StorageError>
@@ -111,6 +121,12 @@ pub trait DatabaseBlocks:
     ) -> BoxedIter<'_, StorageResult<(BlockHeight, BlockId)>>;
 
     fn ids_of_latest_block(&self) -> StorageResult<(BlockHeight, BlockId)>;
+
+    /// Returns the height below which the transactions, receipts and statuses are pruned.
+    fn pruned_below(&self) -> StorageResult<Option<BlockHeight>>;
+
+    /// Returns the height of the block of the transaction, if the transaction is pruned.
+    fn pruned_tx_height(&self, tx_id: &TxId) -> StorageResult<Option<BlockHeight>>;
 }
 
 /// Trait that specifies all the getters required for messages.
@@ -153,6 +169,15 @@ pub trait TxPoolPort: Send + Sync {
 
     fn submission_time(&self, id: TxId) -> Option<Tai64>;
 
+    /// Returns all transactions inside of the txpool.
+    fn pending_transactions(&self) -> Vec<TxInfo>;
+
+    /// Returns the sum of the max gas of all transactions inside of the txpool.
+    fn consumable_gas(&self) -> u64;
+
+    /// Returns the transactions inside of the txpool sorted from the highest gas price.
+    fn sorted_includable(&self) -> Vec<ArcPoolTx>;
+
     async fn insert(
         &self,
         txs: Vec<Arc<Transaction>>,
@@ -197,6 +222,25 @@ pub trait DatabaseMessageProof: Send + Sync {
 #[async_trait::async_trait]
 pub trait P2pPort: Send + Sync {
     async fn all_peer_info(&self) -> anyhow::Result<Vec<PeerInfo>>;
+
+    /// Bans the peer for the `duration`, or permanently if it is not specified.
+    async fn ban_peer(
+        &self,
+        peer_id: PeerId,
+        duration: Option<Duration>,
+    ) -> anyhow::Result<()>;
+
+    async fn unban_peer(&self, peer_id: PeerId) -> anyhow::Result<()>;
+
+    /// Dials the peer at the multi-address.
+    async fn dial_peer(&self, address: String) -> anyhow::Result<()>;
+
+    async fn disconnect_peer(&self, peer_id: PeerId) -> anyhow::Result<()>;
+
+    /// Adds the peer at the multi-address, ending with the peer id, to the reserved peers.
+    async fn add_reserved_peer(&self, address: String) -> anyhow::Result<()>;
+
+    async fn remove_reserved_peer(&self, peer_id: PeerId) -> anyhow::Result<()>;
 }
 
 pub mod worker {
@@ -209,6 +253,8 @@ pub mod worker {
         StorageMutate,
     };
     use fuel_core_types::{
+        blockchain::primitives::DaBlockHeight,
+        entities::message::Message,
         fuel_tx::{
             Address,
             Bytes32,
@@ -239,6 +285,23 @@ pub mod worker {
             id: &Bytes32,
             status: TransactionStatus,
         ) -> StorageResult<Option<TransactionStatus>>;
+
+        /// Removes the receipts and statuses of the transactions of the blocks
+        /// below the `height`.
+        fn prune_receipts_and_statuses_below(
+            &mut self,
+            height: BlockHeight,
+        ) -> StorageResult<()>;
+
+        /// Returns the messages relayed from the DA layer that became available with
+        /// the block at the `block_height`, i.e. the messages above the DA height
+        /// of the previous block and up to the `da_height` of the block.
+        /// The messages spent since then are returned as well.
+        fn relayed_messages(
+            &self,
+            block_height: &BlockHeight,
+            da_height: DaBlockHeight,
+        ) -> StorageResult<Vec<Message>>;
     }
 
     pub trait BlockImporter {
@@ -1,7 +1,6 @@
 use crate::fuel_core_graphql_api::ports;
 use fuel_core_services::{
     stream::BoxStream,
-    EmptyShared,
     RunnableService,
     RunnableTask,
     ServiceRunner,
@@ -13,24 +12,38 @@ use fuel_core_storage::{
     StorageAsMut,
 };
 use fuel_core_types::{
-    blockchain::block::Block,
+    entities::{
+        coins::coin::Coin,
+        message::Message,
+    },
     fuel_tx::{
         field::{
             Inputs,
             Outputs,
         },
-        input::coin::{
-            CoinPredicate,
-            CoinSigned,
+        input::{
+            coin::{
+                CoinPredicate,
+                CoinSigned,
+            },
+            message::{
+                MessageCoinPredicate,
+                MessageCoinSigned,
+                MessageDataPredicate,
+                MessageDataSigned,
+            },
         },
         Input,
         Output,
         Receipt,
         Transaction,
         TxId,
+        TxPointer,
         UniqueIdentifier,
+        UtxoId,
     },
     fuel_types::{
+        Address,
         BlockHeight,
         Bytes32,
     },
@@ -39,7 +52,14 @@ use fuel_core_types::{
             ImportResult,
             SharedImportResult,
         },
-        executor::TransactionExecutionStatus,
+        executor::{
+            TransactionExecutionResult,
+            TransactionExecutionStatus,
+        },
+        graphql_api::{
+            OwnerChange,
+            OwnerEvent,
+        },
         txpool::from_executor_to_status,
     },
 };
@@ -47,12 +67,48 @@ use futures::{
     FutureExt,
     StreamExt,
 };
+use std::{
+    collections::HashSet,
+    sync::Arc,
+};
+use tokio::sync::{
+    broadcast,
+    watch,
+};
+
+/// The number of blocks with owner events that a slow subscriber can fall behind
+/// before it starts to miss them.
+const OWNER_EVENTS_CAPACITY: usize = 1024;
+
+/// The owner events of all transactions within one block.
+pub type SharedOwnerEvents = Arc<[OwnerEvent]>;
+
+/// The shared state of the off-chain GraphQL API worker.
+#[derive(Clone)]
+pub struct SharedState {
+    /// The height of the latest block processed by the worker. Subscribers are notified
+    /// only after all off-chain information about the block is committed.
+    pub block_height: watch::Receiver<BlockHeight>,
+    owner_events: broadcast::Sender<SharedOwnerEvents>,
+}
+
+impl SharedState {
+    /// Subscribes to the owner events of the blocks processed by the worker.
+    pub fn subscribe_owner_events(&self) -> broadcast::Receiver<SharedOwnerEvents> {
+        self.owner_events.subscribe()
+    }
+}
 
 /// The off-chain GraphQL API worker task processes the imported blocks
 /// and actualize the information used by the GraphQL service.
 pub struct Task<D> {
     block_importer: BoxStream<SharedImportResult>,
     database: D,
+    block_height: watch::Sender<BlockHeight>,
+    owner_events: broadcast::Sender<SharedOwnerEvents>,
+    /// The number of the latest blocks that keep receipts and statuses.
+    /// The older blocks are pruned. `None` disables pruning.
+    pruning_depth: Option<u32>,
 }
 
 impl<D> Task<D>
@@ -68,21 +124,49 @@ where
         self.persist_transaction_status(&result, transaction.as_mut())?;
 
         // save the associated owner for each transaction in the block
-        self.index_tx_owners_for_block(
-            &result.sealed_block.entity,
-            transaction.as_mut(),
-        )?;
+        let owner_events =
+            self.index_tx_owners_for_block(&result, transaction.as_mut())?;
+
+        let height = *result.sealed_block.entity.header().height();
+        if let Some(pruning_depth) = self.pruning_depth {
+            let pruned_below = u32::from(height).saturating_sub(pruning_depth);
+            transaction
+                .as_mut()
+                .prune_receipts_and_statuses_below(pruned_below.into())?;
+        }
         transaction.commit()?;
 
+        // It is fine if nobody is subscribed to the events.
+        let _ = self.owner_events.send(owner_events.into());
+        self.block_height.send_replace(height);
+
         Ok(())
     }
 
-    /// Associate all transactions within a block to their respective UTXO owners
+    /// Associate all transactions within a block to their respective UTXO owners.
+    /// Returns the changes of the coins and messages for each owner, including
+    /// the messages relayed from the DA layer with the block.
     fn index_tx_owners_for_block(
         &self,
-        block: &Block,
+        import_result: &ImportResult,
         block_st_transaction: &mut D,
-    ) -> anyhow::Result<()> {
+    ) -> anyhow::Result<Vec<OwnerEvent>> {
+        let block = &import_result.sealed_block.entity;
+        let header = block.header();
+        let relayed_messages =
+            block_st_transaction.relayed_messages(header.height(), header.da_height)?;
+        let mut owner_events =
+            Self::relayed_message_events(*header.height(), relayed_messages);
+
+        let reverted: HashSet<_> = import_result
+            .tx_status
+            .iter()
+            .filter(|status| {
+                matches!(status.result, TransactionExecutionResult::Failed { .. })
+            })
+            .map(|status| status.id)
+            .collect();
+
         for (tx_idx, tx) in block.transactions().iter().enumerate() {
             let block_height = *block.header().height();
             let inputs;
@@ -112,8 +196,160 @@ where
                 tx_idx,
                 block_st_transaction,
             )?;
+            owner_events.extend(Self::owner_events(
+                TxPointer::new(block_height, tx_idx),
+                inputs,
+                outputs,
+                &tx_id,
+                reverted.contains(&tx_id),
+            ));
         }
-        Ok(())
+        Ok(owner_events)
+    }
+
+    /// Collects the changes of coins and messages caused by the transaction.
+    /// It follows the same rules as the executor when it updates the UTXO set.
+    fn owner_events(
+        tx_pointer: TxPointer,
+        inputs: &[Input],
+        outputs: &[Output],
+        tx_id: &TxId,
+        reverted: bool,
+    ) -> Vec<OwnerEvent> {
+        let event = |owner: Address, change: OwnerChange| OwnerEvent {
+            owner,
+            tx_id: Some(*tx_id),
+            block_height: tx_pointer.block_height(),
+            change,
+        };
+        let mut events = vec![];
+
+        for input in inputs {
+            match input {
+                Input::CoinSigned(CoinSigned {
+                    utxo_id,
+                    owner,
+                    amount,
+                    asset_id,
+                    tx_pointer,
+                    maturity,
+                    ..
+                })
+                | Input::CoinPredicate(CoinPredicate {
+                    utxo_id,
+                    owner,
+                    amount,
+                    asset_id,
+                    tx_pointer,
+                    maturity,
+                    ..
+                }) => {
+                    let coin = Coin {
+                        utxo_id: *utxo_id,
+                        owner: *owner,
+                        amount: *amount,
+                        asset_id: *asset_id,
+                        maturity: *maturity,
+                        tx_pointer: *tx_pointer,
+                    };
+                    events.push(event(*owner, OwnerChange::CoinSpent(coin)));
+                }
+                Input::MessageDataSigned(_) | Input::MessageDataPredicate(_)
+                    if reverted =>
+                {
+                    // The retryable messages are not spent if transaction is reverted
+                }
+                Input::MessageCoinSigned(MessageCoinSigned {
+                    recipient,
+                    nonce,
+                    amount,
+                    ..
+                })
+                | Input::MessageCoinPredicate(MessageCoinPredicate {
+                    recipient,
+                    nonce,
+                    amount,
+                    ..
+                })
+                | Input::MessageDataSigned(MessageDataSigned {
+                    recipient,
+                    nonce,
+                    amount,
+                    ..
+                })
+                | Input::MessageDataPredicate(MessageDataPredicate {
+                    recipient,
+                    nonce,
+                    amount,
+                    ..
+                }) => {
+                    let change = OwnerChange::MessageSpent {
+                        nonce: *nonce,
+                        amount: *amount,
+                    };
+                    events.push(event(*recipient, change));
+                }
+                Input::Contract(_) => {}
+            }
+        }
+
+        for (output_index, output) in outputs.iter().enumerate() {
+            match output {
+                Output::Coin {
+                    to,
+                    amount,
+                    asset_id,
+                }
+                | Output::Change {
+                    to,
+                    amount,
+                    asset_id,
+                }
+                | Output::Variable {
+                    to,
+                    amount,
+                    asset_id,
+                } => {
+                    // The executor doesn't create coins with zero amount.
+                    if *amount == 0 {
+                        continue
+                    }
+                    let output_index = u8::try_from(output_index)
+                        .expect("Transaction can have only up to `u8::MAX` outputs");
+                    let coin = Coin {
+                        utxo_id: UtxoId::new(*tx_id, output_index),
+                        owner: *to,
+                        amount: *amount,
+                        asset_id: *asset_id,
+                        maturity: 0u32.into(),
+                        tx_pointer,
+                    };
+                    events.push(event(*to, OwnerChange::CoinCreated(coin)));
+                }
+                Output::Contract(_) | Output::ContractCreated { .. } => {}
+            }
+        }
+
+        events
+    }
+
+    /// Creates the events for the recipients of the messages relayed with the block.
+    fn relayed_message_events(
+        block_height: BlockHeight,
+        messages: Vec<Message>,
+    ) -> Vec<OwnerEvent> {
+        messages
+            .into_iter()
+            .map(|message| OwnerEvent {
+                owner: message.recipient,
+                tx_id: None,
+                block_height,
+                change: OwnerChange::MessageCreated {
+                    nonce: message.nonce,
+                    amount: message.amount,
+                },
+            })
+            .collect()
     }
 
     /// Index the tx id by owner for all of the inputs and outputs
@@ -205,12 +441,15 @@ where
     D: ports::worker::OffChainDatabase,
 {
     const NAME: &'static str = "GraphQL_Off_Chain_Worker";
-    type SharedData = EmptyShared;
+    type SharedData = SharedState;
     type Task = Self;
     type TaskParams = ();
 
     fn shared_data(&self) -> Self::SharedData {
-        EmptyShared
+        SharedState {
+            block_height: self.block_height.subscribe(),
+            owner_events: self.owner_events.clone(),
+        }
     }
 
     async fn into_task(
@@ -271,14 +510,125 @@ where
     }
 }
 
-pub fn new_service<I, D>(block_importer: I, database: D) -> ServiceRunner<Task<D>>
+pub fn new_service<I, D>(
+    block_importer: I,
+    database: D,
+    block_height: BlockHeight,
+    pruning_depth: Option<u32>,
+) -> ServiceRunner<Task<D>>
 where
     I: ports::worker::BlockImporter,
     D: ports::worker::OffChainDatabase,
 {
     let block_importer = block_importer.block_events();
+    let (block_height, _) = watch::channel(block_height);
+    let (owner_events, _) = broadcast::channel(OWNER_EVENTS_CAPACITY);
     ServiceRunner::new(Task {
         block_importer,
         database,
+        block_height,
+        owner_events,
+        pruning_depth,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::Database;
+    use fuel_core_services::stream::IntoBoxStream;
+    use fuel_core_storage::tables::{
+        FuelBlocks,
+        Messages,
+    };
+    use fuel_core_types::{
+        blockchain::{
+            block::Block,
+            consensus::Consensus,
+            SealedBlock,
+        },
+        fuel_types::ChainId,
+    };
+
+    fn block(height: u32, da_height: u64) -> Block {
+        let mut block = Block::default();
+        block.header_mut().consensus.height = height.into();
+        block.header_mut().application.da_height = da_height.into();
+        block.header_mut().recalculate_metadata();
+        block
+    }
+
+    fn insert_block(database: &mut Database, block: &Block) {
+        database
+            .storage_as_mut::<FuelBlocks>()
+            .insert(&block.id(), &block.compress(&ChainId::default()))
+            .unwrap();
+    }
+
+    #[test]
+    fn relayed_messages_are_reported_as_created_for_the_recipient() {
+        let mut database = Database::default();
+        let recipient = Address::new([1; 32]);
+        let previous_block = block(1, 1);
+        let imported_block = block(2, 3);
+        insert_block(&mut database, &previous_block);
+        insert_block(&mut database, &imported_block);
+
+        // Only the messages above the DA height of the previous block and up to
+        // the DA height of the imported block are relayed with it.
+        let messages: Vec<_> = (1..=4u64)
+            .map(|da_height| Message {
+                recipient,
+                nonce: da_height.into(),
+                amount: da_height,
+                da_height: da_height.into(),
+                ..Default::default()
+            })
+            .collect();
+        for message in messages.iter() {
+            database
+                .storage_as_mut::<Messages>()
+                .insert(&message.nonce, message)
+                .unwrap();
+        }
+        // The message spent before the block is processed is still reported.
+        database
+            .storage_as_mut::<Messages>()
+            .remove(&messages[1].nonce)
+            .unwrap();
+
+        let (block_height, _) = watch::channel(1u32.into());
+        let (owner_events, mut receiver) = broadcast::channel(1);
+        let mut task = Task {
+            block_importer: futures::stream::pending().into_boxed(),
+            database,
+            block_height,
+            owner_events,
+            pruning_depth: None,
+        };
+
+        // When
+        let sealed_block = SealedBlock {
+            entity: imported_block,
+            consensus: Consensus::default(),
+        };
+        task.process_block(Arc::new(ImportResult::new_from_local(sealed_block, vec![])))
+            .unwrap();
+
+        // Then
+        let expected: Vec<_> = messages[1..3]
+            .iter()
+            .map(|message| OwnerEvent {
+                owner: recipient,
+                tx_id: None,
+                block_height: 2u32.into(),
+                change: OwnerChange::MessageCreated {
+                    nonce: message.nonce,
+                    amount: message.amount,
+                },
+            })
+            .collect();
+        let events = receiver.try_recv().unwrap();
+        assert_eq!(events.as_ref(), expected.as_slice());
+    }
+}
@@ -259,11 +259,10 @@ pub async fn make_nodes(
                         config.clone(),
                     );
                     if let Some(BootstrapSetup { pub_key, .. }) = boot {
-                        match &mut node_config.chain_conf.consensus {
-                            crate::chain_config::ConsensusConfig::PoA { signing_key } => {
-                                *signing_key = pub_key;
-                            }
-                        }
+                        node_config.chain_conf.consensus =
+                            crate::chain_config::ConsensusConfig::PoA {
+                                signing_key: pub_key,
+                            };
                     }
                     Bootstrap::new(&node_config).await
                 }
@@ -308,11 +307,10 @@ pub async fn make_nodes(
 
             node_config.utxo_validation = utxo_validation;
             let pub_key = secret.public_key();
-            match &mut node_config.chain_conf.consensus {
-                crate::chain_config::ConsensusConfig::PoA { signing_key } => {
-                    *signing_key = Input::owner(&pub_key);
-                }
-            }
+            node_config.chain_conf.consensus =
+                crate::chain_config::ConsensusConfig::PoA {
+                    signing_key: Input::owner(&pub_key),
+                };
 
             node_config.consensus_key = Some(Secret::new(secret.into()));
 
@@ -352,11 +350,10 @@ pub async fn make_nodes(
                     node_config.p2p.as_mut().unwrap().reserved_nodes = boots.clone();
                 }
             }
-            match &mut node_config.chain_conf.consensus {
-                crate::chain_config::ConsensusConfig::PoA { signing_key } => {
-                    *signing_key = pub_key;
-                }
-            }
+            node_config.chain_conf.consensus =
+                crate::chain_config::ConsensusConfig::PoA {
+                    signing_key: pub_key,
+                };
         }
         validators.push(make_node(node_config, Vec::with_capacity(0)).await)
     }
@@ -368,6 +365,46 @@ pub async fn make_nodes(
     }
 }
 
+/// Set of BFT validators connected through the bootstrap node.
+pub struct BftNodes {
+    pub bootstrap: Bootstrap,
+    pub validators: Vec<Node>,
+}
+
+/// Creates the network where each node is a BFT validator with one of the `secrets`.
+/// All validators are part of the validator set and produce blocks every `block_time`.
+pub async fn make_bft_nodes(
+    secrets: impl IntoIterator<Item = SecretKey>,
+    block_time: Duration,
+    config: Option<Config>,
+) -> BftNodes {
+    let secrets: Vec<_> = secrets.into_iter().collect();
+    let mut config = config.unwrap_or(Config::local_node());
+    config.chain_conf.consensus = crate::chain_config::ConsensusConfig::Bft {
+        validators: secrets
+            .iter()
+            .map(|secret| Input::owner(&secret.public_key()))
+            .collect(),
+    };
+
+    let bootstrap = Bootstrap::new(&make_config("b:0".to_string(), config.clone())).await;
+    let boots = bootstrap.listeners();
+
+    let mut validators = Vec::with_capacity(secrets.len());
+    for (i, secret) in secrets.into_iter().enumerate() {
+        let mut node_config = make_config(format!("bft:{i}"), config.clone());
+        node_config.block_production = Trigger::Interval { block_time };
+        node_config.p2p.as_mut().unwrap().bootstrap_nodes = boots.clone();
+        node_config.consensus_key = Some(Secret::new(secret.into()));
+        validators.push(make_node(node_config, Vec::with_capacity(0)).await);
+    }
+
+    BftNodes {
+        bootstrap,
+        validators,
+    }
+}
+
 pub fn make_config(name: String, mut node_config: Config) -> Config {
     node_config.p2p = Config::local_node().p2p;
     node_config.utxo_validation = true;
@@ -3,6 +3,7 @@ mod block;
 mod chain;
 mod coin;
 mod contract;
+mod gas_price;
 mod message;
 mod subscriptions;
 mod tx;
@@ -13,6 +14,7 @@ pub use block::*;
 pub use chain::*;
 pub use coin::*;
 pub use contract::*;
+pub use gas_price::*;
 pub use message::*;
 pub(crate) use subscriptions::*;
 pub use tx::*;
@@ -19,8 +19,11 @@ use fuel_core_types::{
         consensus::Consensus,
         primitives::BlockId,
     },
+    fuel_tx::TxId,
     fuel_types::BlockHeight,
 };
+use futures::Stream;
+use tokio::sync::watch;
 
 pub trait SimpleBlockData: Send + Sync {
     fn block(&self, id: &BlockId) -> StorageResult<CompressedBlock>;
@@ -54,6 +57,12 @@ pub trait BlockQueryData: Send + Sync + SimpleBlockData {
     ) -> BoxedIter<StorageResult<CompressedBlock>>;
 
     fn consensus(&self, id: &BlockId) -> StorageResult<Consensus>;
+
+    /// Returns an error if the transactions of the block at the `height` are pruned.
+    fn ensure_not_pruned(&self, height: &BlockHeight) -> StorageResult<()>;
+
+    /// Returns an error if the transaction is pruned.
+    fn ensure_tx_not_pruned(&self, tx_id: &TxId) -> StorageResult<()>;
 }
 
 impl<D: OnChainDatabase + ?Sized> BlockQueryData for D {
@@ -95,4 +104,65 @@ impl<D: OnChainDatabase + ?Sized> BlockQueryData for D {
             .map(|c| c.map(|c| c.into_owned()))?
             .ok_or(not_found!(SealedBlockConsensus))
     }
+
+    fn ensure_not_pruned(&self, height: &BlockHeight) -> StorageResult<()> {
+        match self.pruned_below()? {
+            Some(pruned_below) if *height < pruned_below => Err(anyhow::anyhow!(
+                "The transactions of the block at height {height} are pruned, \
+                the node keeps them only from the height {pruned_below}"
+            )
+            .into()),
+            _ => Ok(()),
+        }
+    }
+
+    fn ensure_tx_not_pruned(&self, tx_id: &TxId) -> StorageResult<()> {
+        match self.pruned_tx_height(tx_id)? {
+            Some(height) => self.ensure_not_pruned(&height),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Returns the stream of blocks starting from the `from_height`.
+///
+/// The blocks already available in the database are replayed first. After that, the stream
+/// waits for the `latest_height` updates and returns new blocks as soon as they appear.
+/// The database is the source of truth for the stream, and `latest_height` is used only
+/// as a notification, so the stream never skips blocks even if it is polled slowly.
+///
+/// The stream ends after the first error or when the sender of `latest_height` is dropped.
+pub(crate) fn new_blocks_stream<'a, View, F>(
+    view: F,
+    latest_height: watch::Receiver<BlockHeight>,
+    from_height: BlockHeight,
+) -> impl Stream<Item = StorageResult<CompressedBlock>> + 'a
+where
+    F: Fn() -> View + Send + Sync + 'a,
+    View: BlockQueryData,
+{
+    let state = (view, latest_height, Some(from_height));
+    futures::stream::unfold(state, |(view, mut latest_height, next_height)| async move {
+        let next_height = next_height?;
+        loop {
+            let latest = *latest_height.borrow_and_update();
+
+            if next_height <= latest {
+                let database = view();
+                let result = database
+                    .block_id(&next_height)
+                    .and_then(|id| database.block(&id));
+                let next_height = match &result {
+                    Ok(_) => next_height.succ(),
+                    Err(_) => None,
+                };
+                return Some((result, (view, latest_height, next_height)))
+            }
+
+            if latest_height.changed().await.is_err() {
+                // The sender is dropped, so there will be no new blocks.
+                return None
+            }
+        }
+    })
 }
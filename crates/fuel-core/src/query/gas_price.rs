@@ -0,0 +1,111 @@
+use crate::{
+    fuel_core_graphql_api::ports::{
+        OffChainDatabase,
+        OnChainDatabase,
+    },
+    query::{
+        BlockQueryData,
+        SimpleTransactionData,
+    },
+};
+use fuel_core_storage::{
+    iter::IterDirection,
+    Result as StorageResult,
+};
+use fuel_core_types::{
+    fuel_asm::Word,
+    fuel_tx::{
+        Chargeable,
+        Transaction,
+    },
+};
+
+#[cfg(test)]
+mod test;
+
+/// The suggested gas prices for a new transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GasPriceEstimate {
+    pub low: Word,
+    pub medium: Word,
+    pub high: Word,
+}
+
+pub trait GasPriceQueryData: Send + Sync {
+    /// Returns the gas prices of the transactions included in the latest
+    /// `block_horizon` blocks. Blocks with pruned transactions are skipped.
+    fn recent_gas_prices(&self, block_horizon: u32) -> StorageResult<Vec<Word>>;
+}
+
+impl<D> GasPriceQueryData for D
+where
+    D: OnChainDatabase + OffChainDatabase + ?Sized,
+{
+    fn recent_gas_prices(&self, block_horizon: u32) -> StorageResult<Vec<Word>> {
+        let mut prices = vec![];
+        let blocks = self
+            .compressed_blocks(None, IterDirection::Reverse)
+            .take(block_horizon as usize);
+
+        for block in blocks {
+            let block = block?;
+            if self.ensure_not_pruned(block.header().height()).is_err() {
+                // The blocks below are pruned too.
+                break
+            }
+
+            for tx_id in block.transactions() {
+                match self.transaction(tx_id)? {
+                    Transaction::Script(script) => prices.push(script.price()),
+                    Transaction::Create(create) => prices.push(create.price()),
+                    Transaction::Mint(_) => {}
+                }
+            }
+        }
+        Ok(prices)
+    }
+}
+
+/// Suggests gas prices based on the gas prices included in the recent blocks
+/// and the pressure of the txpool.
+///
+/// `pool` contains the gas price and the max gas of the transactions inside of the
+/// txpool, sorted from the highest gas price. If the pool has more gas than fits into
+/// the next block, every suggested price is at least the price of the first transaction
+/// that doesn't fit, and `medium` and `high` outbid it.
+pub fn estimate_gas_price(
+    mut recent_prices: Vec<Word>,
+    pool: impl IntoIterator<Item = (Word, Word)>,
+    block_gas_limit: Word,
+    min_gas_price: Word,
+) -> GasPriceEstimate {
+    recent_prices.sort_unstable();
+    let percentile = |percent: usize| -> Word {
+        let index = recent_prices
+            .len()
+            .saturating_sub(1)
+            .saturating_mul(percent)
+            .checked_div(100)
+            .unwrap_or_default();
+        recent_prices
+            .get(index)
+            .copied()
+            .unwrap_or(min_gas_price)
+            .max(min_gas_price)
+    };
+
+    let mut pool_gas: Word = 0;
+    let clearing_price = pool.into_iter().find_map(|(price, max_gas)| {
+        pool_gas = pool_gas.saturating_add(max_gas);
+        (pool_gas > block_gas_limit).then_some(price)
+    });
+
+    let (low, outbid_price) = match clearing_price {
+        Some(price) => (percentile(25).max(price), price.saturating_add(1)),
+        None => (percentile(25), min_gas_price),
+    };
+    let medium = percentile(50).max(low).max(outbid_price);
+    let high = percentile(90).max(medium);
+
+    GasPriceEstimate { low, medium, high }
+}
@@ -0,0 +1,56 @@
+use super::*;
+
+const BLOCK_GAS_LIMIT: Word = 1000;
+const MIN_GAS_PRICE: Word = 1;
+
+#[test]
+fn estimate_without_history_and_pool_is_min_gas_price() {
+    let estimate = estimate_gas_price(vec![], vec![], BLOCK_GAS_LIMIT, MIN_GAS_PRICE);
+
+    assert_eq!(
+        estimate,
+        GasPriceEstimate {
+            low: MIN_GAS_PRICE,
+            medium: MIN_GAS_PRICE,
+            high: MIN_GAS_PRICE,
+        }
+    );
+}
+
+#[test]
+fn estimate_uses_percentiles_of_recent_prices() {
+    let recent_prices = (1..=100).rev().collect();
+    // The pool fits into the next block, so it doesn't affect the estimate.
+    let pool = vec![(500, 100), (400, 100)];
+
+    let estimate =
+        estimate_gas_price(recent_prices, pool, BLOCK_GAS_LIMIT, MIN_GAS_PRICE);
+
+    assert_eq!(
+        estimate,
+        GasPriceEstimate {
+            low: 25,
+            medium: 50,
+            high: 90,
+        }
+    );
+}
+
+#[test]
+fn estimate_outbids_transactions_that_do_not_fit_into_the_next_block() {
+    let recent_prices = vec![10, 20, 30];
+    // Only the first two transactions fit into the next block.
+    let pool = vec![(500, 400), (400, 400), (300, 400), (200, 400)];
+
+    let estimate =
+        estimate_gas_price(recent_prices, pool, BLOCK_GAS_LIMIT, MIN_GAS_PRICE);
+
+    assert_eq!(
+        estimate,
+        GasPriceEstimate {
+            low: 300,
+            medium: 301,
+            high: 301,
+        }
+    );
+}
@@ -1,15 +1,26 @@
-use crate::schema::tx::types::TransactionStatus as ApiTxStatus;
+use crate::{
+    fuel_core_graphql_api::worker_service::SharedOwnerEvents,
+    schema::tx::types::TransactionStatus as ApiTxStatus,
+};
 use fuel_core_storage::Result as StorageResult;
 use fuel_core_txpool::service::TxStatusMessage;
 use fuel_core_types::{
-    fuel_types::Bytes32,
-    services::txpool::TransactionStatus as TxPoolTxStatus,
+    fuel_types::{
+        Address,
+        AssetId,
+        Bytes32,
+    },
+    services::{
+        graphql_api::OwnerEvent,
+        txpool::TransactionStatus as TxPoolTxStatus,
+    },
 };
 use futures::{
     stream::BoxStream,
     Stream,
     StreamExt,
 };
+use tokio::sync::broadcast;
 
 #[cfg(test)]
 mod test;
@@ -79,3 +90,50 @@ where
             }
         })
 }
+
+/// Returns the stream of the events related to the `owner`. If the `asset_id`
+/// is specified, only events with this asset are returned.
+///
+/// If the stream is polled slower than the events arrive, it returns an error
+/// and closes. In such a case the subscription can be restarted.
+pub(crate) fn owner_events(
+    receiver: broadcast::Receiver<SharedOwnerEvents>,
+    owner: Address,
+    asset_id: Option<AssetId>,
+    base_asset_id: AssetId,
+) -> impl Stream<Item = anyhow::Result<OwnerEvent>> {
+    let is_relevant = move |event: &OwnerEvent| {
+        event.owner == owner
+            && asset_id
+                .map(|asset_id| event.change.asset_id(&base_asset_id) == &asset_id)
+                .unwrap_or(true)
+    };
+
+    futures::stream::unfold(Some(receiver), move |receiver| async move {
+        let mut receiver = receiver?;
+        loop {
+            match receiver.recv().await {
+                Ok(events) => {
+                    let events: Vec<_> = events
+                        .iter()
+                        .filter(|&event| is_relevant(event))
+                        .cloned()
+                        .map(Ok)
+                        .collect();
+
+                    if !events.is_empty() {
+                        return Some((events, Some(receiver)))
+                    }
+                }
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    let error = anyhow::anyhow!(
+                        "The subscription missed events of {skipped} blocks"
+                    );
+                    return Some((vec![Err(error)], None))
+                }
+                Err(broadcast::error::RecvError::Closed) => return None,
+            }
+        }
+    })
+    .flat_map(futures::stream::iter)
+}
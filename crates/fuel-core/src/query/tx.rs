@@ -1,6 +1,9 @@
-use crate::fuel_core_graphql_api::ports::{
-    OffChainDatabase,
-    OnChainDatabase,
+use crate::{
+    fuel_core_graphql_api::ports::{
+        OffChainDatabase,
+        OnChainDatabase,
+    },
+    query::BlockQueryData,
 };
 use fuel_core_storage::{
     iter::{
@@ -13,6 +16,7 @@ use fuel_core_storage::{
         Receipts,
         Transactions,
     },
+    IsNotFound,
     Result as StorageResult,
     StorageAsRef,
 };
@@ -40,15 +44,23 @@ where
     D: OnChainDatabase + OffChainDatabase + ?Sized,
 {
     fn transaction(&self, tx_id: &TxId) -> StorageResult<Transaction> {
-        self.storage::<Transactions>()
-            .get(tx_id)
-            .and_then(|v| v.ok_or(not_found!(Transactions)).map(|tx| tx.into_owned()))
+        match self.storage::<Transactions>().get(tx_id)? {
+            Some(tx) => Ok(tx.into_owned()),
+            None => {
+                self.ensure_tx_not_pruned(tx_id)?;
+                Err(not_found!(Transactions))
+            }
+        }
     }
 
     fn receipts(&self, tx_id: &TxId) -> StorageResult<Vec<Receipt>> {
-        self.storage::<Receipts>()
-            .get(tx_id)
-            .and_then(|v| v.ok_or(not_found!(Transactions)).map(|tx| tx.into_owned()))
+        match self.storage::<Receipts>().get(tx_id)? {
+            Some(receipts) => Ok(receipts.into_owned()),
+            None => {
+                self.ensure_tx_not_pruned(tx_id)?;
+                Err(not_found!(Transactions))
+            }
+        }
     }
 }
 
@@ -68,7 +80,11 @@ where
     D: OnChainDatabase + OffChainDatabase + ?Sized,
 {
     fn status(&self, tx_id: &TxId) -> StorageResult<TransactionStatus> {
-        self.tx_status(tx_id)
+        let status = self.tx_status(tx_id);
+        if status.is_not_found() {
+            self.ensure_tx_not_pruned(tx_id)?;
+        }
+        status
     }
 
     fn owned_transactions(
@@ -80,6 +96,7 @@ where
         self.owned_transactions_ids(owner, start, direction)
             .map(|result| {
                 result.and_then(|(tx_pointer, tx_id)| {
+                    self.ensure_not_pruned(&tx_pointer.block_height())?;
                     let tx = self.transaction(&tx_id)?;
 
                     Ok((tx_pointer, tx))
@@ -25,11 +25,14 @@ pub mod chain;
 pub mod coins;
 pub mod contract;
 pub mod dap;
+pub mod gas_price;
 pub mod health;
 pub mod message;
 pub mod node_info;
+pub mod owner_events;
 pub mod scalars;
 pub mod tx;
+pub mod txpool;
 
 #[derive(MergedObject, Default)]
 pub struct Query(
@@ -38,6 +41,8 @@ pub struct Query(
     block::BlockQuery,
     chain::ChainQuery,
     tx::TxQuery,
+    txpool::TxPoolQuery,
+    gas_price::GasPriceQuery,
     health::HealthQuery,
     coins::CoinQuery,
     contract::ContractQuery,
@@ -47,10 +52,19 @@ pub struct Query(
 );
 
 #[derive(MergedObject, Default)]
-pub struct Mutation(dap::DapMutation, tx::TxMutation, block::BlockMutation);
+pub struct Mutation(
+    dap::DapMutation,
+    tx::TxMutation,
+    block::BlockMutation,
+    node_info::PeerMutation,
+);
 
 #[derive(MergedSubscription, Default)]
-pub struct Subscription(tx::TxStatusSubscription);
+pub struct Subscription(
+    tx::TxStatusSubscription,
+    block::BlockSubscription,
+    owner_events::OwnerEventsSubscription,
+);
 
 pub type CoreSchema = Schema<Query, Mutation, Subscription>;
 pub type CoreSchemaBuilder = SchemaBuilder<Query, Mutation, Subscription>;
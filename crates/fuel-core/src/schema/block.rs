@@ -5,11 +5,16 @@ use super::scalars::{
 use crate::{
     fuel_core_graphql_api::{
         api_service::ConsensusModule,
-        database::ReadView,
+        database::{
+            ReadDatabase,
+            ReadView,
+        },
+        worker_service,
         Config as GraphQLConfig,
         IntoApiResult,
     },
     query::{
+        new_blocks_stream,
         BlockQueryData,
         SimpleBlockData,
         SimpleTransactionData,
@@ -33,6 +38,7 @@ use async_graphql::{
     Context,
     Object,
     SimpleObject,
+    Subscription,
     Union,
 };
 use fuel_core_storage::{
@@ -51,6 +57,10 @@ use fuel_core_types::{
     fuel_types,
     fuel_types::BlockHeight,
 };
+use futures::{
+    Stream,
+    TryStreamExt,
+};
 
 pub struct Block(pub(crate) CompressedBlock);
 
@@ -60,6 +70,7 @@ pub struct Header(pub(crate) BlockHeader);
 pub enum Consensus {
     Genesis(Genesis),
     PoA(PoAConsensus),
+    Bft(BftConsensus),
 }
 
 type CoreGenesis = fuel_core_types::blockchain::consensus::Genesis;
@@ -82,6 +93,11 @@ pub struct PoAConsensus {
     signature: Signature,
 }
 
+pub struct BftConsensus {
+    round: U32,
+    certificate: Vec<Signature>,
+}
+
 #[Object]
 impl Block {
     async fn id(&self) -> BlockId {
@@ -106,6 +122,7 @@ impl Block {
         ctx: &Context<'_>,
     ) -> async_graphql::Result<Vec<Transaction>> {
         let query: &ReadView = ctx.data_unchecked();
+        query.ensure_not_pruned(self.0.header().height())?;
         self.0
             .transactions()
             .iter()
@@ -179,6 +196,19 @@ impl PoAConsensus {
     }
 }
 
+#[Object]
+impl BftConsensus {
+    /// The round in which validators committed the block.
+    async fn round(&self) -> U32 {
+        self.round
+    }
+
+    /// The precommit signatures of the validators that committed the block.
+    async fn certificate(&self) -> Vec<Signature> {
+        self.certificate.clone()
+    }
+}
+
 #[derive(Default)]
 pub struct BlockQuery;
 
@@ -311,6 +341,47 @@ impl BlockMutation {
     }
 }
 
+#[derive(Default)]
+pub struct BlockSubscription;
+
+#[Subscription]
+impl BlockSubscription {
+    /// Returns a stream of blocks starting from the `from_height`.
+    /// If the `from_height` is not specified, the stream starts from the next block.
+    ///
+    /// The blocks that are already in the database are returned first, and after that
+    /// the stream returns new blocks as soon as they are imported, without gaps.
+    /// The block is returned only after all information about its transactions
+    /// is available in the API.
+    ///
+    /// This stream will wait forever so it's advised to use within a timeout.
+    async fn new_blocks<'a>(
+        &self,
+        ctx: &Context<'a>,
+        #[graphql(desc = "The height of the first block in the stream")]
+        from_height: Option<U32>,
+    ) -> async_graphql::Result<impl Stream<Item = async_graphql::Result<Block>> + 'a>
+    {
+        let database: &ReadDatabase = ctx.data_unchecked();
+        let worker = ctx.data_unchecked::<worker_service::SharedState>();
+        let latest_height = worker.block_height.clone();
+
+        let from_height = match from_height {
+            Some(height) => height.into(),
+            None => latest_height
+                .borrow()
+                .succ()
+                .ok_or(anyhow!("The blockchain reached the maximum height"))?,
+        };
+
+        Ok(
+            new_blocks_stream(move || database.view(), latest_height, from_height)
+                .map_ok(Block::from)
+                .map_err(async_graphql::Error::from),
+        )
+    }
+}
+
 impl From<CompressedBlock> for Block {
     fn from(block: CompressedBlock) -> Self {
         Block(block)
@@ -347,6 +418,10 @@ impl From<CoreConsensus> for Consensus {
             CoreConsensus::PoA(poa) => Consensus::PoA(PoAConsensus {
                 signature: poa.signature.into(),
             }),
+            CoreConsensus::Bft(bft) => Consensus::Bft(BftConsensus {
+                round: bft.round.into(),
+                certificate: bft.certificate.into_iter().map(Into::into).collect(),
+            }),
         }
     }
 }
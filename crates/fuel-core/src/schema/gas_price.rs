@@ -0,0 +1,88 @@
+use crate::{
+    fuel_core_graphql_api::{
+        api_service::TxPool,
+        database::ReadView,
+        Config as GraphQLConfig,
+    },
+    query::{
+        estimate_gas_price,
+        GasPriceEstimate as GasPriceEstimateModel,
+        GasPriceQueryData,
+    },
+    schema::scalars::{
+        U32,
+        U64,
+    },
+};
+use anyhow::anyhow;
+use async_graphql::{
+    Context,
+    Object,
+};
+
+/// The number of the latest blocks used for the estimation by default.
+const DEFAULT_BLOCK_HORIZON: u32 = 10;
+/// The maximum number of the latest blocks that can be used for the estimation.
+const MAX_BLOCK_HORIZON: u32 = 100;
+
+#[derive(Default)]
+pub struct GasPriceQuery;
+
+pub struct GasPriceEstimate(GasPriceEstimateModel);
+
+#[Object]
+impl GasPriceEstimate {
+    /// The gas price that may need to wait for a few blocks.
+    async fn low(&self) -> U64 {
+        self.0.low.into()
+    }
+
+    /// The gas price that is likely to be included in the next block.
+    async fn medium(&self) -> U64 {
+        self.0.medium.into()
+    }
+
+    /// The gas price that is paid by most of the recently included transactions.
+    async fn high(&self) -> U64 {
+        self.0.high.into()
+    }
+}
+
+#[Object]
+impl GasPriceQuery {
+    /// Suggests gas prices for a new transaction based on the gas prices included in
+    /// the latest blocks and the transactions waiting inside of the txpool.
+    async fn estimate_gas_price(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The number of the latest blocks used for the estimation")]
+        block_horizon: Option<U32>,
+    ) -> async_graphql::Result<GasPriceEstimate> {
+        let block_horizon = block_horizon
+            .map(|horizon| horizon.0)
+            .unwrap_or(DEFAULT_BLOCK_HORIZON);
+        if block_horizon > MAX_BLOCK_HORIZON {
+            return Err(anyhow!(
+                "The block horizon {block_horizon} is greater than the maximum {MAX_BLOCK_HORIZON}"
+            )
+            .into())
+        }
+
+        let query: &ReadView = ctx.data_unchecked();
+        let txpool = ctx.data_unchecked::<TxPool>();
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+
+        let recent_prices = query.recent_gas_prices(block_horizon)?;
+        let pool = txpool
+            .sorted_includable()
+            .into_iter()
+            .map(|tx| (tx.price(), tx.max_gas()));
+        let estimate = estimate_gas_price(
+            recent_prices,
+            pool,
+            config.block_gas_limit,
+            config.min_gas_price,
+        );
+        Ok(GasPriceEstimate(estimate))
+    }
+}
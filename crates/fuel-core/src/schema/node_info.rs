@@ -2,12 +2,20 @@ use super::scalars::{
     U32,
     U64,
 };
-use crate::fuel_core_graphql_api::Config as GraphQLConfig;
+use crate::fuel_core_graphql_api::{
+    api_service::P2pService,
+    Config as GraphQLConfig,
+};
+use anyhow::anyhow;
 use async_graphql::{
     Context,
     Object,
 };
-use std::time::UNIX_EPOCH;
+use fuel_core_types::services::p2p::PeerId;
+use std::time::{
+    Duration,
+    UNIX_EPOCH,
+};
 
 pub struct NodeInfo {
     utxo_validation: bool,
@@ -83,6 +91,97 @@ impl NodeQuery {
     }
 }
 
+#[derive(Default)]
+pub struct PeerMutation;
+
+#[Object]
+impl PeerMutation {
+    /// Disconnects and bans the peer for the `duration_secs`, or permanently if the duration
+    /// is not specified. Reserved peers can't be banned.
+    async fn ban_peer(
+        &self,
+        ctx: &Context<'_>,
+        peer_id: String,
+        duration_secs: Option<U64>,
+    ) -> async_graphql::Result<bool> {
+        let p2p = p2p_admin(ctx)?;
+        let duration = duration_secs.map(|secs| Duration::from_secs(secs.into()));
+        p2p.ban_peer(parse_peer_id(&peer_id)?, duration).await?;
+        Ok(true)
+    }
+
+    /// Lifts the ban of the peer.
+    async fn unban_peer(
+        &self,
+        ctx: &Context<'_>,
+        peer_id: String,
+    ) -> async_graphql::Result<bool> {
+        let p2p = p2p_admin(ctx)?;
+        p2p.unban_peer(parse_peer_id(&peer_id)?).await?;
+        Ok(true)
+    }
+
+    /// Dials the peer at the multi-address.
+    async fn connect_peer(
+        &self,
+        ctx: &Context<'_>,
+        address: String,
+    ) -> async_graphql::Result<bool> {
+        let p2p = p2p_admin(ctx)?;
+        p2p.dial_peer(address).await?;
+        Ok(true)
+    }
+
+    /// Disconnects the peer. The peer is allowed to connect again.
+    async fn disconnect_peer(
+        &self,
+        ctx: &Context<'_>,
+        peer_id: String,
+    ) -> async_graphql::Result<bool> {
+        let p2p = p2p_admin(ctx)?;
+        p2p.disconnect_peer(parse_peer_id(&peer_id)?).await?;
+        Ok(true)
+    }
+
+    /// Adds the peer to the reserved nodes and connects to it.
+    /// The multi-address must end with the peer id, like `/ip4/.../tcp/.../p2p/<peer id>`.
+    async fn add_reserved_peer(
+        &self,
+        ctx: &Context<'_>,
+        address: String,
+    ) -> async_graphql::Result<bool> {
+        let p2p = p2p_admin(ctx)?;
+        p2p.add_reserved_peer(address).await?;
+        Ok(true)
+    }
+
+    /// Removes the peer from the reserved nodes. The connection is kept
+    /// while there are free slots for non-reserved peers.
+    async fn remove_reserved_peer(
+        &self,
+        ctx: &Context<'_>,
+        peer_id: String,
+    ) -> async_graphql::Result<bool> {
+        let p2p = p2p_admin(ctx)?;
+        p2p.remove_reserved_peer(parse_peer_id(&peer_id)?).await?;
+        Ok(true)
+    }
+}
+
+fn p2p_admin<'a>(ctx: &Context<'a>) -> async_graphql::Result<&'a P2pService> {
+    let config = ctx.data_unchecked::<GraphQLConfig>();
+    if !config.debug {
+        return Err(anyhow!("`debug` must be enabled to use this endpoint").into())
+    }
+    Ok(ctx.data_unchecked::<P2pService>())
+}
+
+fn parse_peer_id(peer_id: &str) -> async_graphql::Result<PeerId> {
+    peer_id
+        .parse()
+        .map_err(|e| anyhow!("Invalid peer id {peer_id}: {e}").into())
+}
+
 struct PeerInfo(fuel_core_types::services::p2p::PeerInfo);
 
 #[Object]
@@ -0,0 +1,132 @@
+use crate::{
+    fuel_core_graphql_api::{
+        worker_service,
+        Config as GraphQLConfig,
+    },
+    query::owner_events,
+    schema::scalars::{
+        Address,
+        AssetId,
+        Nonce,
+        TransactionId,
+        UtxoId,
+        U32,
+        U64,
+    },
+};
+use async_graphql::{
+    Context,
+    Enum,
+    Object,
+    Subscription,
+};
+use fuel_core_types::services::graphql_api::{
+    OwnerChange,
+    OwnerEvent as OwnerEventModel,
+};
+use futures::{
+    Stream,
+    TryStreamExt,
+};
+
+pub struct OwnerEvent(pub(crate) OwnerEventModel);
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum OwnerEventKind {
+    CoinCreated,
+    CoinSpent,
+    MessageCreated,
+    MessageSpent,
+}
+
+#[Object]
+impl OwnerEvent {
+    async fn kind(&self) -> OwnerEventKind {
+        match self.0.change {
+            OwnerChange::CoinCreated(_) => OwnerEventKind::CoinCreated,
+            OwnerChange::CoinSpent(_) => OwnerEventKind::CoinSpent,
+            OwnerChange::MessageCreated { .. } => OwnerEventKind::MessageCreated,
+            OwnerChange::MessageSpent { .. } => OwnerEventKind::MessageSpent,
+        }
+    }
+
+    async fn owner(&self) -> Address {
+        self.0.owner.into()
+    }
+
+    /// The id of the transaction that caused the event. It is `null` for messages
+    /// relayed from the DA layer.
+    async fn transaction_id(&self) -> Option<TransactionId> {
+        self.0.tx_id.map(Into::into)
+    }
+
+    /// The height of the block that contains the event.
+    async fn block_height(&self) -> U32 {
+        self.0.block_height.into()
+    }
+
+    /// The `UtxoId` of the coin. It is `null` for message events.
+    async fn utxo_id(&self) -> Option<UtxoId> {
+        match &self.0.change {
+            OwnerChange::CoinCreated(coin) | OwnerChange::CoinSpent(coin) => {
+                Some(coin.utxo_id.into())
+            }
+            OwnerChange::MessageCreated { .. } | OwnerChange::MessageSpent { .. } => None,
+        }
+    }
+
+    /// The nonce of the message. It is `null` for coin events.
+    async fn nonce(&self) -> Option<Nonce> {
+        match &self.0.change {
+            OwnerChange::CoinCreated(_) | OwnerChange::CoinSpent(_) => None,
+            OwnerChange::MessageCreated { nonce, .. }
+            | OwnerChange::MessageSpent { nonce, .. } => Some((*nonce).into()),
+        }
+    }
+
+    async fn asset_id(&self, ctx: &Context<'_>) -> AssetId {
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+        let base_asset_id = config.consensus_parameters.base_asset_id();
+        (*self.0.change.asset_id(base_asset_id)).into()
+    }
+
+    async fn amount(&self) -> U64 {
+        self.0.change.amount().into()
+    }
+}
+
+#[derive(Default)]
+pub struct OwnerEventsSubscription;
+
+#[Subscription]
+impl OwnerEventsSubscription {
+    /// Returns a stream of coins and messages created or spent by transactions
+    /// for the `owner`, optionally filtered by the `asset_id`. Messages relayed
+    /// from the DA layer are reported as created by the block that includes them.
+    ///
+    /// The stream only contains events from blocks imported after the subscription.
+    ///
+    /// It is possible for the stream to miss events if it is polled slower
+    /// than the events arrive. In such a case the stream will return an error
+    /// and close. If this occurs the stream can simply be restarted.
+    async fn owner_events<'a>(
+        &self,
+        ctx: &Context<'a>,
+        #[graphql(desc = "The owner of coins and messages")] owner: Address,
+        #[graphql(desc = "Filters events by the asset")] asset_id: Option<AssetId>,
+    ) -> async_graphql::Result<impl Stream<Item = async_graphql::Result<OwnerEvent>> + 'a>
+    {
+        let worker = ctx.data_unchecked::<worker_service::SharedState>();
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+        let base_asset_id = *config.consensus_parameters.base_asset_id();
+
+        Ok(owner_events(
+            worker.subscribe_owner_events(),
+            owner.0,
+            asset_id.map(|id| id.0),
+            base_asset_id,
+        )
+        .map_ok(OwnerEvent)
+        .map_err(async_graphql::Error::from))
+    }
+}
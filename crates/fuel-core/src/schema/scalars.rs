@@ -156,6 +156,37 @@ impl CursorType for SortedTxCursor {
     }
 }
 
+/// The cursor of the transactions inside of the txpool. Depending on the order of
+/// the query, the `value` is the gas price or the submission time in nanoseconds.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PoolTxCursor {
+    pub value: u64,
+    pub tx_id: Bytes32,
+}
+
+impl PoolTxCursor {
+    pub fn new(value: u64, tx_id: Bytes32) -> Self {
+        Self { value, tx_id }
+    }
+}
+
+impl CursorType for PoolTxCursor {
+    type Error = String;
+
+    fn decode_cursor(s: &str) -> Result<Self, Self::Error> {
+        let (value, tx_id) = s.split_once('#').ok_or("Incorrect format provided")?;
+
+        Ok(Self::new(
+            u64::from_str(value).map_err(|_| "Failed to decode value")?,
+            Bytes32::decode_cursor(tx_id)?,
+        ))
+    }
+
+    fn encode_cursor(&self) -> String {
+        format!("{}#{}", self.value, self.tx_id)
+    }
+}
+
 #[derive(Clone, Debug, derive_more::Into, derive_more::From, PartialEq, Eq)]
 pub struct HexString(pub(crate) Vec<u8>);
 
@@ -175,6 +175,7 @@ impl SuccessStatus {
         let query: &ReadView = ctx.data_unchecked();
         let receipts = query
             .receipts(&self.tx_id)
+            .into_api_result::<Vec<_>, async_graphql::Error>()?
             .unwrap_or_default()
             .into_iter()
             .map(Into::into)
@@ -220,6 +221,7 @@ impl FailureStatus {
         let query: &ReadView = ctx.data_unchecked();
         let receipts = query
             .receipts(&self.tx_id)
+            .into_api_result::<Vec<_>, async_graphql::Error>()?
             .unwrap_or_default()
             .into_iter()
             .map(Into::into)
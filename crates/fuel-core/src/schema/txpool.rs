@@ -0,0 +1,208 @@
+use crate::{
+    fuel_core_graphql_api::api_service::TxPool,
+    schema::{
+        scalars::{
+            Address,
+            PoolTxCursor,
+            U64,
+        },
+        tx::types::Transaction,
+    },
+};
+use async_graphql::{
+    connection::{
+        Connection,
+        EmptyFields,
+    },
+    Context,
+    Enum,
+    Object,
+    SimpleObject,
+};
+use fuel_core_storage::iter::IterDirection;
+use fuel_core_txpool::TxInfo;
+use fuel_core_types::{
+    fuel_tx::{
+        input::{
+            coin::{
+                CoinPredicate,
+                CoinSigned,
+            },
+            message::{
+                MessageCoinPredicate,
+                MessageCoinSigned,
+                MessageDataPredicate,
+                MessageDataSigned,
+            },
+        },
+        Input,
+    },
+    fuel_types,
+};
+use std::{
+    cmp::Ordering,
+    collections::BTreeMap,
+    ops::Deref,
+};
+
+#[derive(Default)]
+pub struct TxPoolQuery;
+
+#[derive(Enum, Copy, Clone, Default, Eq, PartialEq)]
+pub enum PoolTransactionsOrder {
+    /// From the highest gas price to the lowest.
+    #[default]
+    GasPrice,
+    /// From the oldest submission time to the newest.
+    ArrivalTime,
+}
+
+impl PoolTransactionsOrder {
+    fn cursor(&self, info: &TxInfo) -> PoolTxCursor {
+        let value = match self {
+            PoolTransactionsOrder::GasPrice => info.tx().price(),
+            PoolTransactionsOrder::ArrivalTime => {
+                u64::try_from(info.submitted_time().as_nanos()).unwrap_or(u64::MAX)
+            }
+        };
+        PoolTxCursor::new(value, info.tx().id())
+    }
+
+    /// Compares cursors in the order of the forward pagination.
+    fn cmp_cursors(&self, a: &PoolTxCursor, b: &PoolTxCursor) -> Ordering {
+        match self {
+            PoolTransactionsOrder::GasPrice => {
+                (b.value, b.tx_id).cmp(&(a.value, a.tx_id))
+            }
+            PoolTransactionsOrder::ArrivalTime => {
+                (a.value, a.tx_id).cmp(&(b.value, b.tx_id))
+            }
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct TxPoolStats {
+    /// The number of transactions inside of the txpool.
+    tx_count: U64,
+    /// The sum of the max gas of the transactions inside of the txpool.
+    total_consumable_gas: U64,
+    /// The number of transactions for each gas price, from the highest gas price.
+    gas_price_histogram: Vec<GasPriceBucket>,
+}
+
+#[derive(SimpleObject)]
+pub struct GasPriceBucket {
+    gas_price: U64,
+    tx_count: U64,
+}
+
+#[Object]
+impl TxPoolQuery {
+    /// The transactions inside of the txpool.
+    async fn pool_transactions(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The order of the transactions", default)]
+        order: PoolTransactionsOrder,
+        #[graphql(desc = "Only transactions that spend coins or messages of the owner")]
+        owner: Option<Address>,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<PoolTxCursor, Transaction, EmptyFields, EmptyFields>,
+    > {
+        let txpool = ctx.data_unchecked::<TxPool>();
+        let owner = owner.map(fuel_types::Address::from);
+
+        let mut txs: Vec<_> = txpool
+            .pending_transactions()
+            .into_iter()
+            .filter(|info| match &owner {
+                Some(owner) => info
+                    .tx()
+                    .inputs()
+                    .iter()
+                    .any(|input| input_owner(input).as_ref() == Some(owner)),
+                None => true,
+            })
+            .map(|info| (order.cursor(&info), info))
+            .collect();
+        txs.sort_by(|(a, _), (b, _)| order.cmp_cursors(a, b));
+
+        crate::schema::query_pagination(
+            after,
+            before,
+            first,
+            last,
+            |start: &Option<PoolTxCursor>, direction| {
+                if direction == IterDirection::Reverse {
+                    txs.reverse();
+                }
+                let start = *start;
+                let txs = txs
+                    .into_iter()
+                    .filter(move |(cursor, _)| match &start {
+                        Some(start) => match direction {
+                            IterDirection::Forward => {
+                                order.cmp_cursors(cursor, start) != Ordering::Less
+                            }
+                            IterDirection::Reverse => {
+                                order.cmp_cursors(cursor, start) != Ordering::Greater
+                            }
+                        },
+                        None => true,
+                    })
+                    .map(|(cursor, info)| {
+                        let tx = Transaction(info.tx().deref().into(), info.tx().id());
+                        Ok((cursor, tx))
+                    });
+                Ok(txs)
+            },
+        )
+        .await
+    }
+
+    /// The statistics of the transactions inside of the txpool.
+    async fn pool_stats(&self, ctx: &Context<'_>) -> TxPoolStats {
+        let txpool = ctx.data_unchecked::<TxPool>();
+        let txs = txpool.pending_transactions();
+
+        let mut histogram = BTreeMap::<u64, u64>::new();
+        for info in txs.iter() {
+            let count = histogram.entry(info.tx().price()).or_default();
+            *count = count.saturating_add(1);
+        }
+        let gas_price_histogram = histogram
+            .into_iter()
+            .rev()
+            .map(|(gas_price, tx_count)| GasPriceBucket {
+                gas_price: gas_price.into(),
+                tx_count: tx_count.into(),
+            })
+            .collect();
+
+        TxPoolStats {
+            tx_count: (txs.len() as u64).into(),
+            total_consumable_gas: txpool.consumable_gas().into(),
+            gas_price_histogram,
+        }
+    }
+}
+
+/// The owner of the coin or the recipient of the message spent by the input.
+fn input_owner(input: &Input) -> Option<fuel_types::Address> {
+    match input {
+        Input::CoinSigned(CoinSigned { owner, .. })
+        | Input::CoinPredicate(CoinPredicate { owner, .. }) => Some(*owner),
+        Input::MessageCoinSigned(MessageCoinSigned { recipient, .. })
+        | Input::MessageCoinPredicate(MessageCoinPredicate { recipient, .. })
+        | Input::MessageDataSigned(MessageDataSigned { recipient, .. })
+        | Input::MessageDataPredicate(MessageDataPredicate { recipient, .. }) => {
+            Some(*recipient)
+        }
+        Input::Contract(_) => None,
+    }
+}
@@ -3,10 +3,10 @@ use crate::{
     service::sub_services::BlockProducerService,
 };
 use fuel_core_consensus_module::block_verifier::Verifier;
+#[cfg(feature = "p2p")]
+use fuel_core_p2p::peer_manager::PeerReportConfig;
 use fuel_core_txpool::service::SharedState as TxPoolSharedState;
 use fuel_core_types::fuel_types::BlockHeight;
-#[cfg(feature = "p2p")]
-use fuel_core_types::services::p2p::peer_reputation::AppScore;
 use std::sync::Arc;
 
 pub mod block_importer;
@@ -25,6 +25,12 @@ pub struct PoAAdapter {
     shared_state: Option<fuel_core_poa::service::SharedState>,
 }
 
+#[derive(Clone)]
+pub struct BlockValidatorAdapter {
+    database: Database,
+    executor: ExecutorAdapter,
+}
+
 #[derive(Clone)]
 pub struct TxPoolAdapter {
     service: TxPoolSharedState<P2PAdapter, Database>,
@@ -92,20 +98,25 @@ pub struct P2PAdapter {
     peer_report_config: PeerReportConfig,
 }
 
-#[cfg(feature = "p2p")]
-#[derive(Clone)]
-pub struct PeerReportConfig {
-    pub successful_block_import: AppScore,
-    pub missing_block_headers: AppScore,
-    pub bad_block_header: AppScore,
-    pub missing_transactions: AppScore,
-    pub invalid_transactions: AppScore,
-}
-
 #[cfg(not(feature = "p2p"))]
 #[derive(Default, Clone)]
 pub struct P2PAdapter;
 
+/// The txpool used by the p2p service to serve the transactions requested by peers.
+/// The txpool depends on the p2p service, so it is set after the txpool is created.
+#[cfg(feature = "p2p")]
+#[derive(Default, Clone)]
+pub struct P2PTxPoolAdapter {
+    txpool: Arc<std::sync::OnceLock<TxPoolAdapter>>,
+}
+
+#[cfg(feature = "p2p")]
+impl P2PTxPoolAdapter {
+    pub fn set(&self, txpool: TxPoolAdapter) {
+        let _ = self.txpool.set(txpool);
+    }
+}
+
 #[cfg(feature = "p2p")]
 impl P2PAdapter {
     pub fn new(
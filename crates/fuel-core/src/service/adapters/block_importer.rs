@@ -150,6 +150,18 @@ impl ExecutorDatabase for Database {
         }
         Ok(!found)
     }
+
+    fn record_state_history(&mut self, height: &BlockHeight) -> StorageResult<()> {
+        Database::record_state_history(self, height)
+    }
+
+    fn clear_state_history(&mut self) -> StorageResult<()> {
+        Database::clear_state_history(self)
+    }
+
+    fn prune_blocks_below(&mut self, height: BlockHeight) -> StorageResult<()> {
+        Database::prune_blocks_below(self, height)
+    }
 }
 
 impl Executor for ExecutorAdapter {
@@ -25,6 +25,7 @@ use fuel_core_types::{
 };
 use std::sync::Arc;
 
+pub mod bft;
 pub mod poa;
 
 impl VerifierAdapter {
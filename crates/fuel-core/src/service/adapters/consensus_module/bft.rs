@@ -0,0 +1,169 @@
+use crate::{
+    database::Database,
+    service::adapters::{
+        BlockImporterAdapter,
+        BlockProducerAdapter,
+        BlockValidatorAdapter,
+        ExecutorAdapter,
+        P2PAdapter,
+        TransactionsSource,
+        TxPoolAdapter,
+    },
+};
+use fuel_core_bft::ports::{
+    BlockImporter,
+    BlockProducer,
+    BlockValidator,
+    ConsensusNetwork,
+    TransactionPool,
+};
+use fuel_core_services::stream::BoxStream;
+use fuel_core_types::{
+    blockchain::{
+        block::Block,
+        consensus::bft::ConsensusMessage,
+        SealedBlock,
+    },
+    fuel_asm::Word,
+    fuel_tx::TxId,
+    fuel_types::BlockHeight,
+    services::{
+        block_importer::BlockImportInfo,
+        executor::{
+            ExecutionResult,
+            ExecutionTypes,
+        },
+        p2p::{
+            ConsensusGossipData,
+            GossipsubMessageAcceptance,
+            GossipsubMessageInfo,
+        },
+        txpool::ArcPoolTx,
+    },
+    tai64::Tai64,
+};
+use std::sync::Arc;
+use tokio_stream::{
+    wrappers::BroadcastStream,
+    StreamExt,
+};
+
+impl BlockValidatorAdapter {
+    pub fn new(database: Database, executor: ExecutorAdapter) -> Self {
+        Self { database, executor }
+    }
+}
+
+impl TransactionPool for TxPoolAdapter {
+    fn remove_txs(&self, ids: Vec<TxId>) -> Vec<ArcPoolTx> {
+        self.service.remove_txs(ids)
+    }
+}
+
+#[async_trait::async_trait]
+impl BlockProducer for BlockProducerAdapter {
+    async fn produce_block(
+        &self,
+        height: BlockHeight,
+        block_time: Tai64,
+        max_gas: Word,
+    ) -> anyhow::Result<ExecutionResult> {
+        self.block_producer
+            .produce_and_execute_block_txpool(height, block_time, max_gas)
+            .await
+            .map(|result| result.into_result())
+    }
+}
+
+#[async_trait::async_trait]
+impl BlockValidator for BlockValidatorAdapter {
+    async fn validate_block(&self, block: Block) -> anyhow::Result<()> {
+        fuel_core_poa::verifier::verify_block_fields(&self.database, &block)?;
+
+        let executor = self.executor.clone();
+        tokio::task::spawn_blocking(move || {
+            executor._execute_without_commit::<TransactionsSource>(
+                ExecutionTypes::Validation(block),
+            )
+        })
+        .await??;
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl BlockImporter for BlockImporterAdapter {
+    async fn execute_and_commit(&self, block: SealedBlock) -> anyhow::Result<()> {
+        self.block_importer
+            .execute_and_commit(block)
+            .await
+            .map_err(Into::into)
+    }
+
+    fn block_stream(&self) -> BoxStream<BlockImportInfo> {
+        Box::pin(
+            BroadcastStream::new(self.block_importer.subscribe())
+                .filter_map(|result| result.ok())
+                .map(BlockImportInfo::from),
+        )
+    }
+}
+
+#[cfg(feature = "p2p")]
+impl ConsensusNetwork for P2PAdapter {
+    fn broadcast_consensus_message(
+        &self,
+        message: Arc<ConsensusMessage>,
+    ) -> anyhow::Result<()> {
+        if let Some(service) = &self.service {
+            service.broadcast_consensus_message(message)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn gossiped_consensus_messages(&self) -> BoxStream<ConsensusGossipData> {
+        if let Some(service) = &self.service {
+            Box::pin(
+                BroadcastStream::new(service.subscribe_consensus_messages())
+                    .filter_map(|result| result.ok()),
+            )
+        } else {
+            Box::pin(tokio_stream::pending())
+        }
+    }
+
+    fn notify_gossip_consensus_message_validity(
+        &self,
+        message_info: GossipsubMessageInfo,
+        validity: GossipsubMessageAcceptance,
+    ) -> anyhow::Result<()> {
+        if let Some(service) = &self.service {
+            service.notify_gossip_consensus_message_validity(message_info, validity)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(not(feature = "p2p"))]
+impl ConsensusNetwork for P2PAdapter {
+    fn broadcast_consensus_message(
+        &self,
+        _message: Arc<ConsensusMessage>,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn gossiped_consensus_messages(&self) -> BoxStream<ConsensusGossipData> {
+        Box::pin(tokio_stream::pending())
+    }
+
+    fn notify_gossip_consensus_message_validity(
+        &self,
+        _message_info: GossipsubMessageInfo,
+        _validity: GossipsubMessageAcceptance,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
@@ -17,11 +17,14 @@ use crate::{
     },
 };
 use async_trait::async_trait;
+#[cfg(feature = "p2p")]
+use fuel_core_p2p::p2p_service::PeerManagementRequest;
 use fuel_core_services::stream::BoxStream;
 use fuel_core_storage::Result as StorageResult;
 use fuel_core_txpool::{
     service::TxStatusMessage,
     types::TxId,
+    TxInfo,
 };
 use fuel_core_types::{
     entities::message::MerkleProof,
@@ -32,14 +35,21 @@ use fuel_core_types::{
     fuel_types::BlockHeight,
     services::{
         block_importer::SharedImportResult,
-        p2p::PeerInfo,
-        txpool::InsertionResult,
+        p2p::{
+            PeerId,
+            PeerInfo,
+        },
+        txpool::{
+            ArcPoolTx,
+            InsertionResult,
+        },
     },
     tai64::Tai64,
 };
 use std::{
     ops::Deref,
     sync::Arc,
+    time::Duration,
 };
 
 mod off_chain;
@@ -59,6 +69,18 @@ impl TxPoolPort for TxPoolAdapter {
             .map(|info| Tai64::from_unix(info.submitted_time().as_secs() as i64))
     }
 
+    fn pending_transactions(&self) -> Vec<TxInfo> {
+        self.service.pending_transactions()
+    }
+
+    fn consumable_gas(&self) -> u64 {
+        self.service.total_consumable_gas()
+    }
+
+    fn sorted_includable(&self) -> Vec<ArcPoolTx> {
+        self.service.sorted_includable()
+    }
+
     async fn insert(
         &self,
         txs: Vec<Arc<Transaction>>,
@@ -134,6 +156,125 @@ impl P2pPort for P2PAdapter {
             Ok(vec![])
         }
     }
+
+    async fn ban_peer(
+        &self,
+        peer_id: PeerId,
+        duration: Option<Duration>,
+    ) -> anyhow::Result<()> {
+        #[cfg(feature = "p2p")]
+        {
+            let peer_id = peer_id.try_into_libp2p()?;
+            self.manage_peers(PeerManagementRequest::Ban { peer_id, duration })
+                .await
+        }
+        #[cfg(not(feature = "p2p"))]
+        {
+            let _ = (peer_id, duration);
+            Err(p2p_disabled())
+        }
+    }
+
+    async fn unban_peer(&self, peer_id: PeerId) -> anyhow::Result<()> {
+        #[cfg(feature = "p2p")]
+        {
+            let peer_id = peer_id.try_into_libp2p()?;
+            self.manage_peers(PeerManagementRequest::Unban { peer_id })
+                .await
+        }
+        #[cfg(not(feature = "p2p"))]
+        {
+            let _ = peer_id;
+            Err(p2p_disabled())
+        }
+    }
+
+    async fn dial_peer(&self, address: String) -> anyhow::Result<()> {
+        #[cfg(feature = "p2p")]
+        {
+            let address = address.parse()?;
+            self.manage_peers(PeerManagementRequest::Dial { address })
+                .await
+        }
+        #[cfg(not(feature = "p2p"))]
+        {
+            let _ = address;
+            Err(p2p_disabled())
+        }
+    }
+
+    async fn disconnect_peer(&self, peer_id: PeerId) -> anyhow::Result<()> {
+        #[cfg(feature = "p2p")]
+        {
+            let peer_id = peer_id.try_into_libp2p()?;
+            self.manage_peers(PeerManagementRequest::Disconnect { peer_id })
+                .await
+        }
+        #[cfg(not(feature = "p2p"))]
+        {
+            let _ = peer_id;
+            Err(p2p_disabled())
+        }
+    }
+
+    async fn add_reserved_peer(&self, address: String) -> anyhow::Result<()> {
+        #[cfg(feature = "p2p")]
+        {
+            let address = address.parse()?;
+            self.manage_peers(PeerManagementRequest::AddReservedPeer { address })
+                .await
+        }
+        #[cfg(not(feature = "p2p"))]
+        {
+            let _ = address;
+            Err(p2p_disabled())
+        }
+    }
+
+    async fn remove_reserved_peer(&self, peer_id: PeerId) -> anyhow::Result<()> {
+        #[cfg(feature = "p2p")]
+        {
+            let peer_id = peer_id.try_into_libp2p()?;
+            self.manage_peers(PeerManagementRequest::RemoveReservedPeer { peer_id })
+                .await
+        }
+        #[cfg(not(feature = "p2p"))]
+        {
+            let _ = peer_id;
+            Err(p2p_disabled())
+        }
+    }
+}
+
+#[cfg(feature = "p2p")]
+impl P2PAdapter {
+    async fn manage_peers(&self, request: PeerManagementRequest) -> anyhow::Result<()> {
+        match &self.service {
+            Some(service) => service.manage_peers(request).await,
+            None => Err(anyhow::anyhow!("The p2p service is not running")),
+        }
+    }
+}
+
+#[cfg(feature = "p2p")]
+trait TryIntoLibp2p {
+    fn try_into_libp2p(self) -> anyhow::Result<fuel_core_p2p::PeerId>;
+}
+
+#[cfg(feature = "p2p")]
+impl TryIntoLibp2p for PeerId {
+    fn try_into_libp2p(self) -> anyhow::Result<fuel_core_p2p::PeerId> {
+        let bytes: Vec<u8> = self.into();
+        fuel_core_p2p::PeerId::from_bytes(&bytes)
+            .map_err(|e| anyhow::anyhow!("Invalid peer id: {e}"))
+    }
+}
+
+#[cfg(not(feature = "p2p"))]
+fn p2p_disabled() -> anyhow::Error {
+    anyhow::anyhow!(
+        "Peering is disabled in this build, try using the `p2p` feature flag."
+    )
 }
 
 impl worker::BlockImporter for BlockImporterAdapter {
@@ -18,12 +18,16 @@ use fuel_core_storage::{
         IterDirection,
     },
     not_found,
+    tables::FuelBlocks,
     transactional::AtomicView,
     Error as StorageError,
     Result as StorageResult,
+    StorageAsRef,
 };
 use fuel_core_txpool::types::TxId;
 use fuel_core_types::{
+    blockchain::primitives::DaBlockHeight,
+    entities::message::Message,
     fuel_tx::{
         Address,
         Bytes32,
@@ -36,6 +40,7 @@ use fuel_core_types::{
     },
     services::txpool::TransactionStatus,
 };
+use itertools::Itertools;
 use std::sync::Arc;
 
 impl OffChainDatabase for Database {
@@ -114,4 +119,38 @@ impl worker::OffChainDatabase for Database {
     ) -> StorageResult<Option<TransactionStatus>> {
         Database::update_tx_status(self, id, status)
     }
+
+    fn prune_receipts_and_statuses_below(
+        &mut self,
+        height: BlockHeight,
+    ) -> StorageResult<()> {
+        Database::prune_receipts_and_statuses_below(self, height)
+    }
+
+    fn relayed_messages(
+        &self,
+        block_height: &BlockHeight,
+        da_height: DaBlockHeight,
+    ) -> StorageResult<Vec<Message>> {
+        // The messages of the genesis block come from the chain config.
+        let previous_height = match block_height.pred() {
+            Some(previous_height) => previous_height,
+            None => return Ok(vec![]),
+        };
+        let previous_block_id = self
+            .get_block_id(&previous_height)?
+            .ok_or(not_found!(FuelBlocks))?;
+        let previous_da_height = self
+            .storage::<FuelBlocks>()
+            .get(&previous_block_id)?
+            .ok_or(not_found!(FuelBlocks))?
+            .header()
+            .da_height;
+        if previous_da_height >= da_height {
+            return Ok(vec![])
+        }
+
+        self.relayed_messages_in_range(previous_da_height, da_height)
+            .try_collect()
+    }
 }
@@ -29,7 +29,10 @@ use fuel_core_types::{
         DaBlockHeight,
     },
     entities::message::Message,
-    fuel_tx::AssetId,
+    fuel_tx::{
+        AssetId,
+        TxId,
+    },
     fuel_types::{
         BlockHeight,
         Nonce,
@@ -59,6 +62,14 @@ impl DatabaseBlocks for Database {
             .transpose()
             .ok_or(not_found!("BlockId"))?
     }
+
+    fn pruned_below(&self) -> StorageResult<Option<BlockHeight>> {
+        self.pruned_below()
+    }
+
+    fn pruned_tx_height(&self, tx_id: &TxId) -> StorageResult<Option<BlockHeight>> {
+        self.pruned_tx_height(tx_id)
+    }
 }
 
 impl DatabaseMessages for Database {
@@ -1,8 +1,12 @@
-use super::BlockImporterAdapter;
+use super::{
+    BlockImporterAdapter,
+    P2PTxPoolAdapter,
+};
 use crate::database::Database;
 use fuel_core_p2p::ports::{
     BlockHeightImporter,
     P2pDb,
+    TxPool,
 };
 use fuel_core_services::stream::BoxStream;
 use fuel_core_storage::Result as StorageResult;
@@ -11,16 +15,33 @@ use fuel_core_types::{
         SealedBlock,
         SealedBlockHeader,
     },
+    fuel_tx::{
+        Transaction,
+        TxId,
+    },
     fuel_types::BlockHeight,
-    services::p2p::Transactions,
+    services::{
+        block_importer::Source,
+        p2p::{
+            PeerId,
+            PeerRecord,
+            Transactions,
+        },
+    },
+};
+use std::{
+    ops::Range,
+    sync::Arc,
 };
-use std::ops::Range;
 
 impl P2pDb for Database {
     fn get_sealed_block(
         &self,
         height: &BlockHeight,
     ) -> StorageResult<Option<SealedBlock>> {
+        if self.is_pruned(height)? {
+            return Ok(None)
+        }
         self.get_sealed_block_by_height(height)
     }
 
@@ -44,6 +65,42 @@ impl P2pDb for Database {
     ) -> StorageResult<Option<Vec<Transactions>>> {
         self.get_transactions_on_blocks(block_height_range)
     }
+
+    fn get_transactions_by_ids(
+        &self,
+        tx_ids: &[TxId],
+    ) -> StorageResult<Option<Vec<Transaction>>> {
+        self.get_transactions_by_ids(tx_ids)
+    }
+
+    fn get_peer_records(&self) -> StorageResult<Vec<(PeerId, PeerRecord)>> {
+        self.peer_records()
+    }
+
+    fn pruned_below(&self) -> StorageResult<Option<BlockHeight>> {
+        Database::pruned_below(self)
+    }
+
+    fn store_peer_records(
+        &self,
+        records: Vec<(PeerId, PeerRecord)>,
+    ) -> StorageResult<()> {
+        self.clone().replace_peer_records(records)
+    }
+}
+
+impl TxPool for P2PTxPoolAdapter {
+    fn find_transactions(&self, tx_ids: &[TxId]) -> Vec<Option<Transaction>> {
+        match self.txpool.get() {
+            Some(txpool) => txpool
+                .service
+                .find(tx_ids.to_vec())
+                .into_iter()
+                .map(|info| info.map(|info| info.tx().as_ref().into()))
+                .collect(),
+            None => vec![None; tx_ids.len()],
+        }
+    }
 }
 
 impl BlockHeightImporter for BlockImporterAdapter {
@@ -58,4 +115,17 @@ impl BlockHeightImporter for BlockImporterAdapter {
                 .map(|result| result.sealed_block.entity.header().consensus.height),
         )
     }
+
+    fn local_blocks(&self) -> BoxStream<Arc<SealedBlock>> {
+        use tokio_stream::{
+            wrappers::BroadcastStream,
+            StreamExt,
+        };
+        Box::pin(
+            BroadcastStream::new(self.block_importer.subscribe())
+                .filter_map(|result| result.ok())
+                .filter(|result| result.source == Source::Local)
+                .map(|result| Arc::new(result.sealed_block.clone())),
+        )
+    }
 }
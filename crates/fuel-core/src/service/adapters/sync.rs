@@ -1,6 +1,7 @@
 use super::{
     BlockImporterAdapter,
     P2PAdapter,
+    TxPoolAdapter,
     VerifierAdapter,
 };
 use fuel_core_services::stream::BoxStream;
@@ -9,6 +10,7 @@ use fuel_core_sync::ports::{
     ConsensusPort,
     PeerReportReason,
     PeerToPeerPort,
+    TxPoolPort,
 };
 use fuel_core_types::{
     blockchain::{
@@ -16,12 +18,19 @@ use fuel_core_types::{
         SealedBlock,
         SealedBlockHeader,
     },
+    fuel_tx::{
+        Transaction,
+        TxId,
+    },
     fuel_types::BlockHeight,
     services::p2p::{
         peer_reputation::{
             AppScore,
             PeerReport,
         },
+        BlockGossipData,
+        GossipsubMessageAcceptance,
+        GossipsubMessageInfo,
         PeerId,
         SourcePeer,
         Transactions,
@@ -64,6 +73,38 @@ impl PeerToPeerPort for P2PAdapter {
         }
     }
 
+    async fn select_peers(
+        &self,
+        block_height_range: Range<u32>,
+        max_peers: usize,
+    ) -> anyhow::Result<Vec<PeerId>> {
+        if let Some(service) = &self.service {
+            let peers = service
+                .select_sync_peers(block_height_range, max_peers)
+                .await?;
+            Ok(peers.into_iter().map(PeerId::from).collect())
+        } else {
+            Err(anyhow::anyhow!("No P2P service available"))
+        }
+    }
+
+    async fn get_sealed_block_headers_from_peer(
+        &self,
+        block_height_range: SourcePeer<Range<u32>>,
+    ) -> anyhow::Result<Option<Vec<SealedBlockHeader>>> {
+        let SourcePeer {
+            peer_id,
+            data: range,
+        } = block_height_range;
+        if let Some(service) = &self.service {
+            service
+                .get_sealed_block_headers_from_peer(peer_id.into(), range)
+                .await
+        } else {
+            Err(anyhow::anyhow!("No P2P service available"))
+        }
+    }
+
     async fn get_transactions(
         &self,
         range: SourcePeer<Range<u32>>,
@@ -81,6 +122,23 @@ impl PeerToPeerPort for P2PAdapter {
         }
     }
 
+    async fn get_transactions_by_ids(
+        &self,
+        tx_ids: SourcePeer<Vec<TxId>>,
+    ) -> anyhow::Result<Option<Vec<Transaction>>> {
+        let SourcePeer {
+            peer_id,
+            data: tx_ids,
+        } = tx_ids;
+        if let Some(service) = &self.service {
+            service
+                .get_transactions_by_ids_from_peer(peer_id.into(), tx_ids)
+                .await
+        } else {
+            Err(anyhow::anyhow!("No P2P service available"))
+        }
+    }
+
     fn report_peer(&self, peer: PeerId, report: PeerReportReason) -> anyhow::Result<()> {
         if let Some(service) = &self.service {
             let service_name = "Sync";
@@ -91,6 +149,30 @@ impl PeerToPeerPort for P2PAdapter {
             Err(anyhow::anyhow!("No P2P service available"))
         }
     }
+
+    fn gossiped_blocks(&self) -> BoxStream<BlockGossipData> {
+        use futures::StreamExt;
+        if let Some(service) = &self.service {
+            fuel_core_services::stream::IntoBoxStream::into_boxed(
+                tokio_stream::wrappers::BroadcastStream::new(service.subscribe_blocks())
+                    .filter_map(|r| futures::future::ready(r.ok())),
+            )
+        } else {
+            fuel_core_services::stream::IntoBoxStream::into_boxed(tokio_stream::pending())
+        }
+    }
+
+    fn notify_gossip_block_validity(
+        &self,
+        message_info: GossipsubMessageInfo,
+        validity: GossipsubMessageAcceptance,
+    ) -> anyhow::Result<()> {
+        if let Some(service) = &self.service {
+            service.notify_gossip_block_validity(message_info, validity)
+        } else {
+            Ok(())
+        }
+    }
 }
 
 impl P2PAdapter {
@@ -142,6 +224,16 @@ impl BlockImporterPort for BlockImporterAdapter {
     }
 }
 
+impl TxPoolPort for TxPoolAdapter {
+    fn find_transactions(&self, tx_ids: &[TxId]) -> Vec<Option<Transaction>> {
+        self.service
+            .find(tx_ids.to_vec())
+            .into_iter()
+            .map(|info| info.map(|info| info.tx().as_ref().into()))
+            .collect()
+    }
+}
+
 #[async_trait::async_trait]
 impl ConsensusPort for VerifierAdapter {
     fn check_sealed_header(&self, header: &SealedBlockHeader) -> anyhow::Result<bool> {
@@ -2,6 +2,7 @@ use clap::ValueEnum;
 use fuel_core_chain_config::{
     default_consensus_dev_key,
     ChainConfig,
+    ConsensusConfig,
 };
 use fuel_core_types::{
     blockchain::primitives::SecretKeyWrapper,
@@ -41,6 +42,9 @@ pub struct Config {
     pub database_path: PathBuf,
     pub database_type: DbType,
     pub chain_conf: ChainConfig,
+    /// The path to the genesis state in the streaming format.
+    /// If set, it is imported instead of the `initial_state` of the chain config.
+    pub genesis_state: Option<PathBuf>,
     /// When `true`:
     /// - Enables manual block production.
     /// - Enables debugger endpoint.
@@ -89,6 +93,7 @@ impl Config {
             database_type: DbType::InMemory,
             debug: true,
             chain_conf: chain_conf.clone(),
+            genesis_state: None,
             block_production: Trigger::Instant,
             vm: Default::default(),
             utxo_validation,
@@ -152,6 +157,31 @@ impl From<&Config> for fuel_core_poa::Config {
             consensus_params: config.chain_conf.consensus_parameters.clone(),
             min_connected_reserved_peers: config.min_connected_reserved_peers,
             time_until_synced: config.time_until_synced,
+            producer_schedule: match &config.chain_conf.consensus {
+                ConsensusConfig::PoARoundRobin(schedule) => Some(schedule.clone()),
+                ConsensusConfig::PoA { .. } | ConsensusConfig::Bft { .. } => None,
+            },
+        }
+    }
+}
+
+impl From<&Config> for fuel_core_bft::Config {
+    fn from(config: &Config) -> Self {
+        fuel_core_bft::Config {
+            signing_key: config.consensus_key.clone(),
+            chain_id: config.chain_conf.consensus_parameters.chain_id,
+            validators: match &config.chain_conf.consensus {
+                ConsensusConfig::Bft { validators } => validators.clone(),
+                ConsensusConfig::PoA { .. } | ConsensusConfig::PoARoundRobin(_) => {
+                    vec![]
+                }
+            },
+            block_time: match config.block_production {
+                Trigger::Interval { block_time } => block_time,
+                Trigger::Instant | Trigger::Never => Duration::ZERO,
+            },
+            block_gas_limit: config.chain_conf.block_gas_limit,
+            timeouts: Default::default(),
         }
     }
 }
@@ -1,24 +1,51 @@
 use crate::{
-    database::Database,
+    database::{
+        metadata::{
+            GENESIS_COINS_PROGRESS,
+            GENESIS_CONTRACTS_BALANCES_PROGRESS,
+            GENESIS_CONTRACTS_PROGRESS,
+            GENESIS_CONTRACTS_STATE_PROGRESS,
+            GENESIS_MESSAGES_PROGRESS,
+        },
+        Database,
+    },
     service::config::Config,
 };
-use anyhow::anyhow;
+use anyhow::{
+    anyhow,
+    Context,
+};
 use fuel_core_chain_config::{
+    CoinConfig,
+    ContractBalanceConfig,
     ContractConfig,
+    ContractStateConfig,
     GenesisCommitment,
+    MessageConfig,
     StateConfig,
+    StateEntry,
+    StateReader,
 };
 use fuel_core_executor::refs::ContractRef;
 use fuel_core_importer::Importer;
+use fuel_core_metrics::genesis::genesis_metrics;
 use fuel_core_storage::{
+    iter::{
+        BoxedIter,
+        IntoBoxedIter,
+    },
     tables::{
         Coins,
+        ContractsAssets,
         ContractsInfo,
         ContractsLatestUtxo,
         ContractsRawCode,
+        ContractsState,
         Messages,
     },
     transactional::Transactional,
+    ContractsAssetKey,
+    ContractsStateKey,
     MerkleRoot,
     StorageAsMut,
 };
@@ -42,6 +69,7 @@ use fuel_core_types::{
         contract::ContractUtxoInfo,
         message::Message,
     },
+    fuel_crypto::Hasher,
     fuel_merkle::binary,
     fuel_tx::{
         Contract,
@@ -50,6 +78,7 @@ use fuel_core_types::{
     },
     fuel_types::{
         bytes::WORD_SIZE,
+        BlockHeight,
         Bytes32,
         ContractId,
     },
@@ -59,6 +88,17 @@ use fuel_core_types::{
     },
 };
 use itertools::Itertools;
+use std::{
+    fs::File,
+    io::{
+        BufRead,
+        BufReader,
+    },
+    path::Path,
+};
+
+/// The number of the state entries imported within one database transaction.
+const GENESIS_BATCH_SIZE: usize = 10_000;
 
 /// Loads state from the chain config into database
 pub fn maybe_initialize_state(
@@ -77,24 +117,75 @@ fn import_genesis_block(
     config: &Config,
     original_database: &Database,
 ) -> anyhow::Result<()> {
-    // start a db transaction for bulk-writing
-    let mut database_transaction = Transactional::transaction(original_database);
-
-    let database = database_transaction.as_mut();
-    // Initialize the chain id and height.
+    // The initial height is defined by the `ChainConfig`.
+    // If it is `None` then it will be zero.
+    let height = config
+        .chain_conf
+        .initial_state
+        .as_ref()
+        .map(|config| config.height.unwrap_or_else(|| 0u32.into()))
+        .unwrap_or_else(|| 0u32.into());
 
     let chain_config_hash = config.chain_conf.root()?.into();
-    let coins_root = init_coin_state(database, &config.chain_conf.initial_state)?.into();
-    let contracts_root =
-        init_contracts(database, &config.chain_conf.initial_state)?.into();
-    let messages_root = init_da_messages(database, &config.chain_conf.initial_state)?;
-    let messages_root = messages_root.into();
+    let source = match &config.genesis_state {
+        Some(path) => StateSource::File(path),
+        None => StateSource::Config(config.chain_conf.initial_state.as_ref()),
+    };
+
+    // The entries imported before the restart must come from the same source.
+    let source_hash = source.hash(&chain_config_hash)?;
+    let mut database = original_database.clone();
+    match database.genesis_source_hash()? {
+        Some(hash) if hash != source_hash => {
+            return Err(anyhow!(
+                "The interrupted import of the genesis state was started with another \
+                chain config or genesis state. Use the same chain config and state, \
+                or remove the database to import the genesis state from scratch"
+            ))
+        }
+        Some(_) => {}
+        None => database.set_genesis_source_hash(&source_hash)?,
+    }
+
+    // Tables are independent, so each of them is imported by its own thread.
+    let (coins_root, contracts_root, messages_root) = std::thread::scope(|scope| {
+        let coins = scope.spawn(|| {
+            import_table(original_database, &source, StateTable::Coins, height)
+        });
+        let contracts = scope.spawn(|| {
+            // The roots of the contracts depend on their storage and balances,
+            // so they are imported first.
+            import_table(
+                original_database,
+                &source,
+                StateTable::ContractsState,
+                height,
+            )?;
+            import_table(
+                original_database,
+                &source,
+                StateTable::ContractsBalances,
+                height,
+            )?;
+            import_table(original_database, &source, StateTable::Contracts, height)
+        });
+        let messages =
+            import_table(original_database, &source, StateTable::Messages, height);
+
+        let coins = coins
+            .join()
+            .unwrap_or_else(|panic| std::panic::resume_unwind(panic));
+        let contracts = contracts
+            .join()
+            .unwrap_or_else(|panic| std::panic::resume_unwind(panic));
+        (coins, contracts, messages)
+    });
 
     let genesis = Genesis {
         chain_config_hash,
-        coins_root,
-        contracts_root,
-        messages_root,
+        coins_root: coins_root?.into(),
+        contracts_root: contracts_root?.into(),
+        messages_root: messages_root?.into(),
     };
 
     let block = Block::new(
@@ -107,14 +198,7 @@ fn import_genesis_block(
             consensus: ConsensusHeader::<Empty> {
                 // The genesis is a first block, so previous root is zero.
                 prev_root: Bytes32::zeroed(),
-                // The initial height is defined by the `ChainConfig`.
-                // If it is `None` then it will be zero.
-                height: config
-                    .chain_conf
-                    .initial_state
-                    .as_ref()
-                    .map(|config| config.height.unwrap_or_else(|| 0u32.into()))
-                    .unwrap_or_else(|| 0u32.into()),
+                height,
                 time: fuel_core_types::tai64::Tai64::UNIX_EPOCH,
                 generated: Empty,
             },
@@ -130,6 +214,15 @@ fn import_genesis_block(
         consensus,
     };
 
+    // The progress of the import is not needed after the genesis block is committed.
+    let mut database_transaction = Transactional::transaction(original_database);
+    for table in StateTable::ALL {
+        database_transaction
+            .as_mut()
+            .remove_genesis_progress(table.progress_key())?;
+    }
+    database_transaction.as_mut().remove_genesis_source_hash()?;
+
     let importer = Importer::new(
         config.block_importer.clone(),
         original_database.clone(),
@@ -141,161 +234,383 @@ fn import_genesis_block(
         ImportResult::new_from_local(block, vec![]),
         database_transaction,
     ))?;
+    tracing::info!("The genesis block at height {height} is imported");
     Ok(())
 }
 
-fn init_coin_state(
-    db: &mut Database,
-    state: &Option<StateConfig>,
-) -> anyhow::Result<MerkleRoot> {
-    let mut coins_tree = binary::in_memory::MerkleTree::new();
-    // TODO: Store merkle sum tree root over coins with unspecified utxo ids.
-    let mut generated_output_index: u64 = 0;
-    if let Some(state) = &state {
-        if let Some(coins) = &state.coins {
-            for coin in coins {
-                let utxo_id = UtxoId::new(
-                    // generated transaction id([0..[out_index/255]])
-                    coin.tx_id.unwrap_or_else(|| {
-                        Bytes32::try_from(
-                            (0..(Bytes32::LEN - WORD_SIZE))
-                                .map(|_| 0u8)
-                                .chain(
-                                    (generated_output_index / 255)
-                                        .to_be_bytes()
-                                        .into_iter(),
-                                )
-                                .collect_vec()
-                                .as_slice(),
-                        )
-                        .expect("Incorrect genesis transaction id byte length")
-                    }),
-                    coin.output_index.unwrap_or_else(|| {
-                        generated_output_index = generated_output_index
-                            .checked_add(1)
-                            .expect("The maximum number of UTXOs supported in the genesis configuration has been exceeded.");
-                        (generated_output_index % 255) as u8
-                    }),
-                );
-
-                let coin = CompressedCoin {
-                    owner: coin.owner,
-                    amount: coin.amount,
-                    asset_id: coin.asset_id,
-                    maturity: coin.maturity.unwrap_or_default(),
-                    tx_pointer: TxPointer::new(
-                        coin.tx_pointer_block_height.unwrap_or_default(),
-                        coin.tx_pointer_tx_idx.unwrap_or_default(),
-                    ),
-                };
+/// The source of the genesis state.
+enum StateSource<'a> {
+    /// The state embedded into the chain config.
+    Config(Option<&'a StateConfig>),
+    /// The state file in the streaming format.
+    File(&'a Path),
+}
 
-                // ensure coin can't point to blocks in the future
-                if coin.tx_pointer.block_height() > state.height.unwrap_or_default() {
-                    return Err(anyhow!(
-                        "coin tx_pointer height cannot be greater than genesis block"
-                    ))
+impl StateSource<'_> {
+    /// Returns the hash of the `chain_config_hash` and the genesis state of the source.
+    fn hash(&self, chain_config_hash: &Bytes32) -> anyhow::Result<Bytes32> {
+        let mut hasher = Hasher::default();
+        hasher.input(chain_config_hash);
+        match self {
+            StateSource::Config(state) => {
+                hasher.input(serde_json::to_vec(state)?);
+            }
+            StateSource::File(path) => {
+                let file = File::open(path).context(format!(
+                    "failed to open the genesis state file {}",
+                    path.display()
+                ))?;
+                let mut reader = BufReader::new(file);
+                loop {
+                    let buffer = reader.fill_buf()?;
+                    if buffer.is_empty() {
+                        break
+                    }
+                    hasher.input(buffer);
+                    let read = buffer.len();
+                    reader.consume(read);
                 }
+            }
+        }
+        Ok(hasher.finalize())
+    }
 
-                if db.storage::<Coins>().insert(&utxo_id, &coin)?.is_some() {
-                    return Err(anyhow!("Coin should not exist"))
-                }
-                coins_tree.push(coin.root()?.as_slice())
+    /// Returns the entries of the `table` in the order defined by the source.
+    fn entries(
+        &self,
+        table: StateTable,
+    ) -> anyhow::Result<BoxedIter<anyhow::Result<StateEntry>>> {
+        match self {
+            StateSource::Config(state) => {
+                let state = state.iter();
+                let entries = match table {
+                    StateTable::Coins => state
+                        .flat_map(|state| state.coins.iter().flatten())
+                        .cloned()
+                        .map(StateEntry::Coin)
+                        .map(Ok)
+                        .into_boxed(),
+                    StateTable::Contracts => state
+                        .flat_map(|state| state.contracts.iter().flatten())
+                        .cloned()
+                        .map(StateEntry::Contract)
+                        .map(Ok)
+                        .into_boxed(),
+                    StateTable::Messages => state
+                        .flat_map(|state| state.messages.iter().flatten())
+                        .cloned()
+                        .map(StateEntry::Message)
+                        .map(Ok)
+                        .into_boxed(),
+                    // The embedded contracts keep their storage and balances inline.
+                    StateTable::ContractsState | StateTable::ContractsBalances => {
+                        std::iter::empty().into_boxed()
+                    }
+                };
+                Ok(entries)
+            }
+            StateSource::File(path) => {
+                let file = File::open(path).context(format!(
+                    "failed to open the genesis state file {}",
+                    path.display()
+                ))?;
+                let entries = StateReader::new(BufReader::new(file))
+                    .filter(move |entry| match entry {
+                        Ok(entry) => table.contains(entry),
+                        Err(_) => true,
+                    })
+                    .into_boxed();
+                Ok(entries)
             }
         }
     }
-    Ok(coins_tree.root())
 }
 
-fn init_contracts(
-    db: &mut Database,
-    state: &Option<StateConfig>,
+/// The table of the genesis state. Tables are imported independently.
+#[derive(Clone, Copy, Debug)]
+enum StateTable {
+    Coins,
+    Contracts,
+    ContractsState,
+    ContractsBalances,
+    Messages,
+}
+
+impl StateTable {
+    const ALL: [StateTable; 5] = [
+        StateTable::Coins,
+        StateTable::ContractsState,
+        StateTable::ContractsBalances,
+        StateTable::Contracts,
+        StateTable::Messages,
+    ];
+
+    fn name(&self) -> &'static str {
+        match self {
+            StateTable::Coins => "coins",
+            StateTable::Contracts => "contracts",
+            StateTable::ContractsState => "contracts state entries",
+            StateTable::ContractsBalances => "contracts balances",
+            StateTable::Messages => "messages",
+        }
+    }
+
+    fn contains(&self, entry: &StateEntry) -> bool {
+        matches!(
+            (self, entry),
+            (StateTable::Coins, StateEntry::Coin(_))
+                | (StateTable::Contracts, StateEntry::Contract(_))
+                | (StateTable::ContractsState, StateEntry::ContractState(_))
+                | (
+                    StateTable::ContractsBalances,
+                    StateEntry::ContractBalance(_)
+                )
+                | (StateTable::Messages, StateEntry::Message(_))
+        )
+    }
+
+    /// The key of the `Metadata` that stores the number of imported entries.
+    fn progress_key(&self) -> &'static str {
+        match self {
+            StateTable::Coins => GENESIS_COINS_PROGRESS,
+            StateTable::Contracts => GENESIS_CONTRACTS_PROGRESS,
+            StateTable::ContractsState => GENESIS_CONTRACTS_STATE_PROGRESS,
+            StateTable::ContractsBalances => GENESIS_CONTRACTS_BALANCES_PROGRESS,
+            StateTable::Messages => GENESIS_MESSAGES_PROGRESS,
+        }
+    }
+
+    fn report_progress(&self, imported: i64) {
+        let metrics = genesis_metrics();
+        let gauge = match self {
+            StateTable::Coins => &metrics.imported_coins,
+            StateTable::Contracts => &metrics.imported_contracts,
+            StateTable::ContractsState => &metrics.imported_contracts_state,
+            StateTable::ContractsBalances => &metrics.imported_contracts_balances,
+            StateTable::Messages => &metrics.imported_messages,
+        };
+        gauge.set(imported);
+    }
+}
+
+/// Imports the entries of the `table` in batches of the `GENESIS_BATCH_SIZE`.
+/// Each batch is committed together with the number of imported entries,
+/// so the import continues from the last committed batch after the restart.
+///
+/// Returns the merkle root of the imported entries. The storage entries and balances
+/// of the contracts don't have their own roots, they are a part of the contracts' roots.
+fn import_table(
+    database: &Database,
+    source: &StateSource,
+    table: StateTable,
+    height: BlockHeight,
 ) -> anyhow::Result<MerkleRoot> {
-    let mut contracts_tree = binary::in_memory::MerkleTree::new();
-    // initialize contract state
-    if let Some(state) = &state {
-        if let Some(contracts) = &state.contracts {
-            for (generated_output_index, contract_config) in contracts.iter().enumerate()
-            {
-                let contract = Contract::from(contract_config.code.as_slice());
-                let salt = contract_config.salt;
-                let root = contract.root();
-                let contract_id = contract_config.contract_id;
-                let utxo_id = if let (Some(tx_id), Some(output_idx)) =
-                    (contract_config.tx_id, contract_config.output_index)
-                {
-                    UtxoId::new(tx_id, output_idx)
-                } else {
-                    #[allow(clippy::cast_possible_truncation)]
-                    UtxoId::new(
-                        // generated transaction id([0..[out_index/255]])
-                        Bytes32::try_from(
-                            (0..(Bytes32::LEN - WORD_SIZE))
-                                .map(|_| 0u8)
-                                .chain(
-                                    (generated_output_index as u64 / 255)
-                                        .to_be_bytes()
-                                        .into_iter(),
-                                )
-                                .collect_vec()
-                                .as_slice(),
-                        )
-                        .expect("Incorrect genesis transaction id byte length"),
-                        generated_output_index as u8,
-                    )
-                };
-                let tx_pointer = if let (Some(block_height), Some(tx_idx)) = (
-                    contract_config.tx_pointer_block_height,
-                    contract_config.tx_pointer_tx_idx,
-                ) {
-                    TxPointer::new(block_height, tx_idx)
-                } else {
-                    TxPointer::default()
-                };
+    let mut tree = binary::in_memory::MerkleTree::new();
+    // TODO: Store merkle sum tree root over coins with unspecified utxo ids.
+    let mut generated_output_index: u64 = 0;
+    let mut entries = source.entries(table)?;
+
+    // The entries imported before the restart are already in the database,
+    // only their roots are required.
+    let mut imported = database.genesis_progress(table.progress_key())?;
+    if imported > 0 {
+        tracing::info!(
+            "Resuming the import of genesis {} after {imported} entries",
+            table.name()
+        );
+    }
+    for entry in entries.by_ref().take(usize::try_from(imported)?) {
+        let root = match entry? {
+            StateEntry::Coin(coin) => {
+                let (_, coin) = coin_from_config(&coin, &mut generated_output_index);
+                coin.root()?
+            }
+            StateEntry::Contract(contract) => {
+                let mut database = database.clone();
+                ContractRef::new(&mut database, contract.contract_id).root()?
+            }
+            StateEntry::ContractState(_) | StateEntry::ContractBalance(_) => continue,
+            StateEntry::Message(message) => Message::from(message).root()?,
+        };
+        tree.push(root.as_slice());
+    }
 
-                if tx_pointer.block_height() > state.height.unwrap_or_default() {
-                    return Err(anyhow!(
-                        "contract tx_pointer cannot be greater than genesis block"
-                    ))
-                }
+    for batch in &entries.chunks(GENESIS_BATCH_SIZE) {
+        let mut database_transaction = Transactional::transaction(database);
+        let db = database_transaction.as_mut();
 
-                // insert contract code
-                if db
-                    .storage::<ContractsRawCode>()
-                    .insert(&contract_id, contract.as_ref())?
-                    .is_some()
-                {
-                    return Err(anyhow!("Contract code should not exist"))
+        for entry in batch {
+            let root = match entry? {
+                StateEntry::Coin(coin) => {
+                    Some(init_coin(db, &coin, height, &mut generated_output_index)?)
                 }
-
-                // insert contract root
-                if db
-                    .storage::<ContractsInfo>()
-                    .insert(&contract_id, &(salt, root))?
-                    .is_some()
-                {
-                    return Err(anyhow!("Contract info should not exist"))
+                StateEntry::Contract(contract) => {
+                    Some(init_contract(db, &contract, height, imported)?)
                 }
-                if db
-                    .storage::<ContractsLatestUtxo>()
-                    .insert(
-                        &contract_id,
-                        &ContractUtxoInfo {
-                            utxo_id,
-                            tx_pointer,
-                        },
-                    )?
-                    .is_some()
-                {
-                    return Err(anyhow!("Contract utxo should not exist"))
+                StateEntry::ContractState(state) => {
+                    init_contract_state_entry(db, &state)?;
+                    None
                 }
-                init_contract_state(db, &contract_id, contract_config)?;
-                init_contract_balance(db, &contract_id, contract_config)?;
-                contracts_tree
-                    .push(ContractRef::new(&mut *db, contract_id).root()?.as_slice());
+                StateEntry::ContractBalance(balance) => {
+                    init_contract_balance_entry(db, &balance)?;
+                    None
+                }
+                StateEntry::Message(message) => Some(init_da_message(db, message)?),
+            };
+            if let Some(root) = root {
+                tree.push(root.as_slice());
             }
+            imported = imported.checked_add(1).expect(
+                "The maximum number of entries in the genesis configuration has been exceeded.",
+            );
         }
+
+        db.update_genesis_progress(table.progress_key(), imported)?;
+        database_transaction.commit()?;
+
+        table.report_progress(i64::try_from(imported)?);
+        tracing::info!("Imported {imported} genesis {}", table.name());
+    }
+
+    Ok(tree.root())
+}
+
+/// Creates the coin from the config, generating the `UtxoId` if it is not specified.
+fn coin_from_config(
+    coin: &CoinConfig,
+    generated_output_index: &mut u64,
+) -> (UtxoId, CompressedCoin) {
+    let utxo_id = UtxoId::new(
+        // generated transaction id([0..[out_index/255]])
+        coin.tx_id.unwrap_or_else(|| {
+            Bytes32::try_from(
+                (0..(Bytes32::LEN - WORD_SIZE))
+                    .map(|_| 0u8)
+                    .chain((*generated_output_index / 255).to_be_bytes().into_iter())
+                    .collect_vec()
+                    .as_slice(),
+            )
+            .expect("Incorrect genesis transaction id byte length")
+        }),
+        coin.output_index.unwrap_or_else(|| {
+            *generated_output_index = generated_output_index
+                .checked_add(1)
+                .expect("The maximum number of UTXOs supported in the genesis configuration has been exceeded.");
+            (*generated_output_index % 255) as u8
+        }),
+    );
+
+    let coin = CompressedCoin {
+        owner: coin.owner,
+        amount: coin.amount,
+        asset_id: coin.asset_id,
+        maturity: coin.maturity.unwrap_or_default(),
+        tx_pointer: TxPointer::new(
+            coin.tx_pointer_block_height.unwrap_or_default(),
+            coin.tx_pointer_tx_idx.unwrap_or_default(),
+        ),
+    };
+    (utxo_id, coin)
+}
+
+fn init_coin(
+    db: &mut Database,
+    coin: &CoinConfig,
+    height: BlockHeight,
+    generated_output_index: &mut u64,
+) -> anyhow::Result<MerkleRoot> {
+    let (utxo_id, coin) = coin_from_config(coin, generated_output_index);
+
+    // ensure coin can't point to blocks in the future
+    if coin.tx_pointer.block_height() > height {
+        return Err(anyhow!(
+            "coin tx_pointer height cannot be greater than genesis block"
+        ))
+    }
+
+    if db.storage::<Coins>().insert(&utxo_id, &coin)?.is_some() {
+        return Err(anyhow!("Coin should not exist"))
     }
-    Ok(contracts_tree.root())
+    coin.root()
+}
+
+fn init_contract(
+    db: &mut Database,
+    contract_config: &ContractConfig,
+    height: BlockHeight,
+    generated_output_index: u64,
+) -> anyhow::Result<MerkleRoot> {
+    let contract = Contract::from(contract_config.code.as_slice());
+    let salt = contract_config.salt;
+    let root = contract.root();
+    let contract_id = contract_config.contract_id;
+    let utxo_id = if let (Some(tx_id), Some(output_idx)) =
+        (contract_config.tx_id, contract_config.output_index)
+    {
+        UtxoId::new(tx_id, output_idx)
+    } else {
+        #[allow(clippy::cast_possible_truncation)]
+        UtxoId::new(
+            // generated transaction id([0..[out_index/255]])
+            Bytes32::try_from(
+                (0..(Bytes32::LEN - WORD_SIZE))
+                    .map(|_| 0u8)
+                    .chain((generated_output_index / 255).to_be_bytes().into_iter())
+                    .collect_vec()
+                    .as_slice(),
+            )
+            .expect("Incorrect genesis transaction id byte length"),
+            generated_output_index as u8,
+        )
+    };
+    let tx_pointer = if let (Some(block_height), Some(tx_idx)) = (
+        contract_config.tx_pointer_block_height,
+        contract_config.tx_pointer_tx_idx,
+    ) {
+        TxPointer::new(block_height, tx_idx)
+    } else {
+        TxPointer::default()
+    };
+
+    if tx_pointer.block_height() > height {
+        return Err(anyhow!(
+            "contract tx_pointer cannot be greater than genesis block"
+        ))
+    }
+
+    // insert contract code
+    if db
+        .storage::<ContractsRawCode>()
+        .insert(&contract_id, contract.as_ref())?
+        .is_some()
+    {
+        return Err(anyhow!("Contract code should not exist"))
+    }
+
+    // insert contract root
+    if db
+        .storage::<ContractsInfo>()
+        .insert(&contract_id, &(salt, root))?
+        .is_some()
+    {
+        return Err(anyhow!("Contract info should not exist"))
+    }
+    if db
+        .storage::<ContractsLatestUtxo>()
+        .insert(
+            &contract_id,
+            &ContractUtxoInfo {
+                utxo_id,
+                tx_pointer,
+            },
+        )?
+        .is_some()
+    {
+        return Err(anyhow!("Contract utxo should not exist"))
+    }
+    init_contract_state(db, &contract_id, contract_config)?;
+    init_contract_balance(db, &contract_id, contract_config)?;
+    Ok(ContractRef::new(&mut *db, contract_id).root()?)
 }
 
 fn init_contract_state(
@@ -310,36 +625,47 @@ fn init_contract_state(
     Ok(())
 }
 
-fn init_da_messages(
+fn init_contract_state_entry(
     db: &mut Database,
-    state: &Option<StateConfig>,
-) -> anyhow::Result<MerkleRoot> {
-    let mut message_tree = binary::in_memory::MerkleTree::new();
-    if let Some(state) = &state {
-        if let Some(message_state) = &state.messages {
-            for msg in message_state {
-                let message = Message {
-                    sender: msg.sender,
-                    recipient: msg.recipient,
-                    nonce: msg.nonce,
-                    amount: msg.amount,
-                    data: msg.data.clone(),
-                    da_height: msg.da_height,
-                };
+    state: &ContractStateConfig,
+) -> anyhow::Result<()> {
+    let key = ContractsStateKey::new(&state.contract_id, &state.key);
+    if db
+        .storage::<ContractsState>()
+        .insert(&key, &state.value)?
+        .is_some()
+    {
+        return Err(anyhow!("Contract state should not exist"))
+    }
+    Ok(())
+}
 
-                if db
-                    .storage::<Messages>()
-                    .insert(message.id(), &message)?
-                    .is_some()
-                {
-                    return Err(anyhow!("Message should not exist"))
-                }
-                message_tree.push(message.root()?.as_slice());
-            }
-        }
+fn init_contract_balance_entry(
+    db: &mut Database,
+    balance: &ContractBalanceConfig,
+) -> anyhow::Result<()> {
+    let key = ContractsAssetKey::new(&balance.contract_id, &balance.asset_id);
+    if db
+        .storage::<ContractsAssets>()
+        .insert(&key, &balance.amount)?
+        .is_some()
+    {
+        return Err(anyhow!("Contract balance should not exist"))
     }
+    Ok(())
+}
+
+fn init_da_message(db: &mut Database, msg: MessageConfig) -> anyhow::Result<MerkleRoot> {
+    let message = Message::from(msg);
 
-    Ok(message_tree.root())
+    if db
+        .storage::<Messages>()
+        .insert(message.id(), &message)?
+        .is_some()
+    {
+        return Err(anyhow!("Message should not exist"))
+    }
+    message.root()
 }
 
 fn init_contract_balance(
@@ -362,18 +688,8 @@ mod tests {
         config::Config,
         FuelService,
     };
-    use fuel_core_chain_config::{
-        ChainConfig,
-        CoinConfig,
-        MessageConfig,
-    };
-    use fuel_core_storage::{
-        tables::{
-            ContractsAssets,
-            ContractsState,
-        },
-        StorageAsRef,
-    };
+    use fuel_core_chain_config::ChainConfig;
+    use fuel_core_storage::StorageAsRef;
     use fuel_core_types::{
         blockchain::primitives::DaBlockHeight,
         entities::coins::coin::Coin,
@@ -381,7 +697,6 @@ mod tests {
         fuel_types::{
             Address,
             AssetId,
-            BlockHeight,
             Salt,
         },
     };
@@ -623,6 +938,127 @@ mod tests {
         assert_eq!(expected_msg, ret_msg);
     }
 
+    #[test]
+    fn genesis_import_resumes_after_interruption() {
+        let mut rng = StdRng::seed_from_u64(10);
+        let coins: Vec<_> = (0..10)
+            .map(|_| CoinConfig {
+                tx_id: None,
+                output_index: None,
+                tx_pointer_block_height: None,
+                tx_pointer_tx_idx: None,
+                maturity: None,
+                owner: rng.gen(),
+                amount: rng.gen(),
+                asset_id: rng.gen(),
+            })
+            .collect();
+        let messages: Vec<_> = (0..10)
+            .map(|_| MessageConfig {
+                sender: rng.gen(),
+                recipient: rng.gen(),
+                nonce: rng.gen(),
+                amount: rng.gen(),
+                data: vec![rng.gen()],
+                da_height: DaBlockHeight(0),
+            })
+            .collect();
+        let mut config = Config::local_node();
+        config.chain_conf.initial_state = Some(StateConfig {
+            coins: Some(coins.clone()),
+            messages: Some(messages.clone()),
+            ..Default::default()
+        });
+
+        let expected_db = Database::default();
+        maybe_initialize_state(&config, &expected_db).unwrap();
+
+        // Import only a part of the state as if the node was stopped.
+        let db = Database::default();
+        let partial_state = StateConfig {
+            coins: Some(coins[..4].to_vec()),
+            messages: Some(messages[..7].to_vec()),
+            ..Default::default()
+        };
+        for table in StateTable::ALL {
+            import_table(
+                &db,
+                &StateSource::Config(Some(&partial_state)),
+                table,
+                0u32.into(),
+            )
+            .unwrap();
+        }
+        assert!(db.ids_of_latest_block().unwrap().is_none());
+        assert_eq!(db.genesis_progress(GENESIS_COINS_PROGRESS).unwrap(), 4);
+
+        maybe_initialize_state(&config, &db).unwrap();
+
+        assert_eq!(
+            db.get_genesis().unwrap(),
+            expected_db.get_genesis().unwrap()
+        );
+        assert_eq!(db.genesis_progress(GENESIS_COINS_PROGRESS).unwrap(), 0);
+        assert_eq!(db.genesis_progress(GENESIS_MESSAGES_PROGRESS).unwrap(), 0);
+        assert_eq!(db.genesis_source_hash().unwrap(), None);
+    }
+
+    #[test]
+    fn genesis_import_is_not_resumed_with_another_state() {
+        let mut rng = StdRng::seed_from_u64(10);
+        let coin = |rng: &mut StdRng| CoinConfig {
+            tx_id: None,
+            output_index: None,
+            tx_pointer_block_height: None,
+            tx_pointer_tx_idx: None,
+            maturity: None,
+            owner: rng.gen(),
+            amount: rng.gen(),
+            asset_id: rng.gen(),
+        };
+        let interrupted_state = StateConfig {
+            coins: Some(vec![coin(&mut rng), coin(&mut rng)]),
+            ..Default::default()
+        };
+        let mut config = Config::local_node();
+        config.chain_conf.initial_state = Some(interrupted_state.clone());
+
+        // The import with the first state is interrupted after the first coin.
+        let mut db = Database::default();
+        let partial_state = StateConfig {
+            coins: Some(interrupted_state.coins.clone().unwrap()[..1].to_vec()),
+            ..Default::default()
+        };
+        import_table(
+            &db,
+            &StateSource::Config(Some(&partial_state)),
+            StateTable::Coins,
+            0u32.into(),
+        )
+        .unwrap();
+        let chain_config_hash = config.chain_conf.root().unwrap().into();
+        let hash = StateSource::Config(Some(&interrupted_state))
+            .hash(&chain_config_hash)
+            .unwrap();
+        db.set_genesis_source_hash(&hash).unwrap();
+
+        // The node is restarted with another state.
+        config.chain_conf.initial_state = Some(StateConfig {
+            coins: Some(vec![coin(&mut rng), coin(&mut rng)]),
+            ..Default::default()
+        });
+        let result = maybe_initialize_state(&config, &db);
+
+        assert!(result.is_err());
+        assert!(db.ids_of_latest_block().unwrap().is_none());
+        assert_eq!(db.genesis_progress(GENESIS_COINS_PROGRESS).unwrap(), 1);
+
+        // The import is resumed with the original state.
+        config.chain_conf.initial_state = Some(interrupted_state);
+        maybe_initialize_state(&config, &db).unwrap();
+        assert!(db.ids_of_latest_block().unwrap().is_some());
+    }
+
     #[tokio::test]
     async fn config_state_initializes_contract_balance() {
         let mut rng = StdRng::seed_from_u64(10);
@@ -1,5 +1,7 @@
 #![allow(clippy::let_unit_value)]
 use super::adapters::P2PAdapter;
+#[cfg(feature = "p2p")]
+use super::adapters::P2PTxPoolAdapter;
 
 use crate::{
     database::Database,
@@ -10,6 +12,7 @@ use crate::{
         adapters::{
             BlockImporterAdapter,
             BlockProducerAdapter,
+            BlockValidatorAdapter,
             ExecutorAdapter,
             MaybeRelayerAdapter,
             PoAAdapter,
@@ -21,6 +24,7 @@ use crate::{
         SubServices,
     },
 };
+use fuel_core_chain_config::ConsensusConfig;
 use fuel_core_poa::Trigger;
 use std::sync::Arc;
 use tokio::sync::Mutex;
@@ -32,10 +36,17 @@ use fuel_core_types::blockchain::primitives::DaBlockHeight;
 
 pub type PoAService =
     fuel_core_poa::Service<TxPoolAdapter, BlockProducerAdapter, BlockImporterAdapter>;
+pub type BftService = fuel_core_bft::Service<
+    P2PAdapter,
+    TxPoolAdapter,
+    BlockProducerAdapter,
+    BlockValidatorAdapter,
+    BlockImporterAdapter,
+>;
 #[cfg(feature = "relayer")]
 pub type RelayerService = fuel_core_relayer::Service<Database>;
 #[cfg(feature = "p2p")]
-pub type P2PService = fuel_core_p2p::service::Service<Database>;
+pub type P2PService = fuel_core_p2p::service::Service<Database, P2PTxPoolAdapter>;
 pub type TxPoolService = fuel_core_txpool::Service<P2PAdapter, Database>;
 pub type BlockProducerService = fuel_core_producer::block_producer::Producer<
     Database,
@@ -95,6 +106,9 @@ pub fn init_sub_services(
         verifier.clone(),
     );
 
+    #[cfg(feature = "p2p")]
+    let p2p_tx_pool_adapter = P2PTxPoolAdapter::default();
+
     #[cfg(feature = "p2p")]
     let mut network = {
         if let Some(p2p_config) = config.p2p.clone() {
@@ -106,6 +120,7 @@ pub fn init_sub_services(
                 config.chain_conf.consensus_parameters.chain_id,
                 p2p_config,
                 p2p_db,
+                p2p_tx_pool_adapter.clone(),
                 importer_adapter.clone(),
             ))
         } else {
@@ -115,17 +130,11 @@ pub fn init_sub_services(
 
     #[cfg(feature = "p2p")]
     let p2p_adapter = {
-        use crate::service::adapters::PeerReportConfig;
-
-        // Hardcoded for now, but left here to be configurable in the future.
-        // TODO: https://github.com/FuelLabs/fuel-core/issues/1340
-        let peer_report_config = PeerReportConfig {
-            successful_block_import: 5.,
-            missing_block_headers: -100.,
-            bad_block_header: -100.,
-            missing_transactions: -100.,
-            invalid_transactions: -100.,
-        };
+        let peer_report_config = config
+            .p2p
+            .as_ref()
+            .map(|p2p_config| p2p_config.reputation_config.peer_report_config.clone())
+            .unwrap_or_default();
         P2PAdapter::new(
             network.as_ref().map(|network| network.shared.clone()),
             peer_report_config,
@@ -142,12 +151,14 @@ pub fn init_sub_services(
         p2p_adapter.clone(),
     );
     let tx_pool_adapter = TxPoolAdapter::new(txpool.shared.clone());
+    #[cfg(feature = "p2p")]
+    p2p_tx_pool_adapter.set(tx_pool_adapter.clone());
 
     let block_producer = fuel_core_producer::Producer {
         config: config.block_producer.clone(),
         db: database.clone(),
         txpool: tx_pool_adapter.clone(),
-        executor: Arc::new(executor),
+        executor: Arc::new(executor.clone()),
         relayer: Box::new(relayer_adapter),
         lock: Mutex::new(()),
     };
@@ -161,7 +172,10 @@ pub fn init_sub_services(
         tracing::info!("Enabled manual block production because of `debug` flag");
     }
 
-    let poa = (production_enabled).then(|| {
+    // With the BFT consensus blocks are committed by validators instead of the PoA producer.
+    let is_bft = matches!(config.chain_conf.consensus, ConsensusConfig::Bft { .. });
+
+    let poa = (production_enabled && !is_bft).then(|| {
         fuel_core_poa::new_service(
             last_block.header(),
             poa_config,
@@ -173,12 +187,29 @@ pub fn init_sub_services(
     });
     let poa_adapter = PoAAdapter::new(poa.as_ref().map(|service| service.shared.clone()));
 
+    // Nodes without the consensus key only follow the validators.
+    let is_validator = production_enabled && config.consensus_key.is_some();
+    let bft: Option<BftService> = if is_bft && is_validator {
+        Some(fuel_core_bft::new_service(
+            last_block.header(),
+            config.into(),
+            p2p_adapter.clone(),
+            tx_pool_adapter.clone(),
+            producer_adapter.clone(),
+            BlockValidatorAdapter::new(database.clone(), executor),
+            importer_adapter.clone(),
+        )?)
+    } else {
+        None
+    };
+
     #[cfg(feature = "p2p")]
     let sync = fuel_core_sync::service::new_service(
         *last_block.header().height(),
         p2p_adapter.clone(),
         importer_adapter.clone(),
         verifier,
+        tx_pool_adapter.clone(),
         config.sync,
     )?;
 
@@ -193,6 +224,8 @@ pub fn init_sub_services(
     let graphql_worker = fuel_core_graphql_api::worker_service::new_service(
         importer_adapter.clone(),
         database.clone(),
+        *last_block.header().height(),
+        config.block_importer.pruning_depth,
     );
 
     let graphql_config = GraphQLConfig {
@@ -203,6 +236,7 @@ pub fn init_sub_services(
         min_gas_price: config.txpool.min_gas_price,
         max_tx: config.txpool.max_tx,
         max_depth: config.txpool.max_depth,
+        block_gas_limit: config.chain_conf.block_gas_limit,
         consensus_parameters: config.chain_conf.consensus_parameters.clone(),
         consensus_key: config.consensus_key.clone(),
     };
@@ -216,6 +250,7 @@ pub fn init_sub_services(
         Box::new(producer_adapter),
         Box::new(poa_adapter.clone()),
         Box::new(p2p_adapter),
+        graphql_worker.shared.clone(),
         config.query_log_threshold_time,
         config.api_request_timeout,
     )?;
@@ -245,6 +280,10 @@ pub fn init_sub_services(
         services.push(Box::new(poa));
     }
 
+    if let Some(bft) = bft {
+        services.push(Box::new(bft));
+    }
+
     #[cfg(feature = "relayer")]
     if let Some(relayer) = relayer_service {
         services.push(Box::new(relayer));
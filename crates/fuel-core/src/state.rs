@@ -15,7 +15,11 @@ use fuel_core_storage::{
         IterDirection,
         IteratorableStore,
     },
-    kv_store::BatchOperations,
+    kv_store::{
+        BatchOperations,
+        Value,
+    },
+    Result as StorageResult,
 };
 use std::{
     fmt::Debug,
@@ -74,4 +78,14 @@ pub trait TransactableStorage:
     }
 
     fn flush(&self) -> DatabaseResult<()>;
+
+    /// Returns the keys modified within the `column` by the uncommitted changes
+    /// along with their values before the changes.
+    /// Only transactions have uncommitted changes.
+    fn previous_values(
+        &self,
+        _column: Column,
+    ) -> StorageResult<Vec<(Vec<u8>, Option<Value>)>> {
+        Ok(vec![])
+    }
 }
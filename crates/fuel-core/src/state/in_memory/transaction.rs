@@ -252,6 +252,25 @@ impl TransactableStorage for MemoryTransactionView {
         self.view_layer.flush()?;
         self.data_source.flush()
     }
+
+    fn previous_values(
+        &self,
+        column: Column,
+    ) -> StorageResult<Vec<(Vec<u8>, Option<Value>)>> {
+        let keys: Vec<_> = self.changes[column.as_usize()]
+            .lock()
+            .expect("poisoned lock")
+            .keys()
+            .cloned()
+            .collect();
+
+        keys.into_iter()
+            .map(|key| {
+                let value = self.data_source.get(&key, column)?;
+                Ok((key, value))
+            })
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -142,6 +142,8 @@ impl RocksDb {
 
         let mut opts = Options::default();
         opts.create_if_missing(true);
+        // New columns are created for the existing databases.
+        opts.create_missing_column_families(true);
         opts.set_compression_type(DBCompressionType::Lz4);
         if let Some(capacity) = capacity {
             // Set cache size 1/3 of the capacity. Another 1/3 is
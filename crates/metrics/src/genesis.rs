@@ -0,0 +1,71 @@
+use prometheus_client::{
+    metrics::gauge::Gauge,
+    registry::Registry,
+};
+use std::sync::OnceLock;
+
+pub struct GenesisMetrics {
+    pub registry: Registry,
+    pub imported_coins: Gauge,
+    pub imported_contracts: Gauge,
+    pub imported_contracts_state: Gauge,
+    pub imported_contracts_balances: Gauge,
+    pub imported_messages: Gauge,
+}
+
+impl Default for GenesisMetrics {
+    fn default() -> Self {
+        let mut registry = Registry::default();
+
+        let imported_coins = Gauge::default();
+        let imported_contracts = Gauge::default();
+        let imported_contracts_state = Gauge::default();
+        let imported_contracts_balances = Gauge::default();
+        let imported_messages = Gauge::default();
+
+        registry.register(
+            "genesis_imported_coins",
+            "The number of coins imported from the genesis state",
+            imported_coins.clone(),
+        );
+
+        registry.register(
+            "genesis_imported_contracts",
+            "The number of contracts imported from the genesis state",
+            imported_contracts.clone(),
+        );
+
+        registry.register(
+            "genesis_imported_contracts_state",
+            "The number of contract storage entries imported from the genesis state",
+            imported_contracts_state.clone(),
+        );
+
+        registry.register(
+            "genesis_imported_contracts_balances",
+            "The number of contract balances imported from the genesis state",
+            imported_contracts_balances.clone(),
+        );
+
+        registry.register(
+            "genesis_imported_messages",
+            "The number of messages imported from the genesis state",
+            imported_messages.clone(),
+        );
+
+        Self {
+            registry,
+            imported_coins,
+            imported_contracts,
+            imported_contracts_state,
+            imported_contracts_balances,
+            imported_messages,
+        }
+    }
+}
+
+static GENESIS_METRICS: OnceLock<GenesisMetrics> = OnceLock::new();
+
+pub fn genesis_metrics() -> &'static GenesisMetrics {
+    GENESIS_METRICS.get_or_init(GenesisMetrics::default)
+}
@@ -7,6 +7,7 @@ use std::sync::OnceLock;
 
 pub mod core_metrics;
 pub mod future_tracker;
+pub mod genesis;
 pub mod graphql_metrics;
 pub mod importer;
 pub mod p2p_metrics;
@@ -1,15 +1,38 @@
 use once_cell::race::OnceBox;
 use prometheus_client::{
-    metrics::counter::Counter,
+    encoding::EncodeLabelSet,
+    metrics::{
+        counter::Counter,
+        family::Family,
+        gauge::Gauge,
+    },
     registry::Registry,
 };
-use std::sync::OnceLock;
+use std::sync::{
+    atomic::AtomicU64,
+    OnceLock,
+};
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct PeerLabel {
+    // the id of the peer
+    peer_id: String,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct QuotaLabel {
+    // the exceeded quota of the inbound requests
+    quota: String,
+}
 
 pub struct P2PMetrics {
     pub gossip_sub_registry: OnceBox<Registry>,
     // For descriptions of each Counter, see the `new` function where each Counter/Histogram is initialized
     pub peer_metrics: Registry,
     pub unique_peers: Counter,
+    peer_app_scores: Family<PeerLabel, Gauge<f64, AtomicU64>>,
+    refused_requests: Family<QuotaLabel, Counter>,
+    pub served_response_bytes: Counter,
 }
 
 impl P2PMetrics {
@@ -17,11 +40,17 @@ impl P2PMetrics {
         let peer_metrics = Registry::default();
 
         let unique_peers = Counter::default();
+        let peer_app_scores = Family::default();
+        let refused_requests = Family::default();
+        let served_response_bytes = Counter::default();
 
         let mut metrics = P2PMetrics {
             gossip_sub_registry: OnceBox::new(),
             peer_metrics,
             unique_peers,
+            peer_app_scores,
+            refused_requests,
+            served_response_bytes,
         };
 
         metrics.peer_metrics.register(
@@ -30,8 +59,44 @@ impl P2PMetrics {
             metrics.unique_peers.clone(),
         );
 
+        metrics.peer_metrics.register(
+            "Peer_App_Score",
+            "A Gauge which keeps track of the application score of each connected peer",
+            metrics.peer_app_scores.clone(),
+        );
+
+        metrics.peer_metrics.register(
+            "Refused_Requests_Counter",
+            "A Counter which keeps track of the inbound requests refused because the peer exceeded its quota",
+            metrics.refused_requests.clone(),
+        );
+
+        metrics.peer_metrics.register(
+            "Served_Response_Bytes_Counter",
+            "A Counter which keeps track of the bytes served in the responses to the inbound requests",
+            metrics.served_response_bytes.clone(),
+        );
+
         metrics
     }
+
+    pub fn set_peer_app_score(&self, peer_id: String, score: f64) {
+        self.peer_app_scores
+            .get_or_create(&PeerLabel { peer_id })
+            .set(score);
+    }
+
+    pub fn inc_refused_requests(&self, quota: &str) {
+        self.refused_requests
+            .get_or_create(&QuotaLabel {
+                quota: quota.to_string(),
+            })
+            .inc();
+    }
+
+    pub fn remove_peer_app_score(&self, peer_id: String) {
+        self.peer_app_scores.remove(&PeerLabel { peer_id });
+    }
 }
 
 static P2P_METRICS: OnceLock<P2PMetrics> = OnceLock::new();
@@ -0,0 +1,71 @@
+use fuel_core_types::{
+    blockchain::primitives::SecretKeyWrapper,
+    fuel_asm::Word,
+    fuel_types::{
+        Address,
+        ChainId,
+    },
+    secrecy::Secret,
+};
+use tokio::time::Duration;
+
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// The key of the validator. Without it the node can't participate in the consensus.
+    pub signing_key: Option<Secret<SecretKeyWrapper>>,
+    /// The id of the chain. Validators sign it in every message, so the messages
+    /// can't be replayed on another chain with the same validators.
+    pub chain_id: ChainId,
+    /// The set of validators that commit blocks.
+    pub validators: Vec<Address>,
+    /// The minimal time between the commit of the previous block and the start
+    /// of the consensus for the next one.
+    pub block_time: Duration,
+    pub block_gas_limit: Word,
+    pub timeouts: Timeouts,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            signing_key: None,
+            chain_id: ChainId::default(),
+            validators: vec![],
+            block_time: Duration::ZERO,
+            block_gas_limit: 0,
+            timeouts: Timeouts::default(),
+        }
+    }
+}
+
+/// The timeouts of the consensus steps. Each timeout grows by `delta`
+/// with every round to give the network more time to reach the agreement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timeouts {
+    /// How long validators wait for the proposal.
+    pub propose: Duration,
+    /// How long validators wait for more prevotes after receiving a quorum of any prevotes.
+    pub prevote: Duration,
+    /// How long validators wait for more precommits after receiving a quorum of any precommits.
+    pub precommit: Duration,
+    /// The increase of timeouts per round.
+    pub delta: Duration,
+}
+
+impl Default for Timeouts {
+    fn default() -> Self {
+        Self {
+            propose: Duration::from_secs(3),
+            prevote: Duration::from_secs(1),
+            precommit: Duration::from_secs(1),
+            delta: Duration::from_millis(500),
+        }
+    }
+}
+
+impl Timeouts {
+    /// Returns the timeout of the `base` duration in the `round`.
+    pub fn in_round(&self, base: Duration, round: u32) -> Duration {
+        base.saturating_add(self.delta.saturating_mul(round))
+    }
+}
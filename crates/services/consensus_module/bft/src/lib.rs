@@ -1,4 +1,21 @@
 #![deny(clippy::arithmetic_side_effects)]
 #![deny(clippy::cast_possible_truncation)]
 #![deny(unused_crate_dependencies)]
+#![deny(unused_must_use)]
 #![deny(warnings)]
+
+pub mod config;
+pub mod ports;
+pub mod service;
+pub mod tendermint;
+pub mod validators;
+pub mod verifier;
+
+pub use config::{
+    Config,
+    Timeouts,
+};
+pub use service::{
+    new_service,
+    Service,
+};
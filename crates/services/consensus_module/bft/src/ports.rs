@@ -0,0 +1,71 @@
+use fuel_core_services::stream::BoxStream;
+use fuel_core_types::{
+    blockchain::{
+        block::Block,
+        consensus::bft::ConsensusMessage,
+        SealedBlock,
+    },
+    fuel_asm::Word,
+    fuel_tx::TxId,
+    fuel_types::BlockHeight,
+    services::{
+        block_importer::BlockImportInfo,
+        executor::ExecutionResult,
+        p2p::{
+            ConsensusGossipData,
+            GossipsubMessageAcceptance,
+            GossipsubMessageInfo,
+        },
+        txpool::ArcPoolTx,
+    },
+    tai64::Tai64,
+};
+use std::sync::Arc;
+
+pub trait ConsensusNetwork: Send + Sync {
+    /// Gossip broadcast the consensus message to other validators.
+    fn broadcast_consensus_message(
+        &self,
+        message: Arc<ConsensusMessage>,
+    ) -> anyhow::Result<()>;
+
+    /// Creates a stream of consensus messages gossiped from the network.
+    fn gossiped_consensus_messages(&self) -> BoxStream<ConsensusGossipData>;
+
+    /// Report the validity of the consensus message received from the network.
+    fn notify_gossip_consensus_message_validity(
+        &self,
+        message_info: GossipsubMessageInfo,
+        validity: GossipsubMessageAcceptance,
+    ) -> anyhow::Result<()>;
+}
+
+pub trait TransactionPool: Send + Sync {
+    fn remove_txs(&self, tx_ids: Vec<TxId>) -> Vec<ArcPoolTx>;
+}
+
+#[async_trait::async_trait]
+pub trait BlockProducer: Send + Sync {
+    /// Produces and executes the block with transactions from the `TxPool`.
+    /// The result of the execution is not committed.
+    async fn produce_block(
+        &self,
+        height: BlockHeight,
+        block_time: Tai64,
+        max_gas: Word,
+    ) -> anyhow::Result<ExecutionResult>;
+}
+
+#[async_trait::async_trait]
+pub trait BlockValidator: Send + Sync {
+    /// Validates the proposed block on top of the latest committed block.
+    async fn validate_block(&self, block: Block) -> anyhow::Result<()>;
+}
+
+#[async_trait::async_trait]
+pub trait BlockImporter: Send + Sync {
+    /// Executes and commits the block sealed by validators.
+    async fn execute_and_commit(&self, block: SealedBlock) -> anyhow::Result<()>;
+
+    fn block_stream(&self) -> BoxStream<BlockImportInfo>;
+}
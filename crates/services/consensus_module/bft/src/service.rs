@@ -0,0 +1,484 @@
+use crate::{
+    ports::{
+        BlockImporter,
+        BlockProducer,
+        BlockValidator,
+        ConsensusNetwork,
+        TransactionPool,
+    },
+    tendermint::{
+        Output,
+        Tendermint,
+        Timeout,
+    },
+    validators::ValidatorSet,
+    Config,
+};
+use anyhow::anyhow;
+use fuel_core_services::{
+    stream::BoxStream,
+    EmptyShared,
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+};
+use fuel_core_types::{
+    blockchain::{
+        consensus::bft::ConsensusMessage,
+        header::BlockHeader,
+        SealedBlock,
+    },
+    fuel_crypto::SecretKey,
+    fuel_types::BlockHeight,
+    secrecy::ExposeSecret,
+    services::{
+        block_importer::BlockImportInfo,
+        executor::ExecutionResult,
+        p2p::{
+            ConsensusGossipData,
+            GossipData,
+            GossipsubMessageAcceptance,
+            GossipsubMessageInfo,
+        },
+    },
+    tai64::Tai64,
+};
+use std::{
+    ops::Deref,
+    sync::Arc,
+};
+use tokio::time::Instant;
+use tokio_stream::StreamExt;
+
+/// The maximum number of messages buffered until the validator reaches their height.
+const MAX_BUFFERED_MESSAGES: usize = 1024;
+
+pub type Service<N, T, B, V, I> = ServiceRunner<MainTask<N, T, B, V, I>>;
+
+pub struct MainTask<N, T, B, V, I> {
+    config: Config,
+    signing_key: SecretKey,
+    validators: ValidatorSet,
+    network: N,
+    txpool: T,
+    block_producer: B,
+    block_validator: V,
+    block_importer: I,
+    gossiped_messages: BoxStream<ConsensusGossipData>,
+    imported_blocks: BoxStream<BlockImportInfo>,
+    /// The consensus state of the next height.
+    engine: Tendermint,
+    /// The time when the consensus of the next height starts.
+    /// It is `None` if the consensus already started.
+    start_at: Option<Instant>,
+    last_timestamp: Tai64,
+    timers: Vec<(Instant, Timeout)>,
+    /// Messages of the next height received before the consensus of the height started.
+    buffered_messages: Vec<ConsensusMessage>,
+}
+
+impl<N, T, B, V, I> MainTask<N, T, B, V, I>
+where
+    N: ConsensusNetwork,
+    I: BlockImporter,
+{
+    pub fn new(
+        last_block: &BlockHeader,
+        config: Config,
+        network: N,
+        txpool: T,
+        block_producer: B,
+        block_validator: V,
+        block_importer: I,
+    ) -> anyhow::Result<Self> {
+        let signing_key = config
+            .signing_key
+            .as_ref()
+            .map(|key| *key.expose_secret().deref())
+            .ok_or_else(|| {
+                anyhow!("unable to participate in BFT consensus without a consensus key")
+            })?;
+        let validators = ValidatorSet::new(config.validators.clone());
+        let gossiped_messages = network.gossiped_consensus_messages();
+        let imported_blocks = block_importer.block_stream();
+        let engine = Tendermint::new(
+            next_height(last_block),
+            config.chain_id,
+            validators.clone(),
+            signing_key,
+            config.timeouts,
+        );
+
+        Ok(Self {
+            config,
+            signing_key,
+            validators,
+            network,
+            txpool,
+            block_producer,
+            block_validator,
+            block_importer,
+            gossiped_messages,
+            imported_blocks,
+            engine,
+            start_at: Some(Instant::now()),
+            last_timestamp: last_block.time(),
+            timers: vec![],
+            buffered_messages: vec![],
+        })
+    }
+
+    /// Prepares the consensus of the height after the `last_block`.
+    fn move_to_next_height(&mut self, last_block: &BlockHeader) {
+        let height = next_height(last_block);
+        tracing::debug!("Starting BFT consensus for the block {height}");
+        self.engine = Tendermint::new(
+            height,
+            self.config.chain_id,
+            self.validators.clone(),
+            self.signing_key,
+            self.config.timeouts,
+        );
+        self.last_timestamp = last_block.time();
+        self.timers.clear();
+        self.start_at = Some(
+            Instant::now()
+                .checked_add(self.config.block_time)
+                .unwrap_or_else(Instant::now),
+        );
+        self.buffered_messages
+            .retain(|message| message.height() >= height);
+    }
+
+    /// The closest time when the task should wake up.
+    fn next_deadline(&self) -> Option<Instant> {
+        self.timers
+            .iter()
+            .map(|(at, _)| *at)
+            .chain(self.start_at)
+            .min()
+    }
+}
+
+impl<N, T, B, V, I> MainTask<N, T, B, V, I>
+where
+    N: ConsensusNetwork,
+    T: TransactionPool,
+    B: BlockProducer,
+    V: BlockValidator,
+    I: BlockImporter,
+{
+    async fn on_deadline(&mut self) {
+        let now = Instant::now();
+        if self.start_at.map_or(false, |at| at <= now) {
+            self.start_at = None;
+            self.engine.start();
+            for message in core::mem::take(&mut self.buffered_messages) {
+                if message.height() == self.engine.height() {
+                    self.apply_message(message).await;
+                } else {
+                    self.buffered_messages.push(message);
+                }
+            }
+        }
+
+        let (expired, timers): (Vec<_>, Vec<_>) = core::mem::take(&mut self.timers)
+            .into_iter()
+            .partition(|(at, _)| *at <= now);
+        self.timers = timers;
+        for (_, timeout) in expired {
+            self.engine.on_timeout(timeout);
+        }
+        self.process_outputs().await;
+    }
+
+    fn on_imported_block(&mut self, block_info: BlockImportInfo) {
+        let header = &block_info.block_header;
+        if *header.height() >= self.engine.height() {
+            self.move_to_next_height(header);
+        }
+    }
+
+    async fn on_gossiped_message(&mut self, gossip: ConsensusGossipData) {
+        let GossipData {
+            data,
+            peer_id,
+            message_id,
+        } = gossip;
+        let message = match data {
+            Some(message) => message,
+            None => return,
+        };
+
+        let acceptance = self.handle_message(message).await;
+        self.process_outputs().await;
+
+        if acceptance != GossipsubMessageAcceptance::Ignore {
+            let message_info = GossipsubMessageInfo {
+                message_id,
+                peer_id,
+            };
+            let _ = self
+                .network
+                .notify_gossip_consensus_message_validity(message_info, acceptance);
+        }
+    }
+
+    async fn handle_message(
+        &mut self,
+        message: ConsensusMessage,
+    ) -> GossipsubMessageAcceptance {
+        match message.signer(&self.config.chain_id) {
+            Some(signer) if self.validators.contains(&signer) => {}
+            _ => return GossipsubMessageAcceptance::Reject,
+        }
+
+        let height = self.engine.height();
+        let message_height = message.height();
+        let is_next_height = height.succ() == Some(message_height);
+        let started = self.start_at.is_none();
+
+        if message_height < height {
+            GossipsubMessageAcceptance::Ignore
+        } else if message_height == height && started {
+            self.apply_message(message).await
+        } else if (message_height == height || is_next_height)
+            && self.buffered_messages.len() < MAX_BUFFERED_MESSAGES
+        {
+            self.buffered_messages.push(message);
+            GossipsubMessageAcceptance::Accept
+        } else {
+            GossipsubMessageAcceptance::Ignore
+        }
+    }
+
+    /// Passes the message of the current height to the consensus.
+    async fn apply_message(
+        &mut self,
+        message: ConsensusMessage,
+    ) -> GossipsubMessageAcceptance {
+        let accepted = match message {
+            ConsensusMessage::Proposal(proposal) => {
+                let proposer = self
+                    .validators
+                    .proposer(self.engine.height(), proposal.round);
+                if proposer != proposal.signer(&self.config.chain_id).as_ref() {
+                    return GossipsubMessageAcceptance::Reject
+                }
+                if !self.engine.is_round_kept(proposal.round)
+                    || self.engine.has_proposal(proposal.round)
+                {
+                    return GossipsubMessageAcceptance::Ignore
+                }
+
+                let result = self
+                    .block_validator
+                    .validate_block(proposal.block.clone())
+                    .await;
+                if let Err(err) = &result {
+                    tracing::warn!(
+                        "The proposed block {} is invalid: {:?}",
+                        proposal.height(),
+                        err
+                    );
+                }
+                let valid = result.is_ok();
+                let accepted = self.engine.on_proposal(proposal, valid);
+                if !valid {
+                    return GossipsubMessageAcceptance::Reject
+                }
+                accepted
+            }
+            ConsensusMessage::Vote(vote) => self.engine.on_vote(vote),
+        };
+
+        if accepted {
+            GossipsubMessageAcceptance::Accept
+        } else {
+            GossipsubMessageAcceptance::Ignore
+        }
+    }
+
+    /// Executes the actions requested by the consensus.
+    async fn process_outputs(&mut self) {
+        loop {
+            let outputs = self.engine.take_outputs();
+            if outputs.is_empty() {
+                return
+            }
+
+            for output in outputs {
+                match output {
+                    Output::Broadcast(message) => {
+                        if let Err(err) =
+                            self.network.broadcast_consensus_message(Arc::new(message))
+                        {
+                            tracing::debug!(
+                                "Failed to broadcast the consensus message: {:?}",
+                                err
+                            );
+                        }
+                    }
+                    Output::ProduceBlock { round } => self.produce_block(round).await,
+                    Output::ScheduleTimeout { timeout, after } => {
+                        let at = Instant::now()
+                            .checked_add(after)
+                            .expect("The timeout should be reasonable");
+                        self.timers.push((at, timeout));
+                    }
+                    Output::Decide(block) => self.commit(block).await,
+                }
+            }
+        }
+    }
+
+    async fn produce_block(&mut self, round: u32) {
+        let height = self.engine.height();
+        let block_time = Tai64::now().max(self.last_timestamp);
+        let result = self
+            .block_producer
+            .produce_block(height, block_time, self.config.block_gas_limit)
+            .await;
+
+        match result {
+            Ok(ExecutionResult {
+                block,
+                skipped_transactions,
+                ..
+            }) => {
+                let mut tx_ids_to_remove = Vec::with_capacity(skipped_transactions.len());
+                for (tx_id, err) in skipped_transactions {
+                    tracing::error!(
+                        "During block production got invalid transaction {:?} with error {:?}",
+                        tx_id,
+                        err
+                    );
+                    tx_ids_to_remove.push(tx_id);
+                }
+                self.txpool.remove_txs(tx_ids_to_remove);
+                self.engine.on_block_produced(round, block);
+            }
+            Err(err) => {
+                tracing::error!("Failed to produce the block {}: {:?}", height, err);
+            }
+        }
+    }
+
+    async fn commit(&mut self, block: SealedBlock) {
+        let header = block.entity.header().clone();
+        match self.block_importer.execute_and_commit(block).await {
+            Ok(()) => self.move_to_next_height(&header),
+            Err(err) => {
+                // The block can be already imported by the synchronizer.
+                tracing::error!(
+                    "Failed to commit the block {}: {:?}",
+                    header.height(),
+                    err
+                );
+            }
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<N, T, B, V, I> RunnableService for MainTask<N, T, B, V, I>
+where
+    Self: RunnableTask,
+{
+    const NAME: &'static str = "BFT";
+
+    type SharedData = EmptyShared;
+    type Task = MainTask<N, T, B, V, I>;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {
+        EmptyShared
+    }
+
+    async fn into_task(
+        self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        Ok(self)
+    }
+}
+
+#[async_trait::async_trait]
+impl<N, T, B, V, I> RunnableTask for MainTask<N, T, B, V, I>
+where
+    N: ConsensusNetwork,
+    T: TransactionPool,
+    B: BlockProducer,
+    V: BlockValidator,
+    I: BlockImporter,
+{
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        let should_continue;
+        let deadline = self.next_deadline();
+
+        tokio::select! {
+            biased;
+            _ = watcher.while_started() => {
+                should_continue = false;
+            }
+            Some(block_info) = self.imported_blocks.next() => {
+                self.on_imported_block(block_info);
+                should_continue = true;
+            }
+            message = self.gossiped_messages.next() => {
+                if let Some(message) = message {
+                    self.on_gossiped_message(message).await;
+                    should_continue = true;
+                } else {
+                    should_continue = false;
+                }
+            }
+            _ = tokio::time::sleep_until(deadline.unwrap_or_else(Instant::now)), if deadline.is_some() => {
+                self.on_deadline().await;
+                should_continue = true;
+            }
+        }
+        Ok(should_continue)
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        tracing::info!("BFT MainTask shutting down");
+        Ok(())
+    }
+}
+
+pub fn new_service<N, T, B, V, I>(
+    last_block: &BlockHeader,
+    config: Config,
+    network: N,
+    txpool: T,
+    block_producer: B,
+    block_validator: V,
+    block_importer: I,
+) -> anyhow::Result<Service<N, T, B, V, I>>
+where
+    N: ConsensusNetwork + 'static,
+    T: TransactionPool + 'static,
+    B: BlockProducer + 'static,
+    V: BlockValidator + 'static,
+    I: BlockImporter + 'static,
+{
+    Ok(Service::new(MainTask::new(
+        last_block,
+        config,
+        network,
+        txpool,
+        block_producer,
+        block_validator,
+        block_importer,
+    )?))
+}
+
+fn next_height(last_block: &BlockHeader) -> BlockHeight {
+    last_block
+        .height()
+        .succ()
+        .expect("It should be impossible to produce more blocks than u32::MAX")
+}
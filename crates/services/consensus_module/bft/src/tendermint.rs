@@ -0,0 +1,544 @@
+//! The Tendermint state machine that agrees on the block of one height.
+//!
+//! The state machine follows the algorithm from the
+//! "The latest gossip on BFT consensus" paper. It doesn't do any IO, instead
+//! it accumulates [`Output`]s that should be processed by the caller.
+
+use crate::{
+    config::Timeouts,
+    validators::ValidatorSet,
+};
+use fuel_core_types::{
+    blockchain::{
+        block::Block,
+        consensus::{
+            bft::{
+                BftConsensus,
+                ConsensusMessage,
+                Proposal,
+                Vote,
+                VoteType,
+            },
+            Consensus,
+        },
+        primitives::BlockId,
+        SealedBlock,
+    },
+    fuel_crypto::SecretKey,
+    fuel_tx::Input,
+    fuel_types::{
+        Address,
+        BlockHeight,
+        ChainId,
+    },
+};
+use std::{
+    collections::{
+        BTreeMap,
+        HashMap,
+        HashSet,
+    },
+    time::Duration,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The number of rounds after the current round for which the messages are kept.
+/// The messages of the rounds further in the future are ignored, so faulty validators
+/// can't fill the memory with votes for arbitrary rounds.
+pub const MAX_FUTURE_ROUNDS: u32 = 64;
+
+/// The step of the round.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Step {
+    Propose,
+    Prevote,
+    Precommit,
+}
+
+/// The timeout of the `step` in the `round`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Timeout {
+    pub round: u32,
+    pub step: Step,
+}
+
+/// The action requested by the state machine.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Output {
+    /// Broadcast the message to other validators.
+    Broadcast(ConsensusMessage),
+    /// Produce the block for the proposal in the `round`. The block should be
+    /// passed to the [`Tendermint::on_block_produced`].
+    ProduceBlock { round: u32 },
+    /// Call the [`Tendermint::on_timeout`] with the `timeout` after the duration.
+    ScheduleTimeout { timeout: Timeout, after: Duration },
+    /// Validators committed the block.
+    Decide(SealedBlock),
+}
+
+/// The consensus state of one height.
+pub struct Tendermint {
+    height: BlockHeight,
+    chain_id: ChainId,
+    validators: ValidatorSet,
+    signing_key: SecretKey,
+    address: Address,
+    timeouts: Timeouts,
+    round: u32,
+    step: Step,
+    /// The block that the validator precommitted, and the round of the precommit.
+    locked: Option<(u32, Block)>,
+    /// The last block that received the quorum of prevotes, and the round of the quorum.
+    valid: Option<(u32, Block)>,
+    /// The proposal of each round with the result of the block validation.
+    proposals: HashMap<u32, (Proposal, bool)>,
+    votes: HashMap<(u32, VoteType), BTreeMap<Address, Vote>>,
+    /// The validators that sent any message in the round.
+    senders: HashMap<u32, HashSet<Address>>,
+    prevote_timeout_scheduled: bool,
+    precommit_timeout_scheduled: bool,
+    prevote_quorum_processed: bool,
+    decided: bool,
+    outputs: Vec<Output>,
+}
+
+impl Tendermint {
+    pub fn new(
+        height: BlockHeight,
+        chain_id: ChainId,
+        validators: ValidatorSet,
+        signing_key: SecretKey,
+        timeouts: Timeouts,
+    ) -> Self {
+        let address = Input::owner(&signing_key.public_key());
+        Self {
+            height,
+            chain_id,
+            validators,
+            signing_key,
+            address,
+            timeouts,
+            round: 0,
+            step: Step::Propose,
+            locked: None,
+            valid: None,
+            proposals: HashMap::new(),
+            votes: HashMap::new(),
+            senders: HashMap::new(),
+            prevote_timeout_scheduled: false,
+            precommit_timeout_scheduled: false,
+            prevote_quorum_processed: false,
+            decided: false,
+            outputs: vec![],
+        }
+    }
+
+    pub fn height(&self) -> BlockHeight {
+        self.height
+    }
+
+    pub fn round(&self) -> u32 {
+        self.round
+    }
+
+    pub fn step(&self) -> Step {
+        self.step
+    }
+
+    pub fn validators(&self) -> &ValidatorSet {
+        &self.validators
+    }
+
+    pub fn is_decided(&self) -> bool {
+        self.decided
+    }
+
+    /// Returns `true` if the proposal of the `round` is already received.
+    pub fn has_proposal(&self, round: u32) -> bool {
+        self.proposals.contains_key(&round)
+    }
+
+    /// Returns `true` if the messages of the `round` are kept: the round is not
+    /// below the current round and not too far in the future.
+    pub fn is_round_kept(&self, round: u32) -> bool {
+        round >= self.round && round.saturating_sub(self.round) <= MAX_FUTURE_ROUNDS
+    }
+
+    /// Returns the outputs accumulated since the previous call.
+    pub fn take_outputs(&mut self) -> Vec<Output> {
+        core::mem::take(&mut self.outputs)
+    }
+
+    /// Starts the first round of the height.
+    pub fn start(&mut self) {
+        self.start_round(0);
+        self.process();
+    }
+
+    /// Proposes the produced `block` if the validator still waits for it.
+    pub fn on_block_produced(&mut self, round: u32, block: Block) {
+        if self.decided
+            || round != self.round
+            || self.step != Step::Propose
+            || self.proposals.contains_key(&round)
+            || *block.header().height() != self.height
+        {
+            return
+        }
+        self.propose(round, None, block);
+        self.process();
+    }
+
+    /// Processes the `proposal` from the network. The `valid` is the result
+    /// of the block validation.
+    ///
+    /// Returns `false` if the proposal is not expected by the state machine.
+    pub fn on_proposal(&mut self, proposal: Proposal, valid: bool) -> bool {
+        if self.decided
+            || proposal.height() != self.height
+            || !self.is_round_kept(proposal.round)
+        {
+            return false
+        }
+        let signer = match proposal.signer(&self.chain_id) {
+            Some(signer) => signer,
+            None => return false,
+        };
+        if self.validators.proposer(self.height, proposal.round) != Some(&signer)
+            || self.proposals.contains_key(&proposal.round)
+        {
+            return false
+        }
+        self.senders
+            .entry(proposal.round)
+            .or_default()
+            .insert(signer);
+        self.proposals.insert(proposal.round, (proposal, valid));
+        self.process();
+        true
+    }
+
+    /// Processes the `vote` from the network.
+    ///
+    /// Returns `false` if the vote is not expected by the state machine.
+    pub fn on_vote(&mut self, vote: Vote) -> bool {
+        if self.decided || vote.height != self.height || !self.is_round_kept(vote.round) {
+            return false
+        }
+        let signer = match vote.signer(&self.chain_id) {
+            Some(signer) => signer,
+            None => return false,
+        };
+        let added = self.record_vote(signer, vote);
+        if added {
+            self.process();
+        }
+        added
+    }
+
+    pub fn on_timeout(&mut self, timeout: Timeout) {
+        if self.decided || timeout.round != self.round {
+            return
+        }
+        match timeout.step {
+            Step::Propose if self.step == Step::Propose => {
+                self.vote(VoteType::Prevote, None)
+            }
+            Step::Prevote if self.step == Step::Prevote => {
+                self.vote(VoteType::Precommit, None)
+            }
+            Step::Precommit => match self.round.checked_add(1) {
+                Some(round) => self.start_round(round),
+                None => return,
+            },
+            _ => return,
+        }
+        self.process();
+    }
+
+    fn start_round(&mut self, round: u32) {
+        self.round = round;
+        self.step = Step::Propose;
+        self.prevote_timeout_scheduled = false;
+        self.precommit_timeout_scheduled = false;
+        self.prevote_quorum_processed = false;
+        self.drop_previous_rounds();
+
+        if self.validators.proposer(self.height, round) == Some(&self.address) {
+            match self.valid.clone() {
+                Some((valid_round, block)) => {
+                    self.propose(round, Some(valid_round), block)
+                }
+                None => self.outputs.push(Output::ProduceBlock { round }),
+            }
+        }
+        self.schedule_timeout(Step::Propose, self.timeouts.propose);
+    }
+
+    /// Removes the messages of the rounds below the current round. The prevotes
+    /// of the valid round are kept, because they are required to accept
+    /// the re-proposal of the valid block.
+    fn drop_previous_rounds(&mut self) {
+        let round = self.round;
+        let valid_round = self.valid.as_ref().map(|(valid_round, _)| *valid_round);
+        self.proposals
+            .retain(|proposal_round, _| *proposal_round >= round);
+        self.senders
+            .retain(|sender_round, _| *sender_round >= round);
+        self.votes.retain(|(vote_round, vote_type), _| {
+            *vote_round >= round
+                || (*vote_type == VoteType::Prevote && Some(*vote_round) == valid_round)
+        });
+    }
+
+    fn propose(&mut self, round: u32, valid_round: Option<u32>, block: Block) {
+        let proposal =
+            Proposal::sign(&self.signing_key, &self.chain_id, round, valid_round, block);
+        self.outputs
+            .push(Output::Broadcast(ConsensusMessage::Proposal(
+                proposal.clone(),
+            )));
+        self.senders.entry(round).or_default().insert(self.address);
+        self.proposals.insert(round, (proposal, true));
+    }
+
+    fn vote(&mut self, vote_type: VoteType, block_id: Option<BlockId>) {
+        let vote = Vote::sign(
+            &self.signing_key,
+            &self.chain_id,
+            self.height,
+            self.round,
+            vote_type,
+            block_id,
+        );
+        self.outputs
+            .push(Output::Broadcast(ConsensusMessage::Vote(vote.clone())));
+        self.record_vote(self.address, vote);
+        self.step = match vote_type {
+            VoteType::Prevote => Step::Prevote,
+            VoteType::Precommit => Step::Precommit,
+        };
+    }
+
+    fn record_vote(&mut self, signer: Address, vote: Vote) -> bool {
+        if !self.validators.contains(&signer) {
+            return false
+        }
+        let round = vote.round;
+        let votes = self.votes.entry((round, vote.vote_type)).or_default();
+        if votes.contains_key(&signer) {
+            return false
+        }
+        votes.insert(signer, vote);
+        self.senders.entry(round).or_default().insert(signer);
+        true
+    }
+
+    fn schedule_timeout(&mut self, step: Step, base: Duration) {
+        self.outputs.push(Output::ScheduleTimeout {
+            timeout: Timeout {
+                round: self.round,
+                step,
+            },
+            after: self.timeouts.in_round(base, self.round),
+        });
+    }
+
+    fn votes(&self, round: u32, vote_type: VoteType) -> impl Iterator<Item = &Vote> {
+        self.votes
+            .get(&(round, vote_type))
+            .into_iter()
+            .flat_map(|votes| votes.values())
+    }
+
+    fn count_votes(
+        &self,
+        round: u32,
+        vote_type: VoteType,
+        block_id: Option<&BlockId>,
+    ) -> usize {
+        self.votes(round, vote_type)
+            .filter(|vote| vote.block_id.as_ref() == block_id)
+            .count()
+    }
+
+    fn count_all_votes(&self, round: u32, vote_type: VoteType) -> usize {
+        self.votes
+            .get(&(round, vote_type))
+            .map(|votes| votes.len())
+            .unwrap_or_default()
+    }
+
+    fn has_quorum(
+        &self,
+        round: u32,
+        vote_type: VoteType,
+        block_id: Option<&BlockId>,
+    ) -> bool {
+        self.count_votes(round, vote_type, block_id) >= self.validators.quorum()
+    }
+
+    /// The proposal of the current round with the id of its block.
+    fn current_proposal(&self) -> Option<(&Proposal, BlockId, bool)> {
+        self.proposals
+            .get(&self.round)
+            .map(|(proposal, valid)| (proposal, proposal.block.id(), *valid))
+    }
+
+    /// Applies the rules of the algorithm until the state stops changing.
+    fn process(&mut self) {
+        while !self.decided {
+            let progressed = self.try_decide()
+                || self.try_skip_round()
+                || self.try_prevote_proposal()
+                || self.try_schedule_prevote_timeout()
+                || self.try_lock()
+                || self.try_precommit_nil()
+                || self.try_schedule_precommit_timeout();
+            if !progressed {
+                break
+            }
+        }
+    }
+
+    /// Decides the block of any round that received the quorum of precommits.
+    fn try_decide(&mut self) -> bool {
+        let decision = self
+            .proposals
+            .iter()
+            .find_map(|(round, (proposal, valid))| {
+                let block_id = proposal.block.id();
+                let decided = *valid
+                    && self.has_quorum(*round, VoteType::Precommit, Some(&block_id));
+                decided.then(|| (*round, block_id, proposal.block.clone()))
+            });
+        let (round, block_id, block) = match decision {
+            Some(decision) => decision,
+            None => return false,
+        };
+
+        let certificate = self
+            .votes(round, VoteType::Precommit)
+            .filter(|vote| vote.block_id == Some(block_id))
+            .map(|vote| vote.signature)
+            .collect();
+        self.decided = true;
+        self.outputs.push(Output::Decide(SealedBlock {
+            entity: block,
+            consensus: Consensus::Bft(BftConsensus::new(round, certificate)),
+        }));
+        true
+    }
+
+    /// Moves to the future round when at least one honest validator is already there.
+    fn try_skip_round(&mut self) -> bool {
+        let weak_quorum = self.validators.weak_quorum();
+        let round = self
+            .senders
+            .iter()
+            .filter(|(round, senders)| {
+                **round > self.round && senders.len() >= weak_quorum
+            })
+            .map(|(round, _)| *round)
+            .max();
+        match round {
+            Some(round) => {
+                self.start_round(round);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn try_prevote_proposal(&mut self) -> bool {
+        if self.step != Step::Propose {
+            return false
+        }
+        let (proposal, block_id, valid) = match self.current_proposal() {
+            Some(proposal) => proposal,
+            None => return false,
+        };
+        let locked = self
+            .locked
+            .as_ref()
+            .map(|(round, block)| (*round, block.id()));
+
+        let prevote = match proposal.valid_round {
+            None => valid && locked.map_or(true, |(_, locked_id)| locked_id == block_id),
+            Some(valid_round) => {
+                if valid_round >= self.round
+                    || !self.has_quorum(valid_round, VoteType::Prevote, Some(&block_id))
+                {
+                    return false
+                }
+                valid
+                    && locked.map_or(true, |(locked_round, locked_id)| {
+                        locked_round <= valid_round || locked_id == block_id
+                    })
+            }
+        };
+
+        self.vote(VoteType::Prevote, prevote.then_some(block_id));
+        true
+    }
+
+    fn try_schedule_prevote_timeout(&mut self) -> bool {
+        if self.step != Step::Prevote
+            || self.prevote_timeout_scheduled
+            || self.count_all_votes(self.round, VoteType::Prevote)
+                < self.validators.quorum()
+        {
+            return false
+        }
+        self.prevote_timeout_scheduled = true;
+        self.schedule_timeout(Step::Prevote, self.timeouts.prevote);
+        true
+    }
+
+    /// Locks on the valid block of the round that received the quorum of prevotes.
+    fn try_lock(&mut self) -> bool {
+        if self.step < Step::Prevote || self.prevote_quorum_processed {
+            return false
+        }
+        let (proposal, block_id, valid) = match self.current_proposal() {
+            Some(proposal) => proposal,
+            None => return false,
+        };
+        if !valid || !self.has_quorum(self.round, VoteType::Prevote, Some(&block_id)) {
+            return false
+        }
+
+        let block = proposal.block.clone();
+        self.prevote_quorum_processed = true;
+        if self.step == Step::Prevote {
+            self.locked = Some((self.round, block.clone()));
+            self.vote(VoteType::Precommit, Some(block_id));
+        }
+        self.valid = Some((self.round, block));
+        true
+    }
+
+    fn try_precommit_nil(&mut self) -> bool {
+        if self.step != Step::Prevote
+            || !self.has_quorum(self.round, VoteType::Prevote, None)
+        {
+            return false
+        }
+        self.vote(VoteType::Precommit, None);
+        true
+    }
+
+    fn try_schedule_precommit_timeout(&mut self) -> bool {
+        if self.precommit_timeout_scheduled
+            || self.count_all_votes(self.round, VoteType::Precommit)
+                < self.validators.quorum()
+        {
+            return false
+        }
+        self.precommit_timeout_scheduled = true;
+        self.schedule_timeout(Step::Precommit, self.timeouts.precommit);
+        true
+    }
+}
@@ -0,0 +1,314 @@
+use super::*;
+use crate::verifier::verify_consensus;
+use fuel_core_types::{
+    blockchain::header::BlockHeader,
+    tai64::Tai64,
+};
+use rand::{
+    rngs::StdRng,
+    SeedableRng,
+};
+
+const HEIGHT: u32 = 4;
+
+struct Validators {
+    keys: Vec<SecretKey>,
+    set: ValidatorSet,
+}
+
+impl Validators {
+    fn new(n: u8) -> Self {
+        let mut rng = StdRng::seed_from_u64(2322);
+        let keys: Vec<_> = (0..n).map(|_| SecretKey::random(&mut rng)).collect();
+        let set = ValidatorSet::new(
+            keys.iter()
+                .map(|key| Input::owner(&key.public_key()))
+                .collect(),
+        );
+        Self { keys, set }
+    }
+
+    fn addresses(&self) -> Vec<Address> {
+        self.keys
+            .iter()
+            .map(|key| Input::owner(&key.public_key()))
+            .collect()
+    }
+
+    fn engine(&self, index: usize, height: u32) -> Tendermint {
+        Tendermint::new(
+            height.into(),
+            ChainId::default(),
+            self.set.clone(),
+            self.keys[index],
+            Timeouts::default(),
+        )
+    }
+
+    fn proposal(&self, index: usize, round: u32, block: Block) -> Proposal {
+        Proposal::sign(&self.keys[index], &ChainId::default(), round, None, block)
+    }
+
+    fn vote(
+        &self,
+        index: usize,
+        round: u32,
+        vote_type: VoteType,
+        block_id: Option<BlockId>,
+    ) -> Vote {
+        Vote::sign(
+            &self.keys[index],
+            &ChainId::default(),
+            HEIGHT.into(),
+            round,
+            vote_type,
+            block_id,
+        )
+    }
+}
+
+fn block(height: u32, time: u64) -> Block {
+    let mut block = Block::default();
+    *block.header_mut() = BlockHeader::new_block(height.into(), Tai64(time));
+    block
+}
+
+fn broadcast_votes(outputs: &[Output]) -> Vec<&Vote> {
+    outputs
+        .iter()
+        .filter_map(|output| match output {
+            Output::Broadcast(ConsensusMessage::Vote(vote)) => Some(vote),
+            _ => None,
+        })
+        .collect()
+}
+
+fn decision(outputs: &[Output]) -> Option<&SealedBlock> {
+    outputs.iter().find_map(|output| match output {
+        Output::Decide(block) => Some(block),
+        _ => None,
+    })
+}
+
+fn assert_certificate(validators: &Validators, block: &SealedBlock) {
+    let consensus = match &block.consensus {
+        Consensus::Bft(consensus) => consensus,
+        _ => panic!("Expected the BFT consensus"),
+    };
+    assert!(verify_consensus(
+        &ChainId::default(),
+        &validators.addresses(),
+        block.entity.header(),
+        consensus
+    ));
+}
+
+#[test]
+fn single_validator_commits_produced_block() {
+    let validators = Validators::new(1);
+    let mut engine = validators.engine(0, 1);
+
+    engine.start();
+    let outputs = engine.take_outputs();
+    assert!(outputs.contains(&Output::ProduceBlock { round: 0 }));
+
+    let block = block(1, 1);
+    engine.on_block_produced(0, block.clone());
+    let outputs = engine.take_outputs();
+
+    let decided = decision(&outputs).expect("The block should be committed");
+    assert_eq!(decided.entity, block);
+    assert_certificate(&validators, decided);
+    assert!(engine.is_decided());
+}
+
+#[test]
+fn validators_commit_block_of_proposer() {
+    let validators = Validators::new(4);
+    let mut engine = validators.engine(1, HEIGHT);
+    engine.start();
+    engine.take_outputs();
+
+    let block = block(HEIGHT, 1);
+    let block_id = block.id();
+    assert!(engine.on_proposal(validators.proposal(0, 0, block.clone()), true));
+    let outputs = engine.take_outputs();
+    let votes = broadcast_votes(&outputs);
+    assert_eq!(votes.len(), 1);
+    assert_eq!(votes[0].vote_type, VoteType::Prevote);
+    assert_eq!(votes[0].block_id, Some(block_id));
+
+    for index in [0, 2] {
+        assert!(engine.on_vote(validators.vote(
+            index,
+            0,
+            VoteType::Prevote,
+            Some(block_id)
+        )));
+    }
+    let outputs = engine.take_outputs();
+    let votes = broadcast_votes(&outputs);
+    assert_eq!(votes.len(), 1);
+    assert_eq!(votes[0].vote_type, VoteType::Precommit);
+    assert_eq!(votes[0].block_id, Some(block_id));
+
+    for index in [0, 2] {
+        engine.on_vote(validators.vote(index, 0, VoteType::Precommit, Some(block_id)));
+    }
+    let outputs = engine.take_outputs();
+    let decided = decision(&outputs).expect("The block should be committed");
+    assert_eq!(decided.entity, block);
+    assert_certificate(&validators, decided);
+}
+
+#[test]
+fn rejects_proposal_from_wrong_proposer() {
+    let validators = Validators::new(4);
+    let mut engine = validators.engine(1, HEIGHT);
+    engine.start();
+
+    assert!(!engine.on_proposal(validators.proposal(2, 0, block(HEIGHT, 1)), true));
+}
+
+#[test]
+fn ignores_duplicated_votes() {
+    let validators = Validators::new(4);
+    let mut engine = validators.engine(1, HEIGHT);
+    engine.start();
+
+    let vote = validators.vote(0, 0, VoteType::Prevote, None);
+    assert!(engine.on_vote(vote.clone()));
+    assert!(!engine.on_vote(vote));
+}
+
+#[test]
+fn prevotes_nil_for_invalid_proposal() {
+    let validators = Validators::new(4);
+    let mut engine = validators.engine(1, HEIGHT);
+    engine.start();
+    engine.take_outputs();
+
+    engine.on_proposal(validators.proposal(0, 0, block(HEIGHT, 1)), false);
+    let outputs = engine.take_outputs();
+    let votes = broadcast_votes(&outputs);
+
+    assert_eq!(votes.len(), 1);
+    assert_eq!(votes[0].vote_type, VoteType::Prevote);
+    assert_eq!(votes[0].block_id, None);
+}
+
+#[test]
+fn prevotes_nil_on_propose_timeout() {
+    let validators = Validators::new(4);
+    let mut engine = validators.engine(1, HEIGHT);
+    engine.start();
+    engine.take_outputs();
+
+    engine.on_timeout(Timeout {
+        round: 0,
+        step: Step::Propose,
+    });
+    let outputs = engine.take_outputs();
+    let votes = broadcast_votes(&outputs);
+
+    assert_eq!(votes.len(), 1);
+    assert_eq!(votes[0].vote_type, VoteType::Prevote);
+    assert_eq!(votes[0].block_id, None);
+    assert_eq!(engine.step(), Step::Prevote);
+}
+
+#[test]
+fn precommit_timeout_starts_next_round() {
+    let validators = Validators::new(4);
+    let mut engine = validators.engine(1, HEIGHT);
+    engine.start();
+    engine.take_outputs();
+
+    engine.on_timeout(Timeout {
+        round: 0,
+        step: Step::Precommit,
+    });
+    let outputs = engine.take_outputs();
+
+    assert_eq!(engine.round(), 1);
+    // The validator `1` is the proposer of the round `1`.
+    assert!(outputs.contains(&Output::ProduceBlock { round: 1 }));
+}
+
+#[test]
+fn skips_to_round_with_weak_quorum_of_validators() {
+    let validators = Validators::new(4);
+    let mut engine = validators.engine(1, HEIGHT);
+    engine.start();
+
+    engine.on_vote(validators.vote(0, 5, VoteType::Prevote, None));
+    assert_eq!(engine.round(), 0);
+
+    engine.on_vote(validators.vote(2, 5, VoteType::Prevote, None));
+    assert_eq!(engine.round(), 5);
+}
+
+#[test]
+fn locked_validator_prevotes_nil_for_another_block() {
+    let validators = Validators::new(4);
+    let mut engine = validators.engine(2, HEIGHT);
+    engine.start();
+
+    let locked_block = block(HEIGHT, 1);
+    let locked_id = locked_block.id();
+    engine.on_proposal(validators.proposal(0, 0, locked_block), true);
+    for index in [0, 1] {
+        engine.on_vote(validators.vote(index, 0, VoteType::Prevote, Some(locked_id)));
+    }
+    engine.take_outputs();
+    assert_eq!(engine.step(), Step::Precommit);
+
+    engine.on_timeout(Timeout {
+        round: 0,
+        step: Step::Precommit,
+    });
+    engine.take_outputs();
+    engine.on_proposal(validators.proposal(1, 1, block(HEIGHT, 2)), true);
+    let outputs = engine.take_outputs();
+    let votes = broadcast_votes(&outputs);
+
+    assert_eq!(votes.len(), 1);
+    assert_eq!(votes[0].round, 1);
+    assert_eq!(votes[0].vote_type, VoteType::Prevote);
+    assert_eq!(votes[0].block_id, None);
+}
+
+#[test]
+fn ignores_votes_too_far_in_the_future() {
+    let validators = Validators::new(4);
+    let mut engine = validators.engine(1, HEIGHT);
+    engine.start();
+
+    let too_far_round = MAX_FUTURE_ROUNDS.checked_add(1).unwrap();
+    assert!(!engine.on_vote(validators.vote(0, too_far_round, VoteType::Prevote, None)));
+    assert!(engine.on_vote(validators.vote(
+        0,
+        MAX_FUTURE_ROUNDS,
+        VoteType::Prevote,
+        None
+    )));
+}
+
+#[test]
+fn drops_messages_of_previous_rounds() {
+    let validators = Validators::new(4);
+    let mut engine = validators.engine(1, HEIGHT);
+    engine.start();
+
+    assert!(engine.on_vote(validators.vote(0, 0, VoteType::Prevote, None)));
+    assert!(engine.on_vote(validators.vote(0, 1, VoteType::Prevote, None)));
+    engine.on_timeout(Timeout {
+        round: 0,
+        step: Step::Precommit,
+    });
+    assert_eq!(engine.round(), 1);
+
+    assert!(!engine.votes.contains_key(&(0, VoteType::Prevote)));
+    assert!(engine.votes.contains_key(&(1, VoteType::Prevote)));
+    assert!(!engine.on_vote(validators.vote(2, 0, VoteType::Prevote, None)));
+}
@@ -0,0 +1,89 @@
+use fuel_core_types::fuel_types::{
+    Address,
+    BlockHeight,
+};
+
+/// The ordered set of validators with equal voting power.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidatorSet {
+    validators: Vec<Address>,
+}
+
+impl ValidatorSet {
+    pub fn new(validators: Vec<Address>) -> Self {
+        Self { validators }
+    }
+
+    pub fn len(&self) -> usize {
+        self.validators.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.validators.is_empty()
+    }
+
+    pub fn contains(&self, address: &Address) -> bool {
+        self.validators.contains(address)
+    }
+
+    /// The number of votes required to commit the block: more than two thirds of validators.
+    pub fn quorum(&self) -> usize {
+        self.len()
+            .saturating_mul(2)
+            .checked_div(3)
+            .unwrap_or_default()
+            .saturating_add(1)
+    }
+
+    /// The number of votes that contains at least one honest validator.
+    pub fn weak_quorum(&self) -> usize {
+        self.len().saturating_sub(self.quorum()).saturating_add(1)
+    }
+
+    /// Returns the validator that proposes the block in the `round` of the `height`.
+    pub fn proposer(&self, height: BlockHeight, round: u32) -> Option<&Address> {
+        let turn = u64::from(*height).saturating_add(u64::from(round));
+        let validators = u64::try_from(self.len()).ok()?;
+        let index = turn.checked_rem(validators)?;
+        self.validators.get(usize::try_from(index).ok()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    fn validators(n: u8) -> ValidatorSet {
+        ValidatorSet::new((0..n).map(|i| Address::from([i; 32])).collect())
+    }
+
+    #[test_case(1 => (1, 1))]
+    #[test_case(3 => (3, 1))]
+    #[test_case(4 => (3, 2))]
+    #[test_case(7 => (5, 3))]
+    #[test_case(10 => (7, 4))]
+    fn quorums(n: u8) -> (usize, usize) {
+        let validators = validators(n);
+        (validators.quorum(), validators.weak_quorum())
+    }
+
+    #[test]
+    fn proposer_rotates_with_height_and_round() {
+        let validators = validators(3);
+
+        assert_eq!(
+            validators.proposer(1u32.into(), 0),
+            Some(&Address::from([1; 32]))
+        );
+        assert_eq!(
+            validators.proposer(2u32.into(), 0),
+            Some(&Address::from([2; 32]))
+        );
+        assert_eq!(
+            validators.proposer(2u32.into(), 1),
+            Some(&Address::from([0; 32]))
+        );
+        assert_eq!(validators(0).proposer(1u32.into(), 0), None);
+    }
+}
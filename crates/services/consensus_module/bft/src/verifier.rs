@@ -0,0 +1,217 @@
+use crate::validators::ValidatorSet;
+use fuel_core_types::{
+    blockchain::{
+        consensus::bft::{
+            BftConsensus,
+            Vote,
+            VoteType,
+        },
+        header::BlockHeader,
+    },
+    fuel_tx::Input,
+    fuel_types::{
+        Address,
+        ChainId,
+    },
+};
+use std::collections::HashSet;
+
+/// Verifies that the quorum certificate of the block contains precommits
+/// from the quorum of distinct `validators` signed for the chain with the `chain_id`.
+pub fn verify_consensus(
+    chain_id: &ChainId,
+    validators: &[Address],
+    header: &BlockHeader,
+    consensus: &BftConsensus,
+) -> bool {
+    let validators = ValidatorSet::new(validators.to_vec());
+    if validators.is_empty() {
+        return false
+    }
+
+    let block_id = header.id();
+    let message = Vote::signing_message(
+        chain_id,
+        *header.height(),
+        consensus.round,
+        VoteType::Precommit,
+        Some(&block_id),
+    );
+
+    let mut signers = HashSet::new();
+    for signature in &consensus.certificate {
+        match signature.recover(&message) {
+            Ok(public_key) => {
+                let signer = Input::owner(&public_key);
+                if !validators.contains(&signer) || !signers.insert(signer) {
+                    return false
+                }
+            }
+            Err(_) => return false,
+        }
+    }
+
+    signers.len() >= validators.quorum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_types::{
+        fuel_crypto::{
+            SecretKey,
+            Signature,
+        },
+        tai64::Tai64,
+    };
+    use rand::{
+        rngs::StdRng,
+        SeedableRng,
+    };
+
+    struct Validator {
+        secret: SecretKey,
+        address: Address,
+    }
+
+    fn validators(n: u8) -> Vec<Validator> {
+        let mut rng = StdRng::seed_from_u64(2322);
+        (0..n)
+            .map(|_| {
+                let secret = SecretKey::random(&mut rng);
+                let address = Input::owner(&secret.public_key());
+                Validator { secret, address }
+            })
+            .collect()
+    }
+
+    fn precommit(validator: &Validator, header: &BlockHeader, round: u32) -> Signature {
+        precommit_for_chain(validator, &ChainId::default(), header, round)
+    }
+
+    fn precommit_for_chain(
+        validator: &Validator,
+        chain_id: &ChainId,
+        header: &BlockHeader,
+        round: u32,
+    ) -> Signature {
+        Vote::sign(
+            &validator.secret,
+            chain_id,
+            *header.height(),
+            round,
+            VoteType::Precommit,
+            Some(header.id()),
+        )
+        .signature
+    }
+
+    fn header() -> BlockHeader {
+        BlockHeader::new_block(5u32.into(), Tai64::UNIX_EPOCH)
+    }
+
+    #[test]
+    fn accepts_quorum_of_precommits() {
+        let validators = validators(4);
+        let addresses: Vec<_> = validators.iter().map(|v| v.address).collect();
+        let header = header();
+        let certificate = validators[..3]
+            .iter()
+            .map(|v| precommit(v, &header, 2))
+            .collect();
+
+        assert!(verify_consensus(
+            &ChainId::default(),
+            &addresses,
+            &header,
+            &BftConsensus::new(2, certificate)
+        ));
+    }
+
+    #[test]
+    fn rejects_certificate_without_quorum() {
+        let validators = validators(4);
+        let addresses: Vec<_> = validators.iter().map(|v| v.address).collect();
+        let header = header();
+        let certificate = validators[..2]
+            .iter()
+            .map(|v| precommit(v, &header, 0))
+            .collect();
+
+        assert!(!verify_consensus(
+            &ChainId::default(),
+            &addresses,
+            &header,
+            &BftConsensus::new(0, certificate)
+        ));
+    }
+
+    #[test]
+    fn rejects_duplicated_signatures() {
+        let validators = validators(4);
+        let addresses: Vec<_> = validators.iter().map(|v| v.address).collect();
+        let header = header();
+        let signature = precommit(&validators[0], &header, 0);
+
+        assert!(!verify_consensus(
+            &ChainId::default(),
+            &addresses,
+            &header,
+            &BftConsensus::new(0, vec![signature; 3])
+        ));
+    }
+
+    #[test]
+    fn rejects_signatures_for_another_round() {
+        let validators = validators(4);
+        let addresses: Vec<_> = validators.iter().map(|v| v.address).collect();
+        let header = header();
+        let certificate = validators[..3]
+            .iter()
+            .map(|v| precommit(v, &header, 1))
+            .collect();
+
+        assert!(!verify_consensus(
+            &ChainId::default(),
+            &addresses,
+            &header,
+            &BftConsensus::new(0, certificate)
+        ));
+    }
+
+    #[test]
+    fn rejects_signatures_of_non_validators() {
+        let validators = validators(5);
+        let addresses: Vec<_> = validators[..4].iter().map(|v| v.address).collect();
+        let header = header();
+        let certificate = validators[2..]
+            .iter()
+            .map(|v| precommit(v, &header, 0))
+            .collect();
+
+        assert!(!verify_consensus(
+            &ChainId::default(),
+            &addresses,
+            &header,
+            &BftConsensus::new(0, certificate)
+        ));
+    }
+
+    #[test]
+    fn rejects_signatures_for_another_chain() {
+        let validators = validators(4);
+        let addresses: Vec<_> = validators.iter().map(|v| v.address).collect();
+        let header = header();
+        let certificate = validators[..3]
+            .iter()
+            .map(|v| precommit_for_chain(v, &ChainId::new(1), &header, 0))
+            .collect();
+
+        assert!(!verify_consensus(
+            &ChainId::default(),
+            &addresses,
+            &header,
+            &BftConsensus::new(0, certificate)
+        ));
+    }
+}
@@ -1,3 +1,4 @@
+use fuel_core_chain_config::ProducerSchedule;
 use fuel_core_types::{
     blockchain::primitives::SecretKeyWrapper,
     fuel_asm::Word,
@@ -15,6 +16,9 @@ pub struct Config {
     pub consensus_params: ConsensusParameters,
     pub min_connected_reserved_peers: usize,
     pub time_until_synced: Duration,
+    /// The schedule of the authorized producers. If it is set, the node produces
+    /// blocks only in the slots of the `signing_key`.
+    pub producer_schedule: Option<ProducerSchedule>,
 }
 
 impl Default for Config {
@@ -27,6 +31,7 @@ impl Default for Config {
             consensus_params: ConsensusParameters::default(),
             min_connected_reserved_peers: 0,
             time_until_synced: Duration::ZERO,
+            producer_schedule: None,
         }
     }
 }
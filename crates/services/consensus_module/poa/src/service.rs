@@ -21,8 +21,12 @@ use anyhow::{
     anyhow,
     Context,
 };
+use fuel_core_chain_config::ProducerSchedule;
 use fuel_core_services::{
-    stream::BoxStream,
+    stream::{
+        pending,
+        BoxStream,
+    },
     RunnableService,
     RunnableTask,
     Service as _,
@@ -44,16 +48,23 @@ use fuel_core_types::{
     fuel_asm::Word,
     fuel_crypto::Signature,
     fuel_tx::{
+        Input,
         Transaction,
         TxId,
     },
-    fuel_types::BlockHeight,
+    fuel_types::{
+        Address,
+        BlockHeight,
+    },
     secrecy::{
         ExposeSecret,
         Secret,
     },
     services::{
-        block_importer::ImportResult,
+        block_importer::{
+            BlockImportInfo,
+            ImportResult,
+        },
         executor::{
             ExecutionResult,
             UncommittedResult as UncommittedExecutionResult,
@@ -132,6 +143,11 @@ pub(crate) enum RequestType {
 pub struct MainTask<T, B, I> {
     block_gas_limit: Word,
     signing_key: Option<Secret<SecretKeyWrapper>>,
+    producer_schedule: Option<ProducerSchedule>,
+    /// The address of the `signing_key`.
+    producer_address: Option<Address>,
+    /// Blocks imported from other producers of the `producer_schedule`.
+    imported_blocks: BoxStream<BlockImportInfo>,
     block_producer: B,
     block_importer: I,
     txpool: T,
@@ -174,9 +190,19 @@ where
             min_connected_reserved_peers,
             time_until_synced,
             trigger,
+            producer_schedule,
             ..
         } = config;
 
+        let producer_address = signing_key
+            .as_ref()
+            .map(|key| Input::owner(&key.expose_secret().public_key()));
+        let imported_blocks = if producer_schedule.is_some() {
+            block_importer.block_stream()
+        } else {
+            Box::pin(pending())
+        };
+
         let sync_task = SyncTask::new(
             peer_connections_stream,
             min_connected_reserved_peers,
@@ -190,6 +216,9 @@ where
         Self {
             block_gas_limit,
             signing_key,
+            producer_schedule,
+            producer_address,
+            imported_blocks,
             txpool,
             block_producer,
             block_importer,
@@ -222,6 +251,34 @@ where
             .expect("It should be impossible to produce more blocks than u32::MAX")
     }
 
+    /// Returns `true` if the block with the `height` and `time` is in the slot of
+    /// this producer. Without the producer schedule all slots belong to the producer.
+    /// With the time slots, the time of the block should be after the last block.
+    fn is_own_slot(&self, height: BlockHeight, time: Tai64) -> bool {
+        match &self.producer_schedule {
+            None => true,
+            Some(schedule) => {
+                if schedule.is_time_slotted() && time <= self.last_timestamp {
+                    return false
+                }
+                let scheduled = schedule.scheduled_producer(height, time);
+                scheduled.is_some() && scheduled == self.producer_address.as_ref()
+            }
+        }
+    }
+
+    /// Returns the duration until the next slot of this producer,
+    /// if the slots are assigned by the block time.
+    pub(crate) fn until_next_own_slot(&self, time: Tai64) -> Option<Duration> {
+        let schedule = self.producer_schedule.as_ref()?;
+        let producer = self.producer_address.as_ref()?;
+        let after_last_block = Tai64(self.last_timestamp.0.saturating_add(1));
+        let slot_time = schedule.next_slot_time(producer, time.max(after_last_block))?;
+        Some(Duration::from_secs(
+            slot_time.0.saturating_sub(Tai64::now().0),
+        ))
+    }
+
     fn next_time(&self, request_type: RequestType) -> anyhow::Result<Tai64> {
         match request_type {
             RequestType::Manual => match self.trigger {
@@ -264,9 +321,32 @@ where
     }
 
     pub(crate) async fn produce_next_block(&mut self) -> anyhow::Result<()> {
+        let height = self.next_height();
+        let block_time = self.next_time(RequestType::Trigger)?;
+
+        if !self.is_own_slot(height, block_time) {
+            tracing::debug!("Skipping the block {height}, it is not in our slot");
+            match self.trigger {
+                // Try again later, the slot can be ours after the next interval.
+                Trigger::Interval { block_time } => {
+                    self.timer
+                        .set_timeout(block_time, OnConflict::Overwrite)
+                        .await;
+                }
+                // Wake up at the start of our next time slot for the pending transactions.
+                Trigger::Instant => {
+                    if let Some(timeout) = self.until_next_own_slot(block_time) {
+                        self.timer.set_timeout(timeout, OnConflict::Min).await;
+                    }
+                }
+                Trigger::Never => {}
+            }
+            return Ok(())
+        }
+
         self.produce_block(
-            self.next_height(),
-            self.next_time(RequestType::Trigger)?,
+            height,
+            block_time,
             TransactionsSource::TxPool,
             RequestType::Trigger,
         )
@@ -308,7 +388,7 @@ where
         Ok(())
     }
 
-    async fn produce_block(
+    pub(crate) async fn produce_block(
         &mut self,
         height: BlockHeight,
         block_time: Tai64,
@@ -325,6 +405,13 @@ where
             return Err(anyhow!("The block timestamp should monotonically increase"))
         }
 
+        if !self.is_own_slot(height, block_time) {
+            return Err(anyhow!(
+                "The block {height} at {} is not in the slot of this producer",
+                block_time.0
+            ))
+        }
+
         // Ask the block producer to create the block
         let (
             ExecutionResult {
@@ -404,11 +491,53 @@ where
         }
     }
 
+    /// Processes the block produced by another producer from the schedule.
+    async fn on_imported_block(
+        &mut self,
+        block_info: BlockImportInfo,
+    ) -> anyhow::Result<()> {
+        let header = &block_info.block_header;
+        if *header.height() <= self.last_height {
+            return Ok(())
+        }
+
+        let (last_height, last_timestamp, last_block_created) =
+            Self::extract_block_info(header);
+        self.last_height = last_height;
+        self.last_timestamp = last_timestamp;
+        self.last_block_created = last_block_created;
+
+        match self.trigger {
+            Trigger::Instant => {
+                // The next slot can be ours, produce the block for pending transactions.
+                if self.txpool.pending_number() > 0 {
+                    self.produce_next_block().await?;
+                }
+            }
+            Trigger::Interval { block_time } => {
+                let deadline = last_block_created.checked_add(block_time).expect("It is impossible to overflow except in the case where we don't want to produce a block.");
+                self.timer
+                    .set_deadline(deadline, OnConflict::Overwrite)
+                    .await;
+            }
+            Trigger::Never => {}
+        }
+
+        Ok(())
+    }
+
     async fn on_timer(&mut self, _at: Instant) -> anyhow::Result<()> {
         match self.trigger {
-            Trigger::Instant | Trigger::Never => {
+            Trigger::Never => {
                 unreachable!("Timer is never set in this mode");
             }
+            // In the Instant mode the timer expires only at the start of our time slot.
+            Trigger::Instant => {
+                if self.txpool.pending_number() > 0 {
+                    self.produce_next_block().await?;
+                }
+                Ok(())
+            }
             // In the Interval mode the timer expires only when a new block should be created.
             Trigger::Interval { .. } => {
                 self.produce_next_block().await?;
@@ -514,6 +643,10 @@ where
                     should_continue = false;
                 }
             }
+            Some(block_info) = self.imported_blocks.next() => {
+                self.on_imported_block(block_info).await.context("While processing imported block")?;
+                should_continue = true;
+            }
             at = self.timer.wait() => {
                 self.on_timer(at).await.context("While processing timer event")?;
                 should_continue = true;
@@ -7,12 +7,20 @@ use crate::{
         MockBlockProducer,
         MockP2pPort,
         MockTransactionPool,
+        TransactionsSource,
+    },
+    service::{
+        MainTask,
+        RequestType,
     },
-    service::MainTask,
     Config,
     Service,
     Trigger,
 };
+use fuel_core_chain_config::{
+    ProducerSchedule,
+    SlotAssignment,
+};
 use fuel_core_services::{
     stream::pending,
     Service as StorageTrait,
@@ -52,6 +60,10 @@ use rand::{
 };
 use std::{
     collections::HashSet,
+    num::{
+        NonZeroU32,
+        NonZeroU64,
+    },
     sync::{
         Arc,
         Mutex as StdMutex,
@@ -395,6 +407,131 @@ async fn does_not_produce_when_txpool_empty_in_instant_mode() {
     task.on_txpool_event().await.unwrap();
 }
 
+#[tokio::test]
+async fn does_not_produce_outside_of_own_slot() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let secret_key = SecretKey::random(&mut rng);
+    let other_producer = SecretKey::random(&mut rng);
+
+    let mut block_producer = MockBlockProducer::default();
+    block_producer
+        .expect_produce_and_execute_block()
+        .returning(|_, _, _, _| panic!("Block production should not be called"));
+
+    let mut block_importer = MockBlockImporter::default();
+    block_importer
+        .expect_commit_result()
+        .returning(|_| panic!("Block importer should not be called"));
+    block_importer
+        .expect_block_stream()
+        .returning(|| Box::pin(tokio_stream::pending()));
+
+    let mut txpool = MockTransactionPool::no_tx_updates();
+    txpool.expect_pending_number().returning(|| 1);
+
+    // The next block at height `2` belongs to the `other_producer`.
+    let producer_schedule = ProducerSchedule {
+        producers: vec![
+            Input::owner(&secret_key.public_key()),
+            Input::owner(&other_producer.public_key()),
+        ],
+        slot: SlotAssignment::Height {
+            blocks_per_slot: NonZeroU32::new(1).unwrap(),
+        },
+    };
+    let config = Config {
+        trigger: Trigger::Instant,
+        block_gas_limit: 1000000,
+        signing_key: Some(Secret::new(secret_key.into())),
+        producer_schedule: Some(producer_schedule),
+        ..Default::default()
+    };
+
+    let p2p_port = generate_p2p_port();
+
+    let mut task = MainTask::new(
+        &BlockHeader::new_block(BlockHeight::from(1u32), Tai64::now()),
+        config,
+        txpool,
+        block_producer,
+        block_importer,
+        p2p_port,
+    );
+
+    task.on_txpool_event().await.unwrap();
+    let manual_production = task
+        .produce_block(
+            2u32.into(),
+            Tai64::now(),
+            TransactionsSource::TxPool,
+            RequestType::Manual,
+        )
+        .await;
+    assert!(manual_production.is_err());
+}
+
+#[tokio::test]
+async fn instant_producer_waits_for_own_time_slot_after_last_block() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let secret_key = SecretKey::random(&mut rng);
+    let other_producer = SecretKey::random(&mut rng);
+
+    let mut block_producer = MockBlockProducer::default();
+    block_producer
+        .expect_produce_and_execute_block()
+        .returning(|_, _, _, _| panic!("Block production should not be called"));
+    let mut block_importer = MockBlockImporter::default();
+    block_importer
+        .expect_block_stream()
+        .returning(|| Box::pin(tokio_stream::pending()));
+    let mut txpool = MockTransactionPool::no_tx_updates();
+    txpool.expect_pending_number().returning(|| 1);
+
+    let producer_schedule = ProducerSchedule {
+        producers: vec![
+            Input::owner(&secret_key.public_key()),
+            Input::owner(&other_producer.public_key()),
+        ],
+        slot: SlotAssignment::Time {
+            slot_seconds: NonZeroU64::new(10).unwrap(),
+        },
+    };
+    let config = Config {
+        trigger: Trigger::Instant,
+        block_gas_limit: 1000000,
+        signing_key: Some(Secret::new(secret_key.into())),
+        producer_schedule: Some(producer_schedule),
+        ..Default::default()
+    };
+
+    // The last block is in the future, so its time slot is taken.
+    let last_block_time = Tai64(Tai64::now().0 + 5);
+    let mut task = MainTask::new(
+        &BlockHeader::new_block(BlockHeight::from(1u32), last_block_time),
+        config,
+        txpool,
+        block_producer,
+        block_importer,
+        generate_p2p_port(),
+    );
+
+    task.on_txpool_event().await.unwrap();
+    let manual_production = task
+        .produce_block(
+            2u32.into(),
+            last_block_time,
+            TransactionsSource::TxPool,
+            RequestType::Manual,
+        )
+        .await;
+    assert!(manual_production.is_err());
+
+    // The next own slot starts after the last block, at most one slot later.
+    let until_next_slot = task.until_next_own_slot(last_block_time).unwrap();
+    assert!(until_next_slot >= Duration::from_secs(5));
+    assert!(until_next_slot <= Duration::from_secs(16));
+}
+
 fn test_signing_key() -> Secret<SecretKeyWrapper> {
     let mut rng = StdRng::seed_from_u64(0);
     let secret_key = SecretKey::random(&mut rng);
@@ -8,27 +8,68 @@ use fuel_core_types::{
         header::BlockHeader,
     },
     fuel_tx::Input,
+    tai64::Tai64,
 };
 
 #[cfg(test)]
 mod tests;
 
+/// The maximum number of seconds the time of the block can be ahead of the local clock.
+const MAX_BLOCK_TIME_DRIFT_SECONDS: u64 = 2;
+
 // TODO: Make this function `async` and await the synchronization with the relayer.
+/// Verifies that the block is signed by the producer scheduled for its slot.
 pub fn verify_consensus(
     consensus_config: &ConsensusConfig,
     header: &BlockHeader,
     consensus: &PoAConsensus,
 ) -> bool {
-    match consensus_config {
-        ConsensusConfig::PoA { signing_key } => {
+    match consensus_config.scheduled_producer(*header.height(), header.time()) {
+        Some(producer) => {
             let id = header.id();
             let m = id.as_message();
             consensus
                 .signature
                 .recover(m)
-                .map_or(false, |k| Input::owner(&k) == *signing_key)
+                .map_or(false, |k| Input::owner(&k) == *producer)
         }
+        None => false,
+    }
+}
+
+/// Verifies the time of the block when the slots of the producers are assigned
+/// by the block time. The producer chooses the time of its block, so the time
+/// has to be after the time of the previous block, to keep the slots in order,
+/// and not far ahead of the local clock, so the producer can't take future slots.
+pub fn verify_block_time<D: Database>(
+    consensus_config: &ConsensusConfig,
+    database: &D,
+    header: &BlockHeader,
+) -> anyhow::Result<()> {
+    let is_time_slotted = match consensus_config {
+        ConsensusConfig::PoARoundRobin(schedule) => schedule.is_time_slotted(),
+        ConsensusConfig::PoA { .. } | ConsensusConfig::Bft { .. } => false,
+    };
+    if !is_time_slotted {
+        return Ok(())
     }
+
+    let prev_height = header
+        .height()
+        .pred()
+        .ok_or_else(|| anyhow::anyhow!("The PoA block can't have the zero height"))?;
+    let prev_header = database.block_header(&prev_height)?;
+    ensure!(
+        header.time() > prev_header.time(),
+        "The `time` of the next block should be greater with the time slots"
+    );
+
+    let max_time = Tai64::now().0.saturating_add(MAX_BLOCK_TIME_DRIFT_SECONDS);
+    ensure!(
+        header.time().0 <= max_time,
+        "The `time` of the block is too far ahead of the local clock"
+    );
+    Ok(())
 }
 
 pub fn verify_block_fields<D: Database>(
@@ -1,5 +1,11 @@
+#![allow(clippy::arithmetic_side_effects)]
+
 use super::*;
 use crate::ports::MockDatabase;
+use fuel_core_chain_config::{
+    ProducerSchedule,
+    SlotAssignment,
+};
 use fuel_core_types::{
     blockchain::header::{
         ApplicationHeader,
@@ -7,9 +13,21 @@ use fuel_core_types::{
         GeneratedApplicationFields,
         GeneratedConsensusFields,
     },
+    fuel_crypto::{
+        SecretKey,
+        Signature,
+    },
     fuel_types::Bytes32,
     tai64::Tai64,
 };
+use rand::{
+    rngs::StdRng,
+    SeedableRng,
+};
+use std::num::{
+    NonZeroU32,
+    NonZeroU64,
+};
 use test_case::test_case;
 
 struct Input {
@@ -106,3 +124,84 @@ fn test_verify_genesis_block_fields(input: Input) -> anyhow::Result<()> {
     b.header_mut().application = ah;
     verify_block_fields(&d, &b)
 }
+
+fn sign(secret: &SecretKey, height: u32) -> (BlockHeader, PoAConsensus) {
+    let header = BlockHeader::new_block(height.into(), Tai64::now());
+    let signature = Signature::sign(secret, &header.id().into_message());
+    (header, PoAConsensus::new(signature))
+}
+
+#[test]
+fn verify_consensus_accepts_only_scheduled_producer() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let first = SecretKey::random(&mut rng);
+    let second = SecretKey::random(&mut rng);
+    let config = ConsensusConfig::PoARoundRobin(ProducerSchedule {
+        producers: vec![
+            fuel_core_types::fuel_tx::Input::owner(&first.public_key()),
+            fuel_core_types::fuel_tx::Input::owner(&second.public_key()),
+        ],
+        slot: SlotAssignment::Height {
+            blocks_per_slot: NonZeroU32::new(1).unwrap(),
+        },
+    });
+
+    let (header, consensus) = sign(&first, 2);
+    assert!(verify_consensus(&config, &header, &consensus));
+    let (header, consensus) = sign(&second, 3);
+    assert!(verify_consensus(&config, &header, &consensus));
+
+    let (header, consensus) = sign(&second, 2);
+    assert!(!verify_consensus(&config, &header, &consensus));
+    let (header, consensus) = sign(&first, 3);
+    assert!(!verify_consensus(&config, &header, &consensus));
+}
+
+fn time_slotted_config() -> ConsensusConfig {
+    ConsensusConfig::PoARoundRobin(ProducerSchedule {
+        producers: vec![[1; 32].into(), [2; 32].into()],
+        slot: SlotAssignment::Time {
+            slot_seconds: NonZeroU64::new(10).unwrap(),
+        },
+    })
+}
+
+fn verify_time(config: &ConsensusConfig, prev_time: Tai64, time: Tai64) -> bool {
+    let mut d = MockDatabase::default();
+    d.expect_block_header().returning(move |_| {
+        let mut h = BlockHeader::default();
+        h.consensus.time = prev_time;
+        Ok(h)
+    });
+    let header = BlockHeader::new_block(2u32.into(), time);
+    verify_block_time(config, &d, &header).is_ok()
+}
+
+#[test]
+fn verify_block_time_requires_time_after_previous_block_with_time_slots() {
+    let config = time_slotted_config();
+    let now = Tai64::now();
+    let before = Tai64(now.0 - 1);
+
+    assert!(verify_time(&config, before, now));
+    assert!(!verify_time(&config, now, now));
+    assert!(!verify_time(&config, now, before));
+}
+
+#[test]
+fn verify_block_time_rejects_time_far_ahead_of_local_clock() {
+    let config = time_slotted_config();
+    let now = Tai64::now();
+    let future = Tai64(now.0 + 60);
+
+    assert!(!verify_time(&config, now, future));
+}
+
+#[test]
+fn verify_block_time_is_not_checked_without_time_slots() {
+    let config = ConsensusConfig::default_poa();
+    let now = Tai64::now();
+
+    assert!(verify_time(&config, now, now));
+    assert!(verify_time(&config, now, Tai64(now.0 + 60)));
+}
@@ -8,6 +8,7 @@ mod tests;
 
 use crate::block_verifier::config::Config;
 use anyhow::ensure;
+use fuel_core_chain_config::ConsensusConfig;
 use fuel_core_poa::ports::{
     Database as PoAVerifierDatabase,
     RelayerPort,
@@ -21,6 +22,7 @@ use fuel_core_types::{
         SealedBlockHeader,
     },
     fuel_types::{
+        Address,
         BlockHeight,
         Bytes32,
     },
@@ -70,6 +72,23 @@ where
                 verify_genesis_block_fields(expected_genesis_height, block.header())
             }
             Consensus::PoA(_) => {
+                fuel_core_poa::verifier::verify_block_fields(&self.database, block)?;
+                fuel_core_poa::verifier::verify_block_time(
+                    &self.config.chain_config.consensus,
+                    &self.database,
+                    block.header(),
+                )
+            }
+            Consensus::Bft(consensus) => {
+                ensure!(
+                    fuel_core_bft::verifier::verify_consensus(
+                        &self.config.chain_config.consensus_parameters.chain_id,
+                        self.bft_validators(),
+                        block.header(),
+                        consensus,
+                    ),
+                    "The block doesn't have the quorum certificate of validators"
+                );
                 fuel_core_poa::verifier::verify_block_fields(&self.database, block)
             }
         }
@@ -88,6 +107,20 @@ where
                 header,
                 consensus,
             ),
+            Consensus::Bft(consensus) => fuel_core_bft::verifier::verify_consensus(
+                &self.config.chain_config.consensus_parameters.chain_id,
+                self.bft_validators(),
+                header,
+                consensus,
+            ),
+        }
+    }
+
+    /// The validators of the BFT consensus. It is empty for other consensuses.
+    fn bft_validators(&self) -> &[Address] {
+        match &self.config.chain_config.consensus {
+            ConsensusConfig::Bft { validators } => validators,
+            ConsensusConfig::PoA { .. } | ConsensusConfig::PoARoundRobin(_) => &[],
         }
     }
 
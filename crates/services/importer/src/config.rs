@@ -6,6 +6,12 @@ pub struct Config {
     pub max_block_notify_buffer: usize,
     pub metrics: bool,
     pub chain_id: ChainId,
+    /// Records the previous values of the state modified by each block,
+    /// so the state at any recorded height can be restored.
+    pub state_history: bool,
+    /// The number of the latest blocks that keep transactions, receipts and statuses.
+    /// The data of older blocks is pruned. `None` keeps the data of all blocks.
+    pub pruning_depth: Option<u32>,
 }
 
 impl Config {
@@ -14,6 +20,8 @@ impl Config {
             max_block_notify_buffer: 1 << 10,
             metrics: false,
             chain_id: chain_config.consensus_parameters.chain_id,
+            state_history: false,
+            pruning_depth: None,
         }
     }
 }
@@ -25,6 +33,8 @@ impl Default for Config {
             max_block_notify_buffer: 1,
             metrics: false,
             chain_id: ChainId::default(),
+            state_history: false,
+            pruning_depth: None,
         }
     }
 }
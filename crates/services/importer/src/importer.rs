@@ -113,6 +113,8 @@ pub struct Importer<D, E, V> {
     executor: Arc<E>,
     verifier: Arc<V>,
     chain_id: ChainId,
+    state_history: bool,
+    pruning_depth: Option<u32>,
     broadcast: broadcast::Sender<SharedImportResult>,
     /// The channel to notify about the end of the processing of the previous block by all listeners.
     /// It is used to await until all receivers of the notification process the `SharedImportResult`
@@ -130,6 +132,8 @@ impl<D, E, V> Importer<D, E, V> {
             executor: Arc::new(executor),
             verifier: Arc::new(verifier),
             chain_id: config.chain_id,
+            state_history: config.state_history,
+            pruning_depth: config.pruning_depth,
             broadcast,
             prev_block_process_result: Default::default(),
             guard: tokio::sync::Semaphore::new(1),
@@ -246,7 +250,7 @@ where
                 }
                 actual_next_height
             }
-            Consensus::PoA(_) => {
+            Consensus::PoA(_) | Consensus::Bft(_) => {
                 if actual_next_height == BlockHeight::from(0u32) {
                     return Err(Error::ZeroNonGenericHeight)
                 }
@@ -286,6 +290,18 @@ where
             return Err(Error::NotUnique(expected_next_height))
         }
 
+        if self.state_history {
+            db_after_execution.record_state_history(&actual_next_height)?;
+        } else {
+            db_after_execution.clear_state_history()?;
+        }
+
+        if let Some(pruning_depth) = self.pruning_depth {
+            let pruned_below =
+                u32::from(actual_next_height).saturating_sub(pruning_depth);
+            db_after_execution.prune_blocks_below(pruned_below.into())?;
+        }
+
         // Update the total tx count in chain metadata
         let total_txs = db_after_execution
             // Safety: casting len to u64 since it's impossible to execute a block with more than 2^64 txs
@@ -61,6 +61,12 @@ mockall::mock! {
             chain_id: &ChainId,
             block: &SealedBlock,
         ) -> StorageResult<bool>;
+
+        fn record_state_history(&mut self, height: &BlockHeight) -> StorageResult<()>;
+
+        fn clear_state_history(&mut self) -> StorageResult<()>;
+
+        fn prune_blocks_below(&mut self, height: BlockHeight) -> StorageResult<()>;
     }
 
     impl TransactionTrait<MockDatabase> for Database {
@@ -139,6 +145,7 @@ where
             .returning(move || height().map(|v| v.map(Into::into)));
         db.expect_store_new_block()
             .returning(move |_, _| store_block());
+        db.expect_clear_state_history().returning(|| Ok(()));
         db.expect_commit().times(commits).returning(|| Ok(()));
         db.expect_increase_tx_count().returning(Ok);
         db
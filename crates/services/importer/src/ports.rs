@@ -53,6 +53,16 @@ pub trait ExecutorDatabase: ImporterDatabase {
         chain_id: &ChainId,
         block: &SealedBlock,
     ) -> StorageResult<bool>;
+
+    /// Records the previous values of the state modified by the block at the `height`.
+    fn record_state_history(&mut self, height: &BlockHeight) -> StorageResult<()>;
+
+    /// Removes the recorded state history, if any. Called for the blocks imported
+    /// without the state history, so the history never has a gap.
+    fn clear_state_history(&mut self) -> StorageResult<()>;
+
+    /// Removes the transactions of the blocks below the `height`.
+    fn prune_blocks_below(&mut self, height: BlockHeight) -> StorageResult<()>;
 }
 
 #[cfg_attr(test, mockall::automock)]
@@ -3,7 +3,10 @@ use crate::{
         postcard::PostcardCodec,
         NetworkCodec,
     },
-    config::Config,
+    config::{
+        fuel_upgrade::Checksum,
+        Config,
+    },
     discovery::{
         DiscoveryBehaviour,
         DiscoveryConfig,
@@ -41,6 +44,19 @@ use libp2p::{
     PeerId,
 };
 
+/// The version of the identify protocol reported by the nodes without QUIC support.
+pub(crate) const LEGACY_IDENTIFY_PROTOCOL_VERSION: &str = "/fuel/1.0";
+
+/// Returns the version of the identify protocol that includes the checksum of the network.
+/// QUIC connections bypass the `FuelAuthenticated` upgrade, so the peers with
+/// a different checksum are rejected after the identification.
+pub(crate) fn identify_protocol_version(checksum: &Checksum) -> String {
+    format!(
+        "{LEGACY_IDENTIFY_PROTOCOL_VERSION}/{}",
+        hex::encode(checksum.as_ref())
+    )
+}
+
 /// Handles all p2p protocols needed for Fuel.
 #[derive(NetworkBehaviour)]
 pub struct FuelBehaviour {
@@ -101,7 +117,7 @@ impl FuelBehaviour {
 
         let identify = {
             let identify_config = identify::Config::new(
-                "/fuel/1.0".to_string(),
+                identify_protocol_version(&p2p_config.checksum),
                 p2p_config.keypair.public(),
             );
             if let Some(interval) = p2p_config.identify_interval {
@@ -116,8 +132,10 @@ impl FuelBehaviour {
             BlockHeight::default(),
         );
 
-        let req_res_protocol =
-            core::iter::once((codec.get_req_res_protocol(), ProtocolSupport::Full));
+        let req_res_protocol = codec
+            .get_req_res_protocols()
+            .into_iter()
+            .map(|protocol| (protocol, ProtocolSupport::Full));
 
         let req_res_config = RequestResponseConfig::default();
         req_res_config
@@ -206,6 +224,10 @@ impl FuelBehaviour {
         self.heartbeat.update_block_height(block_height);
     }
 
+    pub fn update_lowest_block_height(&mut self, block_height: BlockHeight) {
+        self.heartbeat.update_lowest_block_height(block_height);
+    }
+
     #[cfg(test)]
     pub fn get_peer_score(&self, peer_id: &PeerId) -> Option<f64> {
         self.gossipsub.peer_score(peer_id)
@@ -214,4 +236,18 @@ impl FuelBehaviour {
     pub fn block_peer(&mut self, peer_id: PeerId) {
         self.blocked_peer.block_peer(peer_id)
     }
+
+    pub fn unblock_peer(&mut self, peer_id: PeerId) {
+        self.blocked_peer.unblock_peer(peer_id)
+    }
+
+    /// The reserved peer receives all gossiped messages regardless of the mesh.
+    pub fn add_reserved_peer(&mut self, peer_id: &PeerId, address: Multiaddr) {
+        self.discovery.add_address(peer_id, address);
+        self.gossipsub.add_explicit_peer(peer_id);
+    }
+
+    pub fn remove_reserved_peer(&mut self, peer_id: &PeerId) {
+        self.gossipsub.remove_explicit_peer(peer_id);
+    }
 }
@@ -39,7 +39,8 @@ pub trait NetworkCodec:
     + Send
     + 'static
 {
-    /// Returns RequestResponse's Protocol
+    /// Returns all supported versions of RequestResponse's Protocol,
+    /// ordered by the preference during the negotiation.
     /// Needed for initialization of RequestResponse Behaviour
-    fn get_req_res_protocol(&self) -> <Self as RequestResponseCodec>::Protocol;
+    fn get_req_res_protocols(&self) -> Vec<<Self as RequestResponseCodec>::Protocol>;
 }
@@ -10,8 +10,8 @@ use crate::{
     },
     request_response::messages::{
         RequestMessage,
+        RequestResponseProtocol,
         ResponseMessage,
-        REQUEST_RESPONSE_PROTOCOL_ID,
     },
 };
 use async_trait::async_trait;
@@ -39,6 +39,25 @@ fn serialize<D: Serialize>(data: &D) -> Result<Vec<u8>, io::Error> {
         .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))
 }
 
+/// Checks that the message requiring the `min_protocol` can be sent over the
+/// negotiated `protocol`. The older peers can't decode the messages added later.
+fn check_protocol(
+    min_protocol: RequestResponseProtocol,
+    protocol: &RequestResponseProtocol,
+    kind: io::ErrorKind,
+) -> Result<(), io::Error> {
+    if min_protocol > *protocol {
+        return Err(io::Error::new(
+            kind,
+            format!(
+                "The message requires the protocol {:?}, but {:?} was negotiated",
+                min_protocol, protocol
+            ),
+        ))
+    }
+    Ok(())
+}
+
 #[derive(Debug, Clone)]
 pub struct PostcardCodec {
     /// Used for `max_size` parameter when reading Response Message
@@ -67,15 +86,18 @@ impl PostcardCodec {
 /// early close as a protocol violation which results in the connection being closed.
 /// If the substream was not properly closed when dropped, the sender would instead
 /// run into a timeout waiting for the response.
+///
+/// The messages are checked against the negotiated version of the protocol,
+/// so the requests unknown to the remote peer are never sent to it.
 #[async_trait]
 impl RequestResponseCodec for PostcardCodec {
-    type Protocol = MessageExchangePostcardProtocol;
+    type Protocol = RequestResponseProtocol;
     type Request = RequestMessage;
     type Response = ResponseMessage;
 
     async fn read_request<T>(
         &mut self,
-        _: &Self::Protocol,
+        protocol: &Self::Protocol,
         socket: &mut T,
     ) -> io::Result<Self::Request>
     where
@@ -86,12 +108,14 @@ impl RequestResponseCodec for PostcardCodec {
             .take(self.max_response_size as u64)
             .read_to_end(&mut response)
             .await?;
-        deserialize(&response)
+        let request: RequestMessage = deserialize(&response)?;
+        check_protocol(request.min_protocol(), protocol, io::ErrorKind::InvalidData)?;
+        Ok(request)
     }
 
     async fn read_response<T>(
         &mut self,
-        _: &Self::Protocol,
+        protocol: &Self::Protocol,
         socket: &mut T,
     ) -> io::Result<Self::Response>
     where
@@ -103,18 +127,25 @@ impl RequestResponseCodec for PostcardCodec {
             .read_to_end(&mut response)
             .await?;
 
-        deserialize(&response)
+        let response: ResponseMessage = deserialize(&response)?;
+        check_protocol(
+            response.min_protocol(),
+            protocol,
+            io::ErrorKind::InvalidData,
+        )?;
+        Ok(response)
     }
 
     async fn write_request<T>(
         &mut self,
-        _protocol: &Self::Protocol,
+        protocol: &Self::Protocol,
         socket: &mut T,
         req: Self::Request,
     ) -> io::Result<()>
     where
         T: futures::AsyncWrite + Unpin + Send,
     {
+        check_protocol(req.min_protocol(), protocol, io::ErrorKind::InvalidInput)?;
         let encoded_data = serialize(&req)?;
         socket.write_all(&encoded_data).await?;
         Ok(())
@@ -122,13 +153,14 @@ impl RequestResponseCodec for PostcardCodec {
 
     async fn write_response<T>(
         &mut self,
-        _protocol: &Self::Protocol,
+        protocol: &Self::Protocol,
         socket: &mut T,
         res: Self::Response,
     ) -> io::Result<()>
     where
         T: futures::AsyncWrite + Unpin + Send,
     {
+        check_protocol(res.min_protocol(), protocol, io::ErrorKind::InvalidInput)?;
         let encoded_data = serialize(&res)?;
         socket.write_all(&encoded_data).await?;
         Ok(())
@@ -142,6 +174,10 @@ impl GossipsubCodec for PostcardCodec {
     fn encode(&self, data: Self::RequestMessage) -> Result<Vec<u8>, io::Error> {
         let encoded_data = match data {
             GossipsubBroadcastRequest::NewTx(tx) => postcard::to_stdvec(&*tx),
+            GossipsubBroadcastRequest::Consensus(message) => {
+                postcard::to_stdvec(&*message)
+            }
+            GossipsubBroadcastRequest::NewBlock(block) => postcard::to_stdvec(&*block),
         };
 
         encoded_data.map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))
@@ -154,6 +190,12 @@ impl GossipsubCodec for PostcardCodec {
     ) -> Result<Self::ResponseMessage, io::Error> {
         let decoded_response = match gossipsub_tag {
             GossipTopicTag::NewTx => GossipsubMessage::NewTx(deserialize(encoded_data)?),
+            GossipTopicTag::Consensus => {
+                GossipsubMessage::Consensus(deserialize(encoded_data)?)
+            }
+            GossipTopicTag::NewBlock => {
+                GossipsubMessage::NewBlock(deserialize(encoded_data)?)
+            }
         };
 
         Ok(decoded_response)
@@ -161,17 +203,8 @@ impl GossipsubCodec for PostcardCodec {
 }
 
 impl NetworkCodec for PostcardCodec {
-    fn get_req_res_protocol(&self) -> <Self as RequestResponseCodec>::Protocol {
-        MessageExchangePostcardProtocol {}
-    }
-}
-
-#[derive(Default, Debug, Clone)]
-pub struct MessageExchangePostcardProtocol;
-
-impl AsRef<str> for MessageExchangePostcardProtocol {
-    fn as_ref(&self) -> &str {
-        REQUEST_RESPONSE_PROTOCOL_ID
+    fn get_req_res_protocols(&self) -> Vec<<Self as RequestResponseCodec>::Protocol> {
+        RequestResponseProtocol::ALL.to_vec()
     }
 }
 
@@ -186,4 +219,69 @@ mod tests {
         let m = RequestMessage::Transactions(arbitrary_range);
         assert!(postcard::to_stdvec(&m).unwrap().len() <= MAX_REQUEST_SIZE);
     }
+
+    #[tokio::test]
+    async fn v1_request_is_decoded_by_v2_protocol() {
+        let mut codec = PostcardCodec::new(1024);
+        let request = RequestMessage::Block(5.into());
+
+        let mut encoded = Vec::new();
+        codec
+            .write_request(&RequestResponseProtocol::V1, &mut encoded, request.clone())
+            .await
+            .unwrap();
+        let decoded = codec
+            .read_request(&RequestResponseProtocol::V2, &mut encoded.as_slice())
+            .await
+            .unwrap();
+
+        assert_eq!(decoded, request);
+    }
+
+    #[tokio::test]
+    async fn v2_request_is_not_sent_over_v1_protocol() {
+        let mut codec = PostcardCodec::new(1024);
+        let request = RequestMessage::TransactionsByIds(vec![]);
+
+        let mut encoded = Vec::new();
+        let result = codec
+            .write_request(&RequestResponseProtocol::V1, &mut encoded, request)
+            .await;
+
+        assert!(result.is_err());
+        assert!(encoded.is_empty());
+    }
+
+    #[tokio::test]
+    async fn v2_request_is_rejected_over_v1_protocol() {
+        let mut codec = PostcardCodec::new(1024);
+        let request = RequestMessage::TransactionsByIds(vec![]);
+
+        let mut encoded = Vec::new();
+        codec
+            .write_request(&RequestResponseProtocol::V2, &mut encoded, request)
+            .await
+            .unwrap();
+        let result = codec
+            .read_request(&RequestResponseProtocol::V1, &mut encoded.as_slice())
+            .await;
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn protocols_are_ordered_from_the_newest() {
+        let protocols = PostcardCodec::new(1024).get_req_res_protocols();
+
+        assert_eq!(
+            protocols,
+            vec![RequestResponseProtocol::V2, RequestResponseProtocol::V1]
+        );
+        for protocol in protocols {
+            assert_eq!(
+                RequestResponseProtocol::from_protocol_id(protocol.as_ref()),
+                Some(protocol)
+            );
+        }
+    }
 }
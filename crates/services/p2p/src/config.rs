@@ -1,11 +1,15 @@
 use crate::{
     gossipsub::config::default_gossipsub_config,
     heartbeat::HeartbeatConfig,
-    peer_manager::ConnectionState,
+    peer_manager::{
+        ConnectionState,
+        ReputationConfig,
+    },
     TryPeerId,
 };
 use fuel_core_types::blockchain::consensus::Genesis;
 
+use futures::future;
 use libp2p::{
     core::{
         muxing::StreamMuxerBox,
@@ -17,6 +21,10 @@ use libp2p::{
         Keypair,
     },
     noise::Config as NoiseConfig,
+    quic::{
+        tokio::Transport as TokioQuicTransport,
+        Config as QuicConfig,
+    },
     tcp::{
         tokio::Transport as TokioTcpTransport,
         Config as TcpConfig,
@@ -42,7 +50,10 @@ use std::{
 
 use self::{
     connection_tracker::ConnectionTracker,
-    fuel_authenticated::FuelAuthenticated,
+    fuel_authenticated::{
+        Approver,
+        FuelAuthenticated,
+    },
     fuel_upgrade::Checksum,
     guarded_node::GuardedNode,
 };
@@ -62,6 +73,18 @@ pub const MAX_RESPONSE_SIZE: usize = 18 * 1024 * 1024;
 /// Maximum number of headers per request.
 pub const MAX_HEADERS_PER_REQUEST: u32 = 100;
 
+/// Maximum number of transactions per request by ids.
+pub const MAX_TXS_PER_REQUEST: u32 = 10_000;
+
+/// Maximum number of inbound requests served to a single peer per second.
+pub const MAX_REQUESTS_PER_SECOND: u32 = 50;
+
+/// Maximum number of bytes served to a single peer within the `RESPONSE_BYTES_WINDOW`.
+pub const MAX_RESPONSE_BYTES_PER_WINDOW: u64 = 10 * MAX_RESPONSE_SIZE as u64;
+
+/// The window of the `MAX_RESPONSE_BYTES_PER_WINDOW` quota.
+pub const RESPONSE_BYTES_WINDOW: Duration = Duration::from_secs(10);
+
 /// Adds a timeout to the setup and protocol upgrade process for all
 /// inbound and outbound connections established through the transport.
 const TRANSPORT_TIMEOUT: Duration = Duration::from_secs(20);
@@ -86,9 +109,14 @@ pub struct Config<State = Initialized> {
     /// The TCP port that Swarm listens on
     pub tcp_port: u16,
 
+    /// The UDP port that Swarm listens on for QUIC connections.
+    /// The QUIC transport is disabled if it is not set.
+    pub quic_port: Option<u16>,
+
     /// Max Size of a Block in bytes
     pub max_block_size: usize,
     pub max_headers_per_request: u32,
+    pub max_txs_per_request: u32,
 
     // `DiscoveryBehaviour` related fields
     pub bootstrap_nodes: Vec<Multiaddr>,
@@ -128,6 +156,14 @@ pub struct Config<State = Initialized> {
     pub set_request_timeout: Duration,
     /// Sets the keep-alive timeout of idle connections.
     pub set_connection_keep_alive: Duration,
+    /// Max number of inbound requests served to a single peer per second.
+    /// The requests above the quota are refused.
+    pub max_requests_per_second: u32,
+    /// Max number of bytes of responses served to a single peer within the `response_bytes_window`.
+    /// The requests above the quota are refused.
+    pub max_response_bytes_per_window: u64,
+    /// The window of the `max_response_bytes_per_window` quota.
+    pub response_bytes_window: Duration,
 
     /// Time between checking heartbeat status for all peers
     pub heartbeat_check_interval: Duration,
@@ -136,6 +172,9 @@ pub struct Config<State = Initialized> {
     /// Max time since a given peer has sent a heartbeat before getting reputation penalty
     pub heartbeat_max_time_since_last: Duration,
 
+    /// The policy of the peer reputation: ban thresholds, decay and penalties
+    pub reputation_config: ReputationConfig,
+
     /// Enables prometheus metrics for this fuel-service
     pub metrics: bool,
 
@@ -157,6 +196,8 @@ impl Config<NotInitialized> {
     pub fn init(self, genesis: Genesis) -> anyhow::Result<Config<Initialized>> {
         use fuel_core_chain_config::GenesisCommitment;
 
+        self.reputation_config.validate()?;
+
         Ok(Config {
             keypair: self.keypair,
             network_name: self.network_name,
@@ -164,8 +205,10 @@ impl Config<NotInitialized> {
             address: self.address,
             public_address: self.public_address,
             tcp_port: self.tcp_port,
+            quic_port: self.quic_port,
             max_block_size: self.max_block_size,
             max_headers_per_request: self.max_headers_per_request,
+            max_txs_per_request: self.max_txs_per_request,
             bootstrap_nodes: self.bootstrap_nodes,
             enable_mdns: self.enable_mdns,
             max_peers_connected: self.max_peers_connected,
@@ -181,9 +224,13 @@ impl Config<NotInitialized> {
             heartbeat_config: self.heartbeat_config,
             set_request_timeout: self.set_request_timeout,
             set_connection_keep_alive: self.set_connection_keep_alive,
+            max_requests_per_second: self.max_requests_per_second,
+            max_response_bytes_per_window: self.max_response_bytes_per_window,
+            response_bytes_window: self.response_bytes_window,
             heartbeat_check_interval: self.heartbeat_check_interval,
             heartbeat_max_avg_interval: self.heartbeat_max_time_since_last,
             heartbeat_max_time_since_last: self.heartbeat_max_time_since_last,
+            reputation_config: self.reputation_config,
             metrics: self.metrics,
             state: Initialized(()),
         })
@@ -212,8 +259,10 @@ impl Config<NotInitialized> {
             address: IpAddr::V4(Ipv4Addr::from([0, 0, 0, 0])),
             public_address: None,
             tcp_port: 0,
+            quic_port: None,
             max_block_size: MAX_RESPONSE_SIZE,
             max_headers_per_request: MAX_HEADERS_PER_REQUEST,
+            max_txs_per_request: MAX_TXS_PER_REQUEST,
             bootstrap_nodes: vec![],
             enable_mdns: false,
             max_peers_connected: 50,
@@ -227,11 +276,15 @@ impl Config<NotInitialized> {
             heartbeat_config: HeartbeatConfig::default(),
             set_request_timeout: REQ_RES_TIMEOUT,
             set_connection_keep_alive: REQ_RES_TIMEOUT,
+            max_requests_per_second: MAX_REQUESTS_PER_SECOND,
+            max_response_bytes_per_window: MAX_RESPONSE_BYTES_PER_WINDOW,
+            response_bytes_window: RESPONSE_BYTES_WINDOW,
             heartbeat_check_interval: Duration::from_secs(10),
             heartbeat_max_avg_interval: Duration::from_secs(20),
             heartbeat_max_time_since_last: Duration::from_secs(40),
             info_interval: Some(Duration::from_secs(3)),
             identify_interval: Some(Duration::from_secs(5)),
+            reputation_config: ReputationConfig::default(),
             metrics: false,
             state: NotInitialized,
         }
@@ -251,6 +304,7 @@ impl Config<Initialized> {
 /// TCP/IP, Websocket
 /// Noise as encryption layer
 /// mplex or yamux for multiplexing
+/// And optionally QUIC with its own encryption and multiplexing
 pub(crate) fn build_transport_function(
     p2p_config: &Config,
 ) -> (
@@ -292,15 +346,17 @@ pub(crate) fn build_transport_function(
 
             let fuel_authenticated = FuelAuthenticated::new(
                 noise_authenticated,
-                guarded_node,
+                guarded_node.clone(),
                 p2p_config.checksum,
             );
 
-            transport
+            let tcp_transport = transport
                 .authenticate(fuel_authenticated)
                 .multiplex(multiplex_config)
                 .timeout(TRANSPORT_TIMEOUT)
-                .boxed()
+                .boxed();
+
+            with_quic_transport(p2p_config, keypair, tcp_transport, guarded_node)
         } else {
             let connection_tracker = ConnectionTracker::new(
                 &p2p_config.reserved_nodes,
@@ -309,21 +365,60 @@ pub(crate) fn build_transport_function(
 
             let fuel_authenticated = FuelAuthenticated::new(
                 noise_authenticated,
-                connection_tracker,
+                connection_tracker.clone(),
                 p2p_config.checksum,
             );
 
-            transport
+            let tcp_transport = transport
                 .authenticate(fuel_authenticated)
                 .multiplex(multiplex_config)
                 .timeout(TRANSPORT_TIMEOUT)
-                .boxed()
+                .boxed();
+
+            with_quic_transport(p2p_config, keypair, tcp_transport, connection_tracker)
         }
     };
 
     (transport_function, kept_connection_state)
 }
 
+/// Combines the `tcp_transport` with the QUIC transport if it is enabled.
+/// QUIC connections are secured by TLS and can't use the `FuelAuthenticated` upgrade,
+/// so the `approver` is applied after the handshake, and the checksum
+/// is verified during the identification of the peer.
+fn with_quic_transport<A>(
+    p2p_config: &Config,
+    keypair: &Keypair,
+    tcp_transport: Boxed<(PeerId, StreamMuxerBox)>,
+    approver: A,
+) -> Boxed<(PeerId, StreamMuxerBox)>
+where
+    A: Approver + Clone + Send + Sync + 'static,
+{
+    if p2p_config.quic_port.is_none() {
+        return tcp_transport
+    }
+
+    let mut quic_config = QuicConfig::new(keypair);
+    quic_config.handshake_timeout = TRANSPORT_TIMEOUT;
+    let quic_transport =
+        TokioQuicTransport::new(quic_config).and_then(move |(peer_id, connection), _| {
+            if approver.allow_peer(&peer_id) {
+                future::ok((peer_id, StreamMuxerBox::new(connection)))
+            } else {
+                future::err(std::io::Error::new(
+                    std::io::ErrorKind::PermissionDenied,
+                    "The connection with the peer is not allowed",
+                ))
+            }
+        });
+
+    tcp_transport
+        .or_transport(quic_transport)
+        .map(|either, _| either.into_inner())
+        .boxed()
+}
+
 fn peer_ids_set_from(multiaddr: &[Multiaddr]) -> HashSet<PeerId> {
     multiaddr
         .iter()
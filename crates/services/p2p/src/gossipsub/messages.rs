@@ -1,6 +1,10 @@
 use std::sync::Arc;
 
-use fuel_core_types::fuel_tx::Transaction;
+use fuel_core_types::{
+    blockchain::consensus::bft::ConsensusMessage,
+    fuel_tx::Transaction,
+    services::p2p::CompactBlock,
+};
 
 use serde::{
     Deserialize,
@@ -12,6 +16,8 @@ use serde::{
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum GossipTopicTag {
     NewTx,
+    Consensus,
+    NewBlock,
 }
 
 /// Takes `Arc<T>` and wraps it in a matching GossipsubBroadcastRequest
@@ -20,9 +26,13 @@ pub enum GossipTopicTag {
 #[derive(Debug, Clone)]
 pub enum GossipsubBroadcastRequest {
     NewTx(Arc<Transaction>),
+    Consensus(Arc<ConsensusMessage>),
+    NewBlock(Arc<CompactBlock>),
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum GossipsubMessage {
     NewTx(Transaction),
+    Consensus(ConsensusMessage),
+    NewBlock(CompactBlock),
 }
@@ -11,6 +11,8 @@ use super::messages::{
 
 pub type GossipTopic = Sha256Topic;
 pub const NEW_TX_GOSSIP_TOPIC: &str = "new_tx";
+pub const CONSENSUS_GOSSIP_TOPIC: &str = "consensus";
+pub const NEW_BLOCK_GOSSIP_TOPIC: &str = "new_block";
 
 /// Holds used Gossipsub Topics
 /// Each field contains TopicHash and GossipTopic itself
@@ -18,14 +20,22 @@ pub const NEW_TX_GOSSIP_TOPIC: &str = "new_tx";
 #[derive(Debug)]
 pub struct GossipsubTopics {
     new_tx_topic: (TopicHash, GossipTopic),
+    consensus_topic: (TopicHash, GossipTopic),
+    new_block_topic: (TopicHash, GossipTopic),
 }
 
 impl GossipsubTopics {
     pub fn new(network_name: &str) -> Self {
         let new_tx_topic = Topic::new(format!("{NEW_TX_GOSSIP_TOPIC}/{network_name}"));
+        let consensus_topic =
+            Topic::new(format!("{CONSENSUS_GOSSIP_TOPIC}/{network_name}"));
+        let new_block_topic =
+            Topic::new(format!("{NEW_BLOCK_GOSSIP_TOPIC}/{network_name}"));
 
         Self {
             new_tx_topic: (new_tx_topic.hash(), new_tx_topic),
+            consensus_topic: (consensus_topic.hash(), consensus_topic),
+            new_block_topic: (new_block_topic.hash(), new_block_topic),
         }
     }
 
@@ -34,10 +44,16 @@ impl GossipsubTopics {
         &self,
         incoming_topic: &TopicHash,
     ) -> Option<GossipTopicTag> {
-        let GossipsubTopics { new_tx_topic } = &self;
+        let GossipsubTopics {
+            new_tx_topic,
+            consensus_topic,
+            new_block_topic,
+        } = &self;
 
         match incoming_topic {
             hash if hash == &new_tx_topic.0 => Some(GossipTopicTag::NewTx),
+            hash if hash == &consensus_topic.0 => Some(GossipTopicTag::Consensus),
+            hash if hash == &new_block_topic.0 => Some(GossipTopicTag::NewBlock),
             _ => None,
         }
     }
@@ -50,6 +66,8 @@ impl GossipsubTopics {
     ) -> GossipTopic {
         match outgoing_request {
             GossipsubBroadcastRequest::NewTx(_) => self.new_tx_topic.1.clone(),
+            GossipsubBroadcastRequest::Consensus(_) => self.consensus_topic.1.clone(),
+            GossipsubBroadcastRequest::NewBlock(_) => self.new_block_topic.1.clone(),
         }
     }
 }
@@ -57,7 +75,15 @@ impl GossipsubTopics {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use fuel_core_types::fuel_tx::Transaction;
+    use fuel_core_types::{
+        blockchain::consensus::bft::{
+            ConsensusMessage,
+            Vote,
+            VoteType,
+        },
+        fuel_tx::Transaction,
+        services::p2p::CompactBlock,
+    };
     use libp2p::gossipsub::Topic;
     use std::sync::Arc;
 
@@ -66,17 +92,31 @@ mod tests {
         let network_name = "fuel_test_network";
         let new_tx_topic: GossipTopic =
             Topic::new(format!("{NEW_TX_GOSSIP_TOPIC}/{network_name}"));
+        let consensus_topic: GossipTopic =
+            Topic::new(format!("{CONSENSUS_GOSSIP_TOPIC}/{network_name}"));
+        let new_block_topic: GossipTopic =
+            Topic::new(format!("{NEW_BLOCK_GOSSIP_TOPIC}/{network_name}"));
 
         let gossipsub_topics = GossipsubTopics::new(network_name);
 
         // Test matching Topic Hashes
         assert_eq!(gossipsub_topics.new_tx_topic.0, new_tx_topic.hash());
+        assert_eq!(gossipsub_topics.consensus_topic.0, consensus_topic.hash());
+        assert_eq!(gossipsub_topics.new_block_topic.0, new_block_topic.hash());
 
         // Test given a TopicHash that `get_gossipsub_tag()` returns matching `GossipTopicTag`
         assert_eq!(
             gossipsub_topics.get_gossipsub_tag(&new_tx_topic.hash()),
             Some(GossipTopicTag::NewTx)
         );
+        assert_eq!(
+            gossipsub_topics.get_gossipsub_tag(&consensus_topic.hash()),
+            Some(GossipTopicTag::Consensus)
+        );
+        assert_eq!(
+            gossipsub_topics.get_gossipsub_tag(&new_block_topic.hash()),
+            Some(GossipTopicTag::NewBlock)
+        );
 
         // Test given a `GossipsubBroadcastRequest` that `get_gossipsub_topic()` returns matching `Topic`
         let broadcast_req =
@@ -85,5 +125,26 @@ mod tests {
             gossipsub_topics.get_gossipsub_topic(&broadcast_req).hash(),
             new_tx_topic.hash()
         );
+
+        let vote = Vote {
+            height: 1u32.into(),
+            round: 0,
+            vote_type: VoteType::Prevote,
+            block_id: None,
+            signature: Default::default(),
+        };
+        let broadcast_req =
+            GossipsubBroadcastRequest::Consensus(Arc::new(ConsensusMessage::Vote(vote)));
+        assert_eq!(
+            gossipsub_topics.get_gossipsub_topic(&broadcast_req).hash(),
+            consensus_topic.hash()
+        );
+
+        let broadcast_req =
+            GossipsubBroadcastRequest::NewBlock(Arc::new(CompactBlock::default()));
+        assert_eq!(
+            gossipsub_topics.get_gossipsub_topic(&broadcast_req).hash(),
+            new_block_topic.hash()
+        );
     }
 }
@@ -28,7 +28,11 @@ use std::{
 
 mod handler;
 
+/// The legacy protocol, it sends only the latest block height.
 pub const HEARTBEAT_PROTOCOL: &str = "/fuel/heartbeat/0.0.1";
+/// The protocol sends the latest block height and the lowest block height
+/// with the transactions, which is above the genesis for the pruning nodes.
+pub const HEARTBEAT_PROTOCOL_V2: &str = "/fuel/heartbeat/0.0.2";
 
 #[derive(Debug, Clone)]
 enum HeartbeatAction {
@@ -61,6 +65,8 @@ impl HeartbeatAction {
 pub struct HeartbeatEvent {
     pub peer_id: PeerId,
     pub latest_block_height: BlockHeight,
+    /// `None` if the peer uses the legacy protocol.
+    pub lowest_block_height: Option<BlockHeight>,
 }
 
 #[derive(Debug, Clone)]
@@ -68,6 +74,7 @@ pub struct Heartbeat {
     config: HeartbeatConfig,
     pending_events: VecDeque<HeartbeatAction>,
     current_block_height: BlockHeight,
+    lowest_block_height: BlockHeight,
 }
 
 impl Heartbeat {
@@ -76,12 +83,18 @@ impl Heartbeat {
             config,
             pending_events: VecDeque::default(),
             current_block_height: block_height,
+            lowest_block_height: BlockHeight::default(),
         }
     }
 
     pub fn update_block_height(&mut self, block_height: BlockHeight) {
         self.current_block_height = block_height;
     }
+
+    /// Updates the lowest height of the block served with the transactions.
+    pub fn update_lowest_block_height(&mut self, block_height: BlockHeight) {
+        self.lowest_block_height = block_height;
+    }
 }
 
 impl NetworkBehaviour for Heartbeat {
@@ -117,20 +130,25 @@ impl NetworkBehaviour for Heartbeat {
         event: THandlerOutEvent<Self>,
     ) {
         match event {
-            HeartbeatOutEvent::BlockHeight(latest_block_height) => self
+            HeartbeatOutEvent::BlockHeight {
+                latest_block_height,
+                lowest_block_height,
+            } => self
                 .pending_events
                 .push_back(HeartbeatAction::HeartbeatEvent(HeartbeatEvent {
                     peer_id,
                     latest_block_height,
+                    lowest_block_height,
                 })),
             HeartbeatOutEvent::RequestBlockHeight => {
                 self.pending_events
                     .push_back(HeartbeatAction::BlockHeightRequest {
                         peer_id,
                         connection_id,
-                        in_event: HeartbeatInEvent::LatestBlock(
-                            self.current_block_height,
-                        ),
+                        in_event: HeartbeatInEvent::LatestBlock {
+                            latest_block_height: self.current_block_height,
+                            lowest_block_height: self.lowest_block_height,
+                        },
                     })
             }
         }
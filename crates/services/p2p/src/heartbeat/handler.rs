@@ -1,7 +1,13 @@
-use super::HEARTBEAT_PROTOCOL;
+use super::{
+    HEARTBEAT_PROTOCOL,
+    HEARTBEAT_PROTOCOL_V2,
+};
 use fuel_core_types::fuel_types::BlockHeight;
 use futures::{
-    future::BoxFuture,
+    future::{
+        BoxFuture,
+        Either,
+    },
     AsyncRead,
     AsyncReadExt,
     AsyncWrite,
@@ -9,7 +15,10 @@ use futures::{
     FutureExt,
 };
 use libp2p::{
-    core::upgrade::ReadyUpgrade,
+    core::upgrade::{
+        ReadyUpgrade,
+        SelectUpgrade,
+    },
     swarm::{
         handler::{
             ConnectionEvent,
@@ -36,15 +45,51 @@ use tracing::debug;
 
 #[derive(Debug, Clone)]
 pub enum HeartbeatInEvent {
-    LatestBlock(BlockHeight),
+    LatestBlock {
+        latest_block_height: BlockHeight,
+        lowest_block_height: BlockHeight,
+    },
 }
 
 #[derive(Debug, Clone)]
 pub enum HeartbeatOutEvent {
-    BlockHeight(BlockHeight),
+    BlockHeight {
+        latest_block_height: BlockHeight,
+        /// `None` if the peer uses the legacy protocol without the lowest height.
+        lowest_block_height: Option<BlockHeight>,
+    },
     RequestBlockHeight,
 }
 
+/// The version of the heartbeat protocol negotiated for the stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Version {
+    /// Only the latest `BlockHeight` is sent.
+    Legacy,
+    /// The latest `BlockHeight` is followed by the lowest `BlockHeight`
+    /// with the transactions.
+    WithLowestHeight,
+}
+
+/// The upgrade prefers the protocol with the lowest height and falls back
+/// to the legacy protocol for the older peers.
+type HeartbeatUpgrade =
+    SelectUpgrade<ReadyUpgrade<&'static str>, ReadyUpgrade<&'static str>>;
+
+fn heartbeat_upgrade() -> HeartbeatUpgrade {
+    SelectUpgrade::new(
+        ReadyUpgrade::new(HEARTBEAT_PROTOCOL_V2),
+        ReadyUpgrade::new(HEARTBEAT_PROTOCOL),
+    )
+}
+
+fn negotiated(stream: Either<Stream, Stream>) -> (Stream, Version) {
+    match stream {
+        Either::Left(stream) => (stream, Version::WithLowestHeight),
+        Either::Right(stream) => (stream, Version::Legacy),
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct HeartbeatConfig {
     /// Sending of `BlockHeight` should not take longer than this
@@ -80,13 +125,18 @@ impl Default for HeartbeatConfig {
     }
 }
 
-type InboundData = BoxFuture<'static, Result<(Stream, BlockHeight), std::io::Error>>;
+type InboundData = BoxFuture<
+    'static,
+    Result<(Stream, BlockHeight, Option<BlockHeight>), std::io::Error>,
+>;
 type OutboundData = BoxFuture<'static, Result<Stream, std::io::Error>>;
 
 pub struct HeartbeatHandler {
     config: HeartbeatConfig,
     inbound: Option<InboundData>,
+    inbound_version: Version,
     outbound: Option<OutboundState>,
+    outbound_version: Version,
     timer: Pin<Box<Sleep>>,
     failure_count: u32,
 }
@@ -96,7 +146,9 @@ impl HeartbeatHandler {
         Self {
             config,
             inbound: None,
+            inbound_version: Version::Legacy,
             outbound: None,
+            outbound_version: Version::Legacy,
             timer: Box::pin(sleep(Duration::new(0, 0))),
             failure_count: 0,
         }
@@ -106,13 +158,13 @@ impl HeartbeatHandler {
 impl ConnectionHandler for HeartbeatHandler {
     type FromBehaviour = HeartbeatInEvent;
     type ToBehaviour = HeartbeatOutEvent;
-    type InboundProtocol = ReadyUpgrade<&'static str>;
-    type OutboundProtocol = ReadyUpgrade<&'static str>;
+    type InboundProtocol = HeartbeatUpgrade;
+    type OutboundProtocol = HeartbeatUpgrade;
     type InboundOpenInfo = ();
     type OutboundOpenInfo = ();
 
-    fn listen_protocol(&self) -> SubstreamProtocol<ReadyUpgrade<&'static str>, ()> {
-        SubstreamProtocol::new(ReadyUpgrade::new(HEARTBEAT_PROTOCOL), ())
+    fn listen_protocol(&self) -> SubstreamProtocol<HeartbeatUpgrade, ()> {
+        SubstreamProtocol::new(heartbeat_upgrade(), ())
     }
 
     fn connection_keep_alive(&self) -> bool {
@@ -136,13 +188,17 @@ impl ConnectionHandler for HeartbeatHandler {
                     debug!(target: "fuel-libp2p", "Incoming heartbeat errored");
                     self.inbound = None;
                 }
-                Poll::Ready(Ok((stream, block_height))) => {
+                Poll::Ready(Ok((stream, latest_block_height, lowest_block_height))) => {
                     // start waiting for the next `BlockHeight`
-                    self.inbound = Some(receive_block_height(stream).boxed());
+                    self.inbound =
+                        Some(receive_block_height(stream, self.inbound_version).boxed());
 
                     // report newly received `BlockHeight` to the Behaviour
                     return Poll::Ready(ConnectionHandlerEvent::NotifyBehaviour(
-                        HeartbeatOutEvent::BlockHeight(block_height),
+                        HeartbeatOutEvent::BlockHeight {
+                            latest_block_height,
+                            lowest_block_height,
+                        },
                     ))
                 }
                 _ => {}
@@ -219,9 +275,8 @@ impl ConnectionHandler for HeartbeatHandler {
                 None => {
                     // Request new stream
                     self.outbound = Some(OutboundState::NegotiatingStream);
-                    let protocol =
-                        SubstreamProtocol::new(ReadyUpgrade::new(HEARTBEAT_PROTOCOL), ())
-                            .with_timeout(self.config.send_timeout);
+                    let protocol = SubstreamProtocol::new(heartbeat_upgrade(), ())
+                        .with_timeout(self.config.send_timeout);
                     return Poll::Ready(ConnectionHandlerEvent::OutboundSubstreamRequest {
                         protocol,
                     })
@@ -232,7 +287,10 @@ impl ConnectionHandler for HeartbeatHandler {
     }
 
     fn on_behaviour_event(&mut self, event: Self::FromBehaviour) {
-        let HeartbeatInEvent::LatestBlock(block_height) = event;
+        let HeartbeatInEvent::LatestBlock {
+            latest_block_height,
+            lowest_block_height,
+        } = event;
 
         match self.outbound.take() {
             Some(OutboundState::RequestingBlockHeight {
@@ -243,7 +301,13 @@ impl ConnectionHandler for HeartbeatHandler {
                 self.timer = Box::pin(sleep(self.config.send_timeout));
                 // send latest `BlockHeight`
                 self.outbound = Some(OutboundState::SendingBlockHeight(
-                    send_block_height(stream, block_height).boxed(),
+                    send_block_height(
+                        stream,
+                        self.outbound_version,
+                        latest_block_height,
+                        lowest_block_height,
+                    )
+                    .boxed(),
                 ))
             }
             other_state => self.outbound = other_state,
@@ -264,12 +328,16 @@ impl ConnectionHandler for HeartbeatHandler {
                 protocol: stream,
                 ..
             }) => {
-                self.inbound = Some(receive_block_height(stream).boxed());
+                let (stream, version) = negotiated(stream);
+                self.inbound_version = version;
+                self.inbound = Some(receive_block_height(stream, version).boxed());
             }
             ConnectionEvent::FullyNegotiatedOutbound(FullyNegotiatedOutbound {
                 protocol: stream,
                 ..
             }) => {
+                let (stream, version) = negotiated(stream);
+                self.outbound_version = version;
                 self.outbound = Some(OutboundState::RequestingBlockHeight {
                     stream,
                     requested: false,
@@ -300,29 +368,45 @@ enum OutboundState {
 const BLOCK_HEIGHT_SIZE: usize = 4;
 
 /// Takes in a stream
-/// Waits to receive next `BlockHeight`
-/// Returns the flushed stream and the received `BlockHeight`
-async fn receive_block_height<S>(mut stream: S) -> std::io::Result<(S, BlockHeight)>
+/// Waits to receive next `BlockHeight` and, for the new protocol, the lowest `BlockHeight`
+/// Returns the flushed stream and the received `BlockHeight`s
+async fn receive_block_height<S>(
+    mut stream: S,
+    version: Version,
+) -> std::io::Result<(S, BlockHeight, Option<BlockHeight>)>
 where
     S: AsyncRead + AsyncWrite + Unpin,
 {
     let mut payload = [0u8; BLOCK_HEIGHT_SIZE];
     stream.read_exact(&mut payload).await?;
+    let latest_block_height = u32::from_be_bytes(payload).into();
+    let lowest_block_height = match version {
+        Version::Legacy => None,
+        Version::WithLowestHeight => {
+            stream.read_exact(&mut payload).await?;
+            Some(u32::from_be_bytes(payload).into())
+        }
+    };
     stream.flush().await?;
-    let block_height = u32::from_be_bytes(payload).into();
-    Ok((stream, block_height))
+    Ok((stream, latest_block_height, lowest_block_height))
 }
 
 /// Takes in a stream and latest `BlockHeight`
-/// Sends the `BlockHeight` and returns back the stream after flushing it
+/// Sends the `BlockHeight` and, for the new protocol, the lowest `BlockHeight`
+/// Returns back the stream after flushing it
 async fn send_block_height<S>(
     mut stream: S,
-    block_height: BlockHeight,
+    version: Version,
+    latest_block_height: BlockHeight,
+    lowest_block_height: BlockHeight,
 ) -> std::io::Result<S>
 where
     S: AsyncRead + AsyncWrite + Unpin,
 {
-    stream.write_all(&block_height.to_bytes()).await?;
+    stream.write_all(&latest_block_height.to_bytes()).await?;
+    if version == Version::WithLowestHeight {
+        stream.write_all(&lowest_block_height.to_bytes()).await?;
+    }
     stream.flush().await?;
 
     Ok(stream)
@@ -1,7 +1,9 @@
 use crate::{
     behavior::{
+        identify_protocol_version,
         FuelBehaviour,
         FuelBehaviourEvent,
+        LEGACY_IDENTIFY_PROTOCOL_VERSION,
     },
     codecs::{
         postcard::PostcardCodec,
@@ -24,19 +26,30 @@ use crate::{
         Punisher,
     },
     peer_report::PeerReportEvent,
-    request_response::messages::{
-        RequestError,
-        RequestMessage,
-        ResponseChannelItem,
-        ResponseMessage,
-        ResponseSendError,
+    request_response::{
+        messages::{
+            RequestError,
+            RequestMessage,
+            RequestResponseProtocol,
+            ResponseChannelItem,
+            ResponseMessage,
+            ResponseSendError,
+        },
+        quotas::{
+            Quota,
+            RequestQuotas,
+        },
     },
     TryPeerId,
 };
 use fuel_core_metrics::p2p_metrics::p2p_metrics;
 use fuel_core_types::{
     fuel_types::BlockHeight,
-    services::p2p::peer_reputation::AppScore,
+    services::p2p::{
+        peer_reputation::AppScore,
+        PeerId as FuelPeerId,
+        PeerRecord,
+    },
 };
 use futures::prelude::*;
 use libp2p::{
@@ -64,8 +77,14 @@ use libp2p::{
 };
 use rand::seq::IteratorRandom;
 use std::{
-    collections::HashMap,
-    time::Duration,
+    collections::{
+        HashMap,
+        HashSet,
+    },
+    time::{
+        Duration,
+        Instant,
+    },
 };
 use tracing::{
     debug,
@@ -75,6 +94,12 @@ use tracing::{
 /// Maximum amount of peer's addresses that we are ready to store per peer
 const MAX_IDENTIFY_ADDRESSES: usize = 10;
 
+fn is_quic(address: &Multiaddr) -> bool {
+    address
+        .iter()
+        .any(|protocol| matches!(protocol, Protocol::Quic | Protocol::QuicV1))
+}
+
 impl Punisher for Swarm<FuelBehaviour> {
     fn ban_peer(&mut self, peer_id: PeerId) {
         self.behaviour_mut().block_peer(peer_id)
@@ -93,6 +118,16 @@ pub struct FuelP2PService {
     /// The TCP port that Swarm listens on
     tcp_port: u16,
 
+    /// The UDP port that Swarm listens on for QUIC connections, if enabled
+    quic_port: Option<u16>,
+
+    /// The version of the identify protocol expected from the peers of the network
+    identify_protocol_version: String,
+
+    /// The number of TCP connections with each peer. Unlike QUIC, TCP connections
+    /// go through the `FuelAuthenticated` upgrade that checks the network checksum
+    tcp_connections: HashMap<PeerId, usize>,
+
     /// Swarm handler for FuelBehaviour
     swarm: Swarm<FuelBehaviour>,
 
@@ -104,7 +139,11 @@ pub struct FuelP2PService {
     /// Holds the ResponseChannel(s) for the inbound requests from the p2p Network
     /// Once the Response is prepared by the NetworkOrchestrator
     /// It will send it to the specified Peer via its unique ResponseChannel    
-    inbound_requests_table: HashMap<InboundRequestId, ResponseChannel<ResponseMessage>>,
+    inbound_requests_table:
+        HashMap<InboundRequestId, (PeerId, ResponseChannel<ResponseMessage>)>,
+
+    /// Limits the inbound requests and the bytes served to each peer
+    request_quotas: RequestQuotas,
 
     /// NetworkCodec used as `<GossipsubCodec>` for encoding and decoding of Gossipsub messages    
     network_codec: PostcardCodec,
@@ -157,6 +196,27 @@ pub enum FuelP2PEvent {
     },
 }
 
+/// The requests of the node operator to manage the peers at runtime.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PeerManagementRequest {
+    /// Bans the peer for the `duration`, or permanently if it is not specified.
+    Ban {
+        peer_id: PeerId,
+        duration: Option<Duration>,
+    },
+    /// Lifts the ban of the peer.
+    Unban { peer_id: PeerId },
+    /// Dials the peer at the `address`.
+    Dial { address: Multiaddr },
+    /// Closes all connections with the peer.
+    Disconnect { peer_id: PeerId },
+    /// Adds the peer at the `address` to the reserved peers and dials it.
+    /// The `address` should end with the peer id.
+    AddReservedPeer { address: Multiaddr },
+    /// Removes the peer from the reserved peers.
+    RemoveReservedPeer { peer_id: PeerId },
+}
+
 impl FuelP2PService {
     pub fn new(config: Config, codec: PostcardCodec) -> Self {
         let gossipsub_data =
@@ -200,50 +260,78 @@ impl FuelP2PService {
             local_peer_id,
             local_address: config.address,
             tcp_port: config.tcp_port,
+            quic_port: config.quic_port,
+            identify_protocol_version: identify_protocol_version(&config.checksum),
+            tcp_connections: HashMap::default(),
             swarm,
             network_codec: codec,
             outbound_requests_table: HashMap::default(),
             inbound_requests_table: HashMap::default(),
+            request_quotas: RequestQuotas::new(
+                Quota::new(
+                    config.max_requests_per_second.into(),
+                    Duration::from_secs(1),
+                ),
+                Quota::new(
+                    config.max_response_bytes_per_window,
+                    config.response_bytes_window,
+                ),
+            ),
             network_metadata,
             metrics,
             peer_manager: PeerManager::new(
                 reserved_peers,
                 connection_state,
                 config.max_peers_connected as usize,
+                config.reputation_config.clone(),
             ),
         }
     }
 
     pub async fn start(&mut self) -> anyhow::Result<()> {
-        // set up node's address to listen on
-        let listen_multiaddr = {
+        // set up node's addresses to listen on
+        let mut listen_multiaddrs = vec![{
             let mut m = Multiaddr::from(self.local_address);
             m.push(Protocol::Tcp(self.tcp_port));
             m
-        };
+        }];
+        if let Some(quic_port) = self.quic_port {
+            let mut m = Multiaddr::from(self.local_address);
+            m.push(Protocol::Udp(quic_port));
+            m.push(Protocol::QuicV1);
+            listen_multiaddrs.push(m);
+        }
         let peer_id = self.local_peer_id;
 
-        tracing::info!(
-            "The p2p service starts on the `{listen_multiaddr}` with `{peer_id}`"
-        );
+        for listen_multiaddr in &listen_multiaddrs {
+            tracing::info!(
+                "The p2p service starts on the `{listen_multiaddr}` with `{peer_id}`"
+            );
 
-        // start listening at the given address
-        self.swarm.listen_on(listen_multiaddr)?;
+            // start listening at the given address
+            self.swarm.listen_on(listen_multiaddr.clone())?;
+        }
 
         // Wait for listener addresses.
-        tokio::time::timeout(Duration::from_secs(5), self.await_listeners_address())
-            .await
-            .map_err(|_| {
-                anyhow::anyhow!("P2PService should get a new address within 5 seconds")
-            })?;
+        tokio::time::timeout(
+            Duration::from_secs(5),
+            self.await_listeners_address(listen_multiaddrs.len()),
+        )
+        .await
+        .map_err(|_| {
+            anyhow::anyhow!("P2PService should get a new address within 5 seconds")
+        })?;
         Ok(())
     }
 
-    async fn await_listeners_address(&mut self) {
-        loop {
-            if let SwarmEvent::NewListenAddr { .. } = self.swarm.select_next_some().await
+    /// Waits until each of the `listeners` gets an address.
+    async fn await_listeners_address(&mut self, listeners: usize) {
+        let mut listeners_with_address = HashSet::new();
+        while listeners_with_address.len() < listeners {
+            if let SwarmEvent::NewListenAddr { listener_id, .. } =
+                self.swarm.select_next_some().await
             {
-                break
+                listeners_with_address.insert(listener_id);
             }
         }
     }
@@ -293,10 +381,19 @@ impl FuelP2PService {
         message_request: RequestMessage,
         channel_item: ResponseChannelItem,
     ) -> Result<OutboundRequestId, RequestError> {
+        let min_protocol = message_request.min_protocol();
         let peer_id = match peer_id {
-            Some(peer_id) => peer_id,
+            Some(peer_id) => {
+                let supported = self
+                    .peer_manager
+                    .get_peer_info(&peer_id)
+                    .map_or(true, |peer_info| peer_info.supports(min_protocol));
+                if !supported {
+                    return Err(RequestError::UnsupportedProtocol(min_protocol))
+                }
+                peer_id
+            }
             _ => {
-                let peers = self.get_peers_ids_iter();
                 let peers_count = self.peer_manager.total_peers_connected();
 
                 if peers_count == 0 {
@@ -304,7 +401,12 @@ impl FuelP2PService {
                 }
 
                 let mut range = rand::thread_rng();
-                *peers.choose(&mut range).unwrap()
+                self.peer_manager
+                    .get_all_peers()
+                    .filter(|(_, peer_info)| peer_info.supports(min_protocol))
+                    .map(|(peer_id, _)| *peer_id)
+                    .choose(&mut range)
+                    .ok_or(RequestError::UnsupportedProtocol(min_protocol))?
             }
         };
 
@@ -325,11 +427,22 @@ impl FuelP2PService {
         request_id: InboundRequestId,
         message: ResponseMessage,
     ) -> Result<(), ResponseSendError> {
-        let Some(channel) = self.inbound_requests_table.remove(&request_id) else {
+        let Some((peer_id, channel)) = self.inbound_requests_table.remove(&request_id)
+        else {
             debug!("ResponseChannel for {:?} does not exist!", request_id);
             return Err(ResponseSendError::ResponseChannelDoesNotExist)
         };
 
+        let response_size = postcard::experimental::serialized_size(&message)
+            .ok()
+            .and_then(|size| u64::try_from(size).ok())
+            .unwrap_or_default();
+        self.request_quotas
+            .record_response(&peer_id, response_size, Instant::now());
+        if self.metrics {
+            p2p_metrics().served_response_bytes.inc_by(response_size);
+        }
+
         if self
             .swarm
             .behaviour_mut()
@@ -347,6 +460,14 @@ impl FuelP2PService {
         self.swarm.behaviour_mut().update_block_height(block_height)
     }
 
+    /// Updates the lowest height of the block that the node serves with
+    /// the transactions, advertised to the peers via the heartbeat.
+    pub fn update_lowest_block_height(&mut self, block_height: BlockHeight) {
+        self.swarm
+            .behaviour_mut()
+            .update_lowest_block_height(block_height)
+    }
+
     /// The report is forwarded to gossipsub behaviour
     /// If acceptance is "Rejected" the gossipsub peer score is calculated
     /// And if it's below allowed threshold the peer is banned
@@ -396,6 +517,17 @@ impl FuelP2PService {
             reporting_service,
             &mut self.swarm,
         );
+        self.update_peer_score_metrics();
+    }
+
+    /// Records the current application scores of the connected peers,
+    /// so the history of the scores is available in the metrics.
+    fn update_peer_score_metrics(&self) {
+        if self.metrics {
+            for (peer_id, peer_info) in self.peer_manager.get_all_peers() {
+                p2p_metrics().set_peer_app_score(peer_id.to_string(), peer_info.score);
+            }
+        }
     }
 
     #[tracing::instrument(skip_all,
@@ -421,6 +553,18 @@ impl FuelP2PService {
                 tracing::info!("Listening for p2p traffic on `{address}`");
                 None
             }
+            SwarmEvent::ConnectionEstablished {
+                peer_id, endpoint, ..
+            } => {
+                self.track_connection(peer_id, endpoint.get_remote_address());
+                None
+            }
+            SwarmEvent::ConnectionClosed {
+                peer_id, endpoint, ..
+            } => {
+                self.untrack_connection(peer_id, endpoint.get_remote_address());
+                None
+            }
             SwarmEvent::ListenerClosed {
                 addresses, reason, ..
             } => {
@@ -437,6 +581,118 @@ impl FuelP2PService {
         &self.peer_manager
     }
 
+    fn track_connection(&mut self, peer_id: PeerId, address: &Multiaddr) {
+        if !is_quic(address) {
+            let connections = self.tcp_connections.entry(peer_id).or_default();
+            *connections = connections.saturating_add(1);
+        }
+    }
+
+    fn untrack_connection(&mut self, peer_id: PeerId, address: &Multiaddr) {
+        if is_quic(address) {
+            return
+        }
+        if let Some(connections) = self.tcp_connections.get_mut(&peer_id) {
+            *connections = connections.saturating_sub(1);
+            if *connections == 0 {
+                self.tcp_connections.remove(&peer_id);
+            }
+        }
+    }
+
+    /// Returns `true` if the peer identified with the `protocol_version` is from
+    /// the same network. The legacy version doesn't include the checksum, so it is
+    /// accepted only from the peers whose checksum was verified by the TCP upgrade.
+    fn is_same_network(&self, peer_id: &PeerId, protocol_version: &str) -> bool {
+        protocol_version == self.identify_protocol_version
+            || (protocol_version == LEGACY_IDENTIFY_PROTOCOL_VERSION
+                && self.tcp_connections.contains_key(peer_id))
+    }
+
+    /// Restores the known peers persisted by the previous run of the node.
+    /// The banned peers are blocked again, and the peers seen the most recently
+    /// are dialed to reconnect without waiting for the discovery.
+    pub fn restore_peers(&mut self, records: Vec<(FuelPeerId, PeerRecord)>) {
+        self.peer_manager.restore_peer_store(records);
+
+        for peer_id in self.peer_manager.banned_peers() {
+            self.swarm.behaviour_mut().block_peer(*peer_id);
+        }
+
+        for (peer_id, addresses) in self.peer_manager.peers_to_dial() {
+            self.swarm
+                .behaviour_mut()
+                .add_addresses_to_discovery(&peer_id, addresses);
+            if let Err(e) = self.swarm.dial(peer_id) {
+                debug!(target: "fuel-p2p", "Failed to dial the known peer {:?}: {}", peer_id, e);
+            }
+        }
+    }
+
+    /// Handles the request of the node operator to manage the peers.
+    pub fn manage_peers(&mut self, request: PeerManagementRequest) -> anyhow::Result<()> {
+        match request {
+            PeerManagementRequest::Ban { peer_id, duration } => {
+                if self.peer_manager.is_reserved(&peer_id) {
+                    return Err(anyhow::anyhow!(
+                        "The reserved peer {peer_id} can't be banned, remove it from the reserved peers first"
+                    ))
+                }
+                self.peer_manager.ban_peer(&peer_id, duration);
+                self.swarm.behaviour_mut().block_peer(peer_id);
+            }
+            PeerManagementRequest::Unban { peer_id } => {
+                self.unban_peer(peer_id);
+            }
+            PeerManagementRequest::Dial { address } => {
+                self.swarm.dial(address)?;
+            }
+            PeerManagementRequest::Disconnect { peer_id } => {
+                self.swarm.disconnect_peer_id(peer_id).map_err(|_| {
+                    anyhow::anyhow!("The peer {peer_id} is not connected")
+                })?;
+            }
+            PeerManagementRequest::AddReservedPeer { address } => {
+                let peer_id = address.try_to_peer_id().ok_or_else(|| {
+                    anyhow::anyhow!("The address {address} doesn't contain the peer id")
+                })?;
+                self.unban_peer(peer_id);
+                self.peer_manager.add_reserved_peer(peer_id);
+                self.swarm
+                    .behaviour_mut()
+                    .add_reserved_peer(&peer_id, address.clone());
+                if !self.swarm.is_connected(&peer_id) {
+                    self.swarm.dial(address)?;
+                }
+            }
+            PeerManagementRequest::RemoveReservedPeer { peer_id } => {
+                self.swarm.behaviour_mut().remove_reserved_peer(&peer_id);
+                if self.peer_manager.remove_reserved_peer(&peer_id) {
+                    let _ = self.swarm.disconnect_peer_id(peer_id);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Lifts the bans that have expired.
+    pub fn unban_expired_peers(&mut self) {
+        for peer_id in self.peer_manager.expired_bans() {
+            debug!(target: "fuel-p2p", "The ban of the peer {:?} has expired", peer_id);
+            self.unban_peer(peer_id);
+        }
+    }
+
+    fn unban_peer(&mut self, peer_id: PeerId) {
+        self.peer_manager.unban_peer(&peer_id);
+        self.swarm.behaviour_mut().unblock_peer(peer_id);
+    }
+
+    /// Returns the records of the known peers to persist.
+    pub fn peer_records(&mut self) -> Vec<(FuelPeerId, PeerRecord)> {
+        self.peer_manager.peer_records()
+    }
+
     fn handle_behaviour_event(
         &mut self,
         event: FuelBehaviourEvent,
@@ -498,7 +754,8 @@ impl FuelP2PService {
     ) -> Option<FuelP2PEvent> {
         match event {
             PeerReportEvent::PerformDecay => {
-                self.peer_manager.batch_update_score_with_decay()
+                self.peer_manager.batch_update_score_with_decay();
+                self.update_peer_score_metrics();
             }
             PeerReportEvent::CheckReservedNodesHealth => {
                 let disconnected_peers: Vec<_> = self
@@ -527,6 +784,10 @@ impl FuelP2PService {
                 }
             }
             PeerReportEvent::PeerDisconnected { peer_id } => {
+                if self.metrics {
+                    p2p_metrics().remove_peer_app_score(peer_id.to_string());
+                }
+                self.request_quotas.evict_refilled(Instant::now());
                 if self.peer_manager.handle_peer_disconnect(peer_id) {
                     let _ = self.swarm.dial(peer_id);
                 }
@@ -547,7 +808,27 @@ impl FuelP2PService {
                     channel,
                     request_id,
                 } => {
-                    self.inbound_requests_table.insert(request_id, channel);
+                    if !self.peer_manager.is_reserved(&peer) {
+                        if let Err(quota) =
+                            self.request_quotas.try_request(&peer, Instant::now())
+                        {
+                            debug!(target: "fuel-p2p", "Peer {:?} exceeded the quota of {} and the request is refused", peer, quota.as_str());
+                            if self.metrics {
+                                p2p_metrics().inc_refused_requests(quota.as_str());
+                            }
+                            let _ = self
+                                .swarm
+                                .behaviour_mut()
+                                .send_response_msg(channel, request.empty_response());
+                            self.peer_manager
+                                .handle_request_quota_exceeded(peer, &mut self.swarm);
+                            self.update_peer_score_metrics();
+                            return None
+                        }
+                    }
+
+                    self.inbound_requests_table
+                        .insert(request_id, (peer, channel));
 
                     return Some(FuelP2PEvent::InboundRequestMessage {
                         request_id,
@@ -577,6 +858,10 @@ impl FuelP2PService {
                             ResponseChannelItem::SealedHeaders(channel),
                             ResponseMessage::SealedHeaders(headers),
                         ) => channel.send((peer, headers)).is_ok(),
+                        (
+                            ResponseChannelItem::TransactionsByIds(channel),
+                            ResponseMessage::TransactionsByIds(transactions),
+                        ) => channel.send(transactions).is_ok(),
 
                         (_, _) => {
                             tracing::error!(
@@ -615,6 +900,12 @@ impl FuelP2PService {
     fn handle_identify_event(&mut self, event: identify::Event) -> Option<FuelP2PEvent> {
         match event {
             identify::Event::Received { peer_id, info } => {
+                if !self.is_same_network(&peer_id, &info.protocol_version) {
+                    debug!(target: "fuel-p2p", "Peer {:?} is from another network, it is identified by {:?}", peer_id, info.protocol_version);
+                    let _ = self.swarm.disconnect_peer_id(peer_id);
+                    return None
+                }
+
                 if self.metrics {
                     p2p_metrics().unique_peers.inc();
                 }
@@ -632,10 +923,19 @@ impl FuelP2PService {
                     addresses.truncate(MAX_IDENTIFY_ADDRESSES);
                 }
 
+                let req_res_protocols = info
+                    .protocols
+                    .iter()
+                    .filter_map(|protocol| {
+                        RequestResponseProtocol::from_protocol_id(protocol.as_ref())
+                    })
+                    .collect();
+
                 self.peer_manager.handle_peer_identified(
                     &peer_id,
                     addresses.clone(),
                     agent_version,
+                    req_res_protocols,
                 );
 
                 self.swarm
@@ -655,9 +955,13 @@ impl FuelP2PService {
         let HeartbeatEvent {
             peer_id,
             latest_block_height,
+            lowest_block_height,
         } = event;
-        self.peer_manager
-            .handle_peer_info_updated(&peer_id, latest_block_height);
+        self.peer_manager.handle_peer_info_updated(
+            &peer_id,
+            latest_block_height,
+            lowest_block_height,
+        );
 
         Some(FuelP2PEvent::PeerInfoUpdated {
             peer_id,
@@ -674,6 +978,7 @@ mod tests {
         PublishError,
     };
     use crate::{
+        behavior::LEGACY_IDENTIFY_PROTOCOL_VERSION,
         codecs::postcard::PostcardCodec,
         config::Config,
         gossipsub::{
@@ -683,10 +988,15 @@ mod tests {
             },
             topics::{
                 GossipTopic,
+                CONSENSUS_GOSSIP_TOPIC,
+                NEW_BLOCK_GOSSIP_TOPIC,
                 NEW_TX_GOSSIP_TOPIC,
             },
         },
-        p2p_service::FuelP2PEvent,
+        p2p_service::{
+            FuelP2PEvent,
+            PeerManagementRequest,
+        },
         peer_manager::PeerInfo,
         request_response::messages::{
             RequestMessage,
@@ -699,6 +1009,11 @@ mod tests {
         blockchain::{
             block::Block,
             consensus::{
+                bft::{
+                    ConsensusMessage,
+                    Vote,
+                    VoteType,
+                },
                 poa::PoAConsensus,
                 Consensus,
             },
@@ -714,6 +1029,7 @@ mod tests {
             TransactionBuilder,
         },
         services::p2p::{
+            CompactBlock,
             GossipsubMessageAcceptance,
             Transactions,
         },
@@ -725,6 +1041,7 @@ mod tests {
     use libp2p::{
         gossipsub::Topic,
         identity::Keypair,
+        multiaddr::Protocol,
         swarm::{
             ListenError,
             SwarmEvent,
@@ -1118,6 +1435,96 @@ mod tests {
         }
     }
 
+    fn quic_multiaddrs(node: &P2PService) -> Vec<Multiaddr> {
+        node.multiaddrs()
+            .into_iter()
+            .filter(|address| address.iter().any(|p| p == Protocol::QuicV1))
+            .collect()
+    }
+
+    // Simulates 2 p2p nodes, Node B is bootstrapped only with the QUIC address of Node A
+    #[tokio::test]
+    #[instrument]
+    async fn nodes_connected_via_quic() {
+        // Node A
+        let mut p2p_config = Config::default_initialized("nodes_connected_via_quic");
+        p2p_config.quic_port = Some(0);
+        let mut node_a = build_service_from_config(p2p_config.clone()).await;
+
+        // Node B
+        p2p_config.bootstrap_nodes = quic_multiaddrs(&node_a);
+        assert!(!p2p_config.bootstrap_nodes.is_empty());
+        let mut node_b = build_service_from_config(p2p_config).await;
+
+        loop {
+            tokio::select! {
+                node_b_event = node_b.next_event() => {
+                    if let Some(FuelP2PEvent::PeerConnected(_)) = node_b_event {
+                        // successfully connected to Node A
+                        break
+                    }
+                    tracing::info!("Node B Event: {:?}", node_b_event);
+                },
+                _ = node_a.swarm.select_next_some() => {},
+            };
+        }
+    }
+
+    // Simulates 2 p2p nodes connected via QUIC with different checksums.
+    // QUIC bypasses the Fuel Upgrade, so they are disconnected after the identification
+    #[tokio::test]
+    #[instrument]
+    async fn nodes_disconnect_over_quic_due_to_different_checksum() {
+        // Node A
+        let mut p2p_config = Config::default_initialized(
+            "nodes_disconnect_over_quic_due_to_different_checksum",
+        );
+        p2p_config.quic_port = Some(0);
+        let mut node_a = build_service_from_config(p2p_config.clone()).await;
+
+        // different checksum
+        p2p_config.checksum = [1u8; 32].into();
+        p2p_config.bootstrap_nodes = quic_multiaddrs(&node_a);
+        // Node B
+        let mut node_b = build_service_from_config(p2p_config).await;
+
+        loop {
+            tokio::select! {
+                node_b_event = node_b.next_event() => {
+                    if let Some(FuelP2PEvent::PeerDisconnected(_)) = node_b_event {
+                        break
+                    }
+                    tracing::info!("Node B Event: {:?}", node_b_event);
+                },
+                _ = node_a.next_event() => {},
+            };
+        }
+    }
+
+    #[tokio::test]
+    #[instrument]
+    async fn legacy_identify_version_is_accepted_only_over_tcp() {
+        let p2p_config = Config::default_initialized(
+            "legacy_identify_version_is_accepted_only_over_tcp",
+        );
+        let mut node = build_service_from_config(p2p_config).await;
+        let peer_id = PeerId::random();
+        let quic_address: Multiaddr = "/ip4/127.0.0.1/udp/4001/quic-v1".parse().unwrap();
+        let tcp_address: Multiaddr = "/ip4/127.0.0.1/tcp/4001".parse().unwrap();
+
+        // The QUIC connection skips the checksum handshake
+        node.track_connection(peer_id, &quic_address);
+        assert!(!node.is_same_network(&peer_id, LEGACY_IDENTIFY_PROTOCOL_VERSION));
+        assert!(node.is_same_network(&peer_id, &node.identify_protocol_version));
+
+        // The TCP connection verified the checksum
+        node.track_connection(peer_id, &tcp_address);
+        assert!(node.is_same_network(&peer_id, LEGACY_IDENTIFY_PROTOCOL_VERSION));
+
+        node.untrack_connection(peer_id, &tcp_address);
+        assert!(!node.is_same_network(&peer_id, LEGACY_IDENTIFY_PROTOCOL_VERSION));
+    }
+
     // Simulates 3 p2p nodes, Node B & Node C are bootstrapped with Node A
     // Using Identify Protocol Node C should be able to identify and connect to Node B
     #[tokio::test]
@@ -1158,6 +1565,58 @@ mod tests {
         }
     }
 
+    // Simulates 2 p2p nodes, Node B is bootstrapped with Node A
+    // Node A bans Node B, which closes the connection, and lifts the ban later,
+    // so Node B can be dialed again
+    #[tokio::test]
+    #[instrument]
+    async fn banned_peer_is_disconnected_until_unbanned() {
+        let mut p2p_config =
+            Config::default_initialized("banned_peer_is_disconnected_until_unbanned");
+
+        // Node A
+        let mut node_a = build_service_from_config(p2p_config.clone()).await;
+
+        // Node B
+        p2p_config.bootstrap_nodes = node_a.multiaddrs();
+        let mut node_b = build_service_from_config(p2p_config).await;
+        let node_b_peer_id = node_b.local_peer_id;
+        let node_b_address = node_b.multiaddrs().pop().unwrap();
+
+        let mut banned = false;
+        loop {
+            tokio::select! {
+                node_a_event = node_a.next_event() => {
+                    match node_a_event {
+                        Some(FuelP2PEvent::PeerConnected(peer_id)) if peer_id == node_b_peer_id => {
+                            if banned {
+                                // Node B is connected again after the ban is lifted
+                                break
+                            }
+                            node_a.manage_peers(PeerManagementRequest::Ban {
+                                peer_id,
+                                duration: None,
+                            }).unwrap();
+                            banned = true;
+                        }
+                        Some(FuelP2PEvent::PeerDisconnected(peer_id)) if peer_id == node_b_peer_id => {
+                            // the banned peer can't be dialed
+                            let dial = PeerManagementRequest::Dial { address: node_b_address.clone() };
+                            assert!(node_a.manage_peers(dial.clone()).is_err());
+
+                            node_a.manage_peers(PeerManagementRequest::Unban { peer_id }).unwrap();
+                            node_a.manage_peers(dial).unwrap();
+                        }
+                        _ => {}
+                    }
+                },
+                node_b_event = node_b.next_event() => {
+                    tracing::info!("Node B Event: {:?}", node_b_event);
+                },
+            };
+        }
+    }
+
     // Simulates 2 p2p nodes that connect to each other and consequently exchange Peer Info
     // On successful connection, node B updates its latest BlockHeight
     // and shares it with Peer A via Heartbeat protocol
@@ -1174,6 +1633,7 @@ mod tests {
         let mut node_b = build_service_from_config(p2p_config).await;
 
         let latest_block_height = 40_u32.into();
+        let lowest_block_height = 10_u32.into();
 
         loop {
             tokio::select! {
@@ -1183,8 +1643,10 @@ mod tests {
                             // Exits after it verifies that:
                             // 1. Peer Addresses are known
                             // 2. Client Version is known
-                            // 3. Node has responded with their latest BlockHeight
-                            if client_version.is_some() && heartbeat_data.block_height == Some(latest_block_height) {
+                            // 3. Node has responded with their latest and lowest BlockHeight
+                            if client_version.is_some()
+                                && heartbeat_data.block_height == Some(latest_block_height)
+                                && heartbeat_data.lowest_block_height == Some(lowest_block_height) {
                                 break;
                             }
                         }
@@ -1197,6 +1659,7 @@ mod tests {
                         // we've connected to Peer A
                         // let's update our BlockHeight
                         node_b.update_block_height(latest_block_height);
+                        node_b.update_lowest_block_height(lowest_block_height);
                     }
                     tracing::info!("Node B Event: {:?}", node_b_event);
                 }
@@ -1224,6 +1687,46 @@ mod tests {
         .await;
     }
 
+    fn test_consensus_message() -> ConsensusMessage {
+        ConsensusMessage::Vote(Vote {
+            height: 1u32.into(),
+            round: 0,
+            vote_type: VoteType::Prevote,
+            block_id: None,
+            signature: Default::default(),
+        })
+    }
+
+    #[tokio::test]
+    #[instrument]
+    async fn gossipsub_broadcast_consensus_message_with_accept() {
+        gossipsub_broadcast(
+            GossipsubBroadcastRequest::Consensus(Arc::new(test_consensus_message())),
+            GossipsubMessageAcceptance::Accept,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[instrument]
+    async fn gossipsub_broadcast_new_block_with_accept() {
+        gossipsub_broadcast(
+            GossipsubBroadcastRequest::NewBlock(Arc::new(CompactBlock::default())),
+            GossipsubMessageAcceptance::Accept,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[instrument]
+    async fn gossipsub_broadcast_new_block_with_reject() {
+        gossipsub_broadcast(
+            GossipsubBroadcastRequest::NewBlock(Arc::new(CompactBlock::default())),
+            GossipsubMessageAcceptance::Reject,
+        )
+        .await;
+    }
+
     #[tokio::test]
     #[instrument]
     #[ignore]
@@ -1354,6 +1857,8 @@ mod tests {
         let selected_topic: GossipTopic = {
             let topic = match broadcast_request {
                 GossipsubBroadcastRequest::NewTx(_) => NEW_TX_GOSSIP_TOPIC,
+                GossipsubBroadcastRequest::Consensus(_) => CONSENSUS_GOSSIP_TOPIC,
+                GossipsubBroadcastRequest::NewBlock(_) => NEW_BLOCK_GOSSIP_TOPIC,
             };
 
             Topic::new(format!("{}/{}", topic, p2p_config.network_name))
@@ -1415,6 +1920,18 @@ mod tests {
                                     panic!("Wrong GossipsubMessage")
                                 }
                             }
+                            GossipsubMessage::Consensus(consensus_message) => {
+                                if consensus_message != &test_consensus_message() {
+                                    tracing::error!("Wrong p2p message {:?}", message);
+                                    panic!("Wrong GossipsubMessage")
+                                }
+                            }
+                            GossipsubMessage::NewBlock(block) => {
+                                if block != &CompactBlock::default() {
+                                    tracing::error!("Wrong p2p message {:?}", message);
+                                    panic!("Wrong GossipsubMessage")
+                                }
+                            }
                         }
 
                         // Node B received the correct message
@@ -1557,6 +2074,23 @@ mod tests {
                                             }
                                         });
                                     }
+                                    RequestMessage::TransactionsByIds(tx_ids) => {
+                                        let (tx_orchestrator, rx_orchestrator) = oneshot::channel();
+                                        assert!(node_a.send_request_msg(None, request_msg.clone(), ResponseChannelItem::TransactionsByIds(tx_orchestrator)).is_ok());
+                                        let tx_test_end = tx_test_end.clone();
+
+                                        tokio::spawn(async move {
+                                            let response_message = rx_orchestrator.await;
+
+                                            if let Ok(Some(transactions)) = response_message {
+                                                let check = transactions.len() == tx_ids.len();
+                                                let _ = tx_test_end.send(check).await;
+                                            } else {
+                                                tracing::error!("Orchestrator failed to receive a message: {:?}", response_message);
+                                                let _ = tx_test_end.send(false).await;
+                                            }
+                                        });
+                                    }
                                 }
                             }
                         }
@@ -1588,6 +2122,10 @@ mod tests {
                                 let transactions = vec![Transactions(txs)];
                                 let _ = node_b.send_response_msg(*request_id, ResponseMessage::Transactions(Some(transactions)));
                             }
+                            RequestMessage::TransactionsByIds(tx_ids) => {
+                                let transactions = tx_ids.iter().map(|_| Transaction::default_test_tx()).collect();
+                                let _ = node_b.send_response_msg(*request_id, ResponseMessage::TransactionsByIds(Some(transactions)));
+                            }
                         }
                     }
 
@@ -1617,6 +2155,13 @@ mod tests {
         request_response_works_with(RequestMessage::SealedHeaders(arbitrary_range)).await
     }
 
+    #[tokio::test]
+    #[instrument]
+    async fn request_response_works_with_transactions_by_ids() {
+        let tx_ids = vec![Default::default(); 3];
+        request_response_works_with(RequestMessage::TransactionsByIds(tx_ids)).await
+    }
+
     #[tokio::test]
     #[instrument]
     async fn req_res_outbound_timeout_works() {
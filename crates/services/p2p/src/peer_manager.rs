@@ -1,11 +1,15 @@
 use fuel_core_types::{
     fuel_types::BlockHeight,
-    services::p2p::peer_reputation::{
-        AppScore,
-        DECAY_APP_SCORE,
-        DEFAULT_APP_SCORE,
-        MAX_APP_SCORE,
-        MIN_APP_SCORE,
+    services::p2p::{
+        peer_reputation::{
+            AppScore,
+            DECAY_APP_SCORE,
+            DEFAULT_APP_SCORE,
+            MAX_APP_SCORE,
+            MIN_APP_SCORE,
+        },
+        PeerId as FuelPeerId,
+        PeerRecord,
     },
 };
 use libp2p::{
@@ -18,10 +22,12 @@ use std::{
         HashMap,
         HashSet,
     },
+    ops::Range,
     sync::{
         Arc,
         RwLock,
     },
+    time::Duration,
 };
 use tracing::{
     debug,
@@ -30,19 +36,33 @@ use tracing::{
 
 use crate::{
     gossipsub_config::GRAYLIST_THRESHOLD,
-    peer_manager::heartbeat_data::HeartbeatData,
+    peer_manager::{
+        heartbeat_data::HeartbeatData,
+        peer_store::PeerStore,
+    },
+    request_response::messages::RequestResponseProtocol,
 };
 
 pub mod heartbeat_data;
+pub mod peer_store;
 
 /// At this point we better just ban the peer
 const MIN_GOSSIPSUB_SCORE_BEFORE_BAN: AppScore = GRAYLIST_THRESHOLD;
 
+/// The interval between the decays of the application scores.
+const REPUTATION_DECAY_INTERVAL: Duration = Duration::from_secs(1);
+
+/// The duration of the automatic bans. Only the bans made by the operator are permanent.
+const AUTOMATIC_BAN_DURATION: Duration = Duration::from_secs(60 * 60);
+
 // Info about a single Peer that we're connected to
 #[derive(Debug, Clone)]
 pub struct PeerInfo {
     pub peer_addresses: HashSet<Multiaddr>,
     pub client_version: Option<String>,
+    /// The versions of the request/response protocol reported by the peer
+    /// during the identification. Empty until the peer is identified.
+    pub req_res_protocols: Vec<RequestResponseProtocol>,
     pub heartbeat_data: HeartbeatData,
     pub score: AppScore,
 }
@@ -52,22 +72,35 @@ impl PeerInfo {
         Self {
             peer_addresses: HashSet::new(),
             client_version: None,
+            req_res_protocols: Vec::new(),
             heartbeat_data: HeartbeatData::new(heartbeat_avg_window),
             score: DEFAULT_APP_SCORE,
         }
     }
+
+    /// Returns `true` if the peer can serve the requests of the `protocol`.
+    /// The peer that is not identified yet is expected to support it,
+    /// the negotiation of the protocol rejects the request otherwise.
+    pub fn supports(&self, protocol: RequestResponseProtocol) -> bool {
+        self.req_res_protocols.is_empty()
+            || self
+                .req_res_protocols
+                .iter()
+                .any(|supported| *supported >= protocol)
+    }
 }
 
 /// Manages Peers and their events
 #[derive(Debug)]
 pub struct PeerManager {
-    score_config: ScoreConfig,
+    reputation_config: ReputationConfig,
     non_reserved_connected_peers: HashMap<PeerId, PeerInfo>,
     reserved_connected_peers: HashMap<PeerId, PeerInfo>,
     reserved_peers: HashSet<PeerId>,
     connection_state: Arc<RwLock<ConnectionState>>,
     max_non_reserved_peers: usize,
     reserved_peers_updates: tokio::sync::broadcast::Sender<usize>,
+    peer_store: PeerStore,
 }
 
 impl PeerManager {
@@ -75,20 +108,119 @@ impl PeerManager {
         reserved_peers: HashSet<PeerId>,
         connection_state: Arc<RwLock<ConnectionState>>,
         max_non_reserved_peers: usize,
+        reputation_config: ReputationConfig,
     ) -> Self {
         let (reserved_peers_updates, _) = tokio::sync::broadcast::channel(
             reserved_peers.len().saturating_mul(2).saturating_add(1),
         );
 
         Self {
-            score_config: ScoreConfig::default(),
+            reputation_config,
             non_reserved_connected_peers: HashMap::with_capacity(max_non_reserved_peers),
             reserved_connected_peers: HashMap::with_capacity(reserved_peers.len()),
             reserved_peers,
             connection_state,
             max_non_reserved_peers,
             reserved_peers_updates,
+            peer_store: PeerStore::default(),
+        }
+    }
+
+    /// Restores the known peers persisted by the previous run of the node.
+    pub fn restore_peer_store(&mut self, records: Vec<(FuelPeerId, PeerRecord)>) {
+        self.peer_store = PeerStore::from_records(records);
+    }
+
+    /// Returns the banned peers, including the ones banned by the previous runs.
+    pub fn banned_peers(&self) -> impl Iterator<Item = &PeerId> {
+        self.peer_store.banned_peers()
+    }
+
+    /// Records the ban of the peer by the operator for the `duration`,
+    /// or permanently if it is not specified.
+    pub fn ban_peer(&mut self, peer_id: &PeerId, duration: Option<Duration>) {
+        self.peer_store.ban(peer_id, duration);
+    }
+
+    /// Lifts the ban of the peer. Returns `true` if the peer was banned.
+    pub fn unban_peer(&mut self, peer_id: &PeerId) -> bool {
+        self.peer_store.unban(peer_id)
+    }
+
+    /// Returns the banned peers with the expired bans.
+    pub fn expired_bans(&self) -> Vec<PeerId> {
+        self.peer_store.expired_bans()
+    }
+
+    /// Marks the peer as reserved. The connected peer frees its non-reserved slot.
+    pub fn add_reserved_peer(&mut self, peer_id: PeerId) {
+        if !self.reserved_peers.insert(peer_id) {
+            return
+        }
+
+        let all_slots_taken =
+            self.non_reserved_connected_peers.len() >= self.max_non_reserved_peers;
+        if let Some(peer_info) = self.non_reserved_connected_peers.remove(&peer_id) {
+            self.reserved_connected_peers.insert(peer_id, peer_info);
+            self.send_reserved_peers_update();
+
+            if all_slots_taken {
+                if let Ok(mut connection_state) = self.connection_state.write() {
+                    connection_state.allow_new_peers();
+                }
+            }
+        }
+    }
+
+    /// Removes the peer from the reserved peers. The connected peer takes
+    /// a non-reserved slot. Returns `true` signaling that the peer should be
+    /// disconnected, because all non-reserved slots are already taken.
+    pub fn remove_reserved_peer(&mut self, peer_id: &PeerId) -> bool {
+        if !self.reserved_peers.remove(peer_id) {
+            return false
+        }
+
+        let peer_info = match self.reserved_connected_peers.remove(peer_id) {
+            Some(peer_info) => peer_info,
+            None => return false,
+        };
+        self.send_reserved_peers_update();
+
+        let non_reserved_peers_connected = self.non_reserved_connected_peers.len();
+        if non_reserved_peers_connected >= self.max_non_reserved_peers {
+            return true
+        }
+        if non_reserved_peers_connected.saturating_add(1) == self.max_non_reserved_peers {
+            if let Ok(mut connection_state) = self.connection_state.write() {
+                connection_state.deny_new_peers();
+            }
+        }
+        self.non_reserved_connected_peers
+            .insert(*peer_id, peer_info);
+        false
+    }
+
+    /// Returns the known peers seen the most recently, that are worth dialing
+    /// to fill the slots of non-reserved peers.
+    pub fn peers_to_dial(&self) -> Vec<(PeerId, Vec<Multiaddr>)> {
+        self.peer_store
+            .peers_to_dial(self.max_non_reserved_peers)
+            .into_iter()
+            .filter(|(peer_id, _)| !self.reserved_peers.contains(peer_id))
+            .collect()
+    }
+
+    /// Returns the records of the known peers to persist,
+    /// including the latest scores of the connected peers.
+    pub fn peer_records(&mut self) -> Vec<(FuelPeerId, PeerRecord)> {
+        for (peer_id, peer_info) in self
+            .non_reserved_connected_peers
+            .iter()
+            .chain(self.reserved_connected_peers.iter())
+        {
+            self.peer_store.update_score(peer_id, peer_info.score);
         }
+        self.peer_store.records()
     }
 
     pub fn reserved_peers_updates(&self) -> tokio::sync::broadcast::Sender<usize> {
@@ -100,14 +232,16 @@ impl PeerManager {
     }
 
     pub fn handle_gossip_score_update<T: Punisher>(
-        &self,
+        &mut self,
         peer_id: PeerId,
         gossip_score: f64,
         punisher: &mut T,
     ) {
-        if gossip_score < self.score_config.min_gossip_score_allowed
+        if gossip_score < self.reputation_config.min_gossip_score
             && !self.reserved_peers.contains(&peer_id)
         {
+            self.peer_store
+                .ban(&peer_id, Some(self.reputation_config.ban_duration));
             punisher.ban_peer(peer_id);
         }
     }
@@ -116,6 +250,7 @@ impl PeerManager {
         &mut self,
         peer_id: &PeerId,
         block_height: BlockHeight,
+        lowest_block_height: Option<BlockHeight>,
     ) {
         if let Some(time_elapsed) = self
             .get_peer_info(peer_id)
@@ -125,7 +260,7 @@ impl PeerManager {
         }
 
         let peers = self.get_assigned_peer_table_mut(peer_id);
-        update_heartbeat(peers, peer_id, block_height);
+        update_heartbeat(peers, peer_id, block_height, lowest_block_height);
     }
 
     /// Returns `true` signaling that the peer should be disconnected
@@ -146,15 +281,18 @@ impl PeerManager {
         peer_id: &PeerId,
         addresses: Vec<Multiaddr>,
         agent_version: String,
+        req_res_protocols: Vec<RequestResponseProtocol>,
     ) {
+        self.peer_store.update_addresses(peer_id, &addresses);
         let peers = self.get_assigned_peer_table_mut(peer_id);
         insert_client_version(peers, peer_id, agent_version);
+        insert_req_res_protocols(peers, peer_id, req_res_protocols);
         insert_peer_addresses(peers, peer_id, addresses);
     }
 
     pub fn batch_update_score_with_decay(&mut self) {
         for peer_info in self.non_reserved_connected_peers.values_mut() {
-            peer_info.score *= DECAY_APP_SCORE;
+            peer_info.score *= self.reputation_config.decay_factor;
         }
     }
 
@@ -167,12 +305,14 @@ impl PeerManager {
     ) {
         if let Some(peer) = self.non_reserved_connected_peers.get_mut(&peer_id) {
             // score should not go over `max_score`
-            let new_score = self.score_config.max_app_score.min(peer.score + score);
+            let new_score = self.reputation_config.max_app_score.min(peer.score + score);
             peer.score = new_score;
 
             info!(target: "fuel-p2p", "{reporting_service} updated {peer_id} with new score {score}");
 
-            if new_score < self.score_config.min_app_score_allowed {
+            if new_score < self.reputation_config.min_app_score {
+                self.peer_store
+                    .ban(&peer_id, Some(self.reputation_config.ban_duration));
                 punisher.ban_peer(peer_id);
             }
         } else {
@@ -180,6 +320,16 @@ impl PeerManager {
         }
     }
 
+    /// Lowers the score of the peer that exceeded the quota of the inbound requests.
+    pub fn handle_request_quota_exceeded<T: Punisher>(
+        &mut self,
+        peer_id: PeerId,
+        punisher: &mut T,
+    ) {
+        let penalty = self.reputation_config.request_quota_exceeded_penalty;
+        self.update_app_score(peer_id, penalty, "p2p", punisher);
+    }
+
     pub fn total_peers_connected(&self) -> usize {
         self.reserved_connected_peers
             .len()
@@ -222,9 +372,12 @@ impl PeerManager {
             let all_slots_taken = self.max_non_reserved_peers
                 == self.non_reserved_connected_peers.len().saturating_add(1);
 
-            if self.non_reserved_connected_peers.remove(&peer_id).is_some()
-                && all_slots_taken
-            {
+            let removed = self.non_reserved_connected_peers.remove(&peer_id);
+            if let Some(peer_info) = &removed {
+                self.peer_store.update_score(&peer_id, peer_info.score);
+            }
+
+            if removed.is_some() && all_slots_taken {
                 // since all the slots were full prior to this disconnect
                 // let's allow new peer non-reserved peers connections
                 if let Ok(mut connection_state) = self.connection_state.write() {
@@ -241,8 +394,11 @@ impl PeerManager {
         }
     }
 
-    /// Find a peer that is holding the given block height.
-    pub fn get_peer_id_with_height(&self, height: &BlockHeight) -> Option<PeerId> {
+    /// Find a peer that is holding the blocks with the transactions in the given range.
+    pub fn get_peer_id_with_blocks(
+        &self,
+        block_height_range: &Range<u32>,
+    ) -> Option<PeerId> {
         let mut range = rand::thread_rng();
         // TODO: Optimize the selection of the peer.
         //  We can store pair `(peer id, height)` for all nodes(reserved and not) in the
@@ -251,7 +407,7 @@ impl PeerManager {
             .iter()
             .chain(self.reserved_connected_peers.iter())
             .filter(|(_, peer_info)| {
-                peer_info.heartbeat_data.block_height >= Some(*height)
+                peer_info.heartbeat_data.has_blocks(block_height_range)
             })
             .map(|(peer_id, _)| *peer_id)
             .choose(&mut range)
@@ -279,8 +435,13 @@ impl PeerManager {
                 }
             }
 
+            let mut peer_info = PeerInfo::new(HEARTBEAT_AVG_WINDOW);
+            // the peer continues with the score from the previous connection
+            if let Some(score) = self.peer_store.score(peer_id) {
+                peer_info.score = score;
+            }
             self.non_reserved_connected_peers
-                .insert(*peer_id, PeerInfo::new(HEARTBEAT_AVG_WINDOW));
+                .insert(*peer_id, peer_info);
         } else {
             self.reserved_connected_peers
                 .insert(*peer_id, PeerInfo::new(HEARTBEAT_AVG_WINDOW));
@@ -352,9 +513,11 @@ fn update_heartbeat(
     peers: &mut HashMap<PeerId, PeerInfo>,
     peer_id: &PeerId,
     block_height: BlockHeight,
+    lowest_block_height: Option<BlockHeight>,
 ) {
     if let Some(peer) = peers.get_mut(peer_id) {
         peer.heartbeat_data.update(block_height);
+        peer.heartbeat_data.lowest_block_height = lowest_block_height;
     } else {
         log_missing_peer(peer_id);
     }
@@ -372,29 +535,110 @@ fn insert_client_version(
     }
 }
 
+fn insert_req_res_protocols(
+    peers: &mut HashMap<PeerId, PeerInfo>,
+    peer_id: &PeerId,
+    req_res_protocols: Vec<RequestResponseProtocol>,
+) {
+    if let Some(peer) = peers.get_mut(peer_id) {
+        peer.req_res_protocols = req_res_protocols;
+    } else {
+        log_missing_peer(peer_id);
+    }
+}
+
 fn log_missing_peer(peer_id: &PeerId) {
     debug!(target: "fuel-p2p", "Peer with PeerId: {:?} is not among the connected peers", peer_id)
 }
 
-#[derive(Clone, Debug, Copy)]
-struct ScoreConfig {
-    max_app_score: AppScore,
-    min_app_score_allowed: AppScore,
-    min_gossip_score_allowed: f64,
+/// The policy of the application-level peer reputation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReputationConfig {
+    /// The maximum application score a peer can reach
+    pub max_app_score: AppScore,
+    /// The peer is banned when its application score falls below this threshold
+    pub min_app_score: AppScore,
+    /// The peer is banned when its gossipsub score falls below this threshold
+    pub min_gossip_score: f64,
+    /// The duration of the automatic bans
+    pub ban_duration: Duration,
+    /// The factor applied to the scores of non-reserved peers at each decay
+    pub decay_factor: AppScore,
+    /// Time between the decays of the scores
+    pub decay_interval: Duration,
+    /// The penalty for the peer that hasn't sent a heartbeat for too long
+    pub old_heartbeat_penalty: AppScore,
+    /// The penalty for the peer that sends heartbeats too rarely
+    pub low_heartbeat_frequency_penalty: AppScore,
+    /// The penalty for the peer that exceeds the quota of the inbound requests
+    pub request_quota_exceeded_penalty: AppScore,
+    /// The score changes reported by the other services
+    pub peer_report_config: PeerReportConfig,
 }
 
-impl Default for ScoreConfig {
+impl Default for ReputationConfig {
     fn default() -> Self {
-        Self::new()
+        Self {
+            max_app_score: MAX_APP_SCORE,
+            min_app_score: MIN_APP_SCORE,
+            min_gossip_score: MIN_GOSSIPSUB_SCORE_BEFORE_BAN,
+            ban_duration: AUTOMATIC_BAN_DURATION,
+            decay_factor: DECAY_APP_SCORE,
+            decay_interval: REPUTATION_DECAY_INTERVAL,
+            old_heartbeat_penalty: -5.,
+            low_heartbeat_frequency_penalty: -5.,
+            request_quota_exceeded_penalty: -10.,
+            peer_report_config: PeerReportConfig::default(),
+        }
     }
 }
 
-impl ScoreConfig {
-    pub fn new() -> Self {
+impl ReputationConfig {
+    /// Checks that the decay and the bounds of the application score are valid.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        if self.decay_interval.is_zero() {
+            return Err(anyhow::anyhow!(
+                "The reputation decay interval should be greater than zero"
+            ))
+        }
+        // The negated checks reject `NaN` values as well.
+        let decay_factor_in_range = self.decay_factor > 0. && self.decay_factor <= 1.;
+        if !decay_factor_in_range {
+            return Err(anyhow::anyhow!(
+                "The reputation decay factor should be in the range (0, 1], got {}",
+                self.decay_factor
+            ))
+        }
+        let app_scores_ordered = self.min_app_score < self.max_app_score;
+        if !app_scores_ordered {
+            return Err(anyhow::anyhow!(
+                "The minimum application score {} should be less than the maximum {}",
+                self.min_app_score,
+                self.max_app_score
+            ))
+        }
+        Ok(())
+    }
+}
+
+/// The score changes for each reason of the peer report from the synchronization.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PeerReportConfig {
+    pub successful_block_import: AppScore,
+    pub missing_block_headers: AppScore,
+    pub bad_block_header: AppScore,
+    pub missing_transactions: AppScore,
+    pub invalid_transactions: AppScore,
+}
+
+impl Default for PeerReportConfig {
+    fn default() -> Self {
         Self {
-            max_app_score: MAX_APP_SCORE,
-            min_app_score_allowed: MIN_APP_SCORE,
-            min_gossip_score_allowed: MIN_GOSSIPSUB_SCORE_BEFORE_BAN,
+            successful_block_import: 5.,
+            missing_block_headers: -100.,
+            bad_block_header: -100.,
+            missing_transactions: -100.,
+            invalid_transactions: -100.,
         }
     }
 }
@@ -421,6 +665,7 @@ mod tests {
             reserved_peers.into_iter().collect(),
             connection_state,
             max_non_reserved_peers,
+            ReputationConfig::default(),
         )
     }
 
@@ -499,4 +744,137 @@ mod tests {
             reserved_peers.len() + max_non_reserved_peers
         );
     }
+
+    #[test]
+    fn identified_peer_supports_only_reported_protocols() {
+        let mut peer_manager = initialize_peer_manager(vec![], 5);
+        let peer_id = PeerId::random();
+        peer_manager.handle_initial_connection(&peer_id);
+
+        // not identified peer is expected to support all protocols
+        let peer_info = peer_manager.get_peer_info(&peer_id).unwrap();
+        assert!(peer_info.supports(RequestResponseProtocol::V2));
+
+        peer_manager.handle_peer_identified(
+            &peer_id,
+            vec![],
+            "fuel-core".to_string(),
+            vec![RequestResponseProtocol::V1],
+        );
+
+        let peer_info = peer_manager.get_peer_info(&peer_id).unwrap();
+        assert!(peer_info.supports(RequestResponseProtocol::V1));
+        assert!(!peer_info.supports(RequestResponseProtocol::V2));
+    }
+
+    #[test]
+    fn reconnected_peer_keeps_its_score() {
+        let mut peer_manager = initialize_peer_manager(vec![], 5);
+        let peer_id = PeerId::random();
+        peer_manager.handle_initial_connection(&peer_id);
+        peer_manager
+            .non_reserved_connected_peers
+            .get_mut(&peer_id)
+            .unwrap()
+            .score = 42.0;
+        let records = peer_manager.peer_records();
+
+        let mut restarted_peer_manager = initialize_peer_manager(vec![], 5);
+        restarted_peer_manager.restore_peer_store(records);
+        restarted_peer_manager.handle_initial_connection(&peer_id);
+
+        let peer_info = restarted_peer_manager.get_peer_info(&peer_id).unwrap();
+        assert_eq!(peer_info.score, 42.0);
+    }
+
+    #[derive(Default)]
+    struct FakePunisher {
+        banned_peers: Vec<PeerId>,
+    }
+
+    impl Punisher for FakePunisher {
+        fn ban_peer(&mut self, peer_id: PeerId) {
+            self.banned_peers.push(peer_id);
+        }
+    }
+
+    #[test]
+    fn peer_is_banned_according_to_reputation_config() {
+        let reputation_config = ReputationConfig {
+            min_app_score: -10.,
+            ban_duration: Duration::from_secs(0),
+            ..Default::default()
+        };
+        let mut peer_manager = PeerManager::new(
+            HashSet::new(),
+            ConnectionState::new(),
+            5,
+            reputation_config,
+        );
+        let mut punisher = FakePunisher::default();
+        let peer_id = PeerId::random();
+        peer_manager.handle_initial_connection(&peer_id);
+
+        // the score is above the configured threshold
+        peer_manager.update_app_score(peer_id, -5., "test", &mut punisher);
+        assert!(punisher.banned_peers.is_empty());
+
+        peer_manager.update_app_score(peer_id, -10., "test", &mut punisher);
+        assert_eq!(punisher.banned_peers, vec![peer_id]);
+        // the ban with the configured duration is already expired
+        assert_eq!(peer_manager.expired_bans(), vec![peer_id]);
+    }
+
+    #[test]
+    fn reserved_peer_can_be_added_and_removed_at_runtime() {
+        let max_non_reserved_peers = 1;
+        let mut peer_manager = initialize_peer_manager(vec![], max_non_reserved_peers);
+        let peer_id = PeerId::random();
+        peer_manager.handle_initial_connection(&peer_id);
+
+        peer_manager.add_reserved_peer(peer_id);
+
+        // the slot of the peer is available for another non-reserved peer
+        assert!(peer_manager.is_reserved(&peer_id));
+        let other_peer_id = PeerId::random();
+        assert!(!peer_manager.handle_initial_connection(&other_peer_id));
+        assert_eq!(peer_manager.total_peers_connected(), 2);
+
+        // the slot is taken, so the peer should be disconnected
+        assert!(peer_manager.remove_reserved_peer(&peer_id));
+        assert!(!peer_manager.is_reserved(&peer_id));
+        assert_eq!(peer_manager.total_peers_connected(), 1);
+    }
+
+    #[test]
+    fn reputation_config_validation_rejects_invalid_values() {
+        assert!(ReputationConfig::default().validate().is_ok());
+
+        let invalid_configs = [
+            ReputationConfig {
+                decay_interval: Duration::ZERO,
+                ..Default::default()
+            },
+            ReputationConfig {
+                decay_factor: 0.,
+                ..Default::default()
+            },
+            ReputationConfig {
+                decay_factor: 1.5,
+                ..Default::default()
+            },
+            ReputationConfig {
+                decay_factor: f64::NAN,
+                ..Default::default()
+            },
+            ReputationConfig {
+                min_app_score: 10.,
+                max_app_score: 10.,
+                ..Default::default()
+            },
+        ];
+        for config in invalid_configs {
+            assert!(config.validate().is_err(), "{config:?} should be invalid");
+        }
+    }
 }
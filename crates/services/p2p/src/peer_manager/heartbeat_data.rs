@@ -1,6 +1,7 @@
 use fuel_core_types::fuel_types::BlockHeight;
 use std::{
     collections::VecDeque,
+    ops::Range,
     time::{
         Duration,
         SystemTime,
@@ -11,6 +12,9 @@ use tokio::time::Instant;
 #[derive(Debug, Clone)]
 pub struct HeartbeatData {
     pub block_height: Option<BlockHeight>,
+    /// The lowest height of the block that the peer serves with the transactions.
+    /// `None` if the peer didn't advertise it, so it keeps the whole history.
+    pub lowest_block_height: Option<BlockHeight>,
     pub last_heartbeat: Instant,
     pub last_heartbeat_sys: SystemTime,
     // Size of moving average window
@@ -22,6 +26,7 @@ impl HeartbeatData {
     pub fn new(window: u32) -> Self {
         Self {
             block_height: None,
+            lowest_block_height: None,
             last_heartbeat: Instant::now(),
             last_heartbeat_sys: SystemTime::now(),
             window,
@@ -29,6 +34,17 @@ impl HeartbeatData {
         }
     }
 
+    /// Returns `true` if the peer can serve the blocks with the transactions
+    /// in the `block_height_range`.
+    pub fn has_blocks(&self, block_height_range: &Range<u32>) -> bool {
+        let last = BlockHeight::from(block_height_range.end.saturating_sub(1));
+        let first = BlockHeight::from(block_height_range.start);
+        self.block_height >= Some(last)
+            && self
+                .lowest_block_height
+                .map_or(true, |lowest| lowest <= first)
+    }
+
     pub fn duration_since_last_heartbeat(&self) -> Duration {
         self.last_heartbeat.elapsed()
     }
@@ -0,0 +1,340 @@
+use fuel_core_types::services::p2p::{
+    peer_reputation::AppScore,
+    PeerId as FuelPeerId,
+    PeerRecord,
+};
+use libp2p::{
+    Multiaddr,
+    PeerId,
+};
+use std::{
+    collections::HashMap,
+    time::{
+        Duration,
+        SystemTime,
+    },
+};
+
+/// The maximum number of peers that are not banned kept in the store.
+/// The peers seen the most recently are preferred.
+pub const MAX_STORED_PEERS: usize = 1024;
+
+/// The maximum number of banned peers kept in the store.
+/// The peers banned the longest time ago are evicted first.
+pub const MAX_BANNED_PEERS: usize = 1024;
+
+/// The maximum number of addresses kept for each peer.
+/// The addresses seen the longest time ago are evicted first.
+pub const MAX_ADDRESSES_PER_PEER: usize = 16;
+
+/// Keeps the addresses, scores and bans of the known peers,
+/// so they can be persisted and reused after the restart of the node.
+#[derive(Debug, Default)]
+pub struct PeerStore {
+    records: HashMap<PeerId, PeerRecord>,
+}
+
+impl PeerStore {
+    /// Restores the store from the persisted `records`. The records with invalid
+    /// peer ids are skipped, as well as the invalid addresses. The limits
+    /// of the store are applied to the restored records.
+    pub fn from_records(records: Vec<(FuelPeerId, PeerRecord)>) -> Self {
+        let records = records
+            .into_iter()
+            .filter_map(|(peer_id, mut record)| {
+                let peer_id = PeerId::from_bytes(peer_id.as_ref()).ok()?;
+                record
+                    .addresses
+                    .retain(|address| Multiaddr::try_from(address.clone()).is_ok());
+                evict_oldest_addresses(&mut record.addresses);
+                Some((peer_id, record))
+            })
+            .collect();
+        let mut store = Self { records };
+        store.evict_oldest_peers(MAX_STORED_PEERS);
+        store.evict_oldest_bans();
+        store
+    }
+
+    /// Returns the records to persist.
+    pub fn records(&self) -> Vec<(FuelPeerId, PeerRecord)> {
+        self.records
+            .iter()
+            .map(|(peer_id, record)| (peer_id.to_bytes().into(), record.clone()))
+            .collect()
+    }
+
+    /// Returns the banned peers.
+    pub fn banned_peers(&self) -> impl Iterator<Item = &PeerId> {
+        self.records
+            .iter()
+            .filter(|(_, record)| record.banned)
+            .map(|(peer_id, _)| peer_id)
+    }
+
+    /// Returns up to `limit` peers that are not banned, seen the most recently first,
+    /// along with their addresses.
+    pub fn peers_to_dial(&self, limit: usize) -> Vec<(PeerId, Vec<Multiaddr>)> {
+        let mut peers: Vec<_> = self
+            .records
+            .iter()
+            .filter(|(_, record)| !record.banned && !record.addresses.is_empty())
+            .collect();
+        peers.sort_by(|(_, a), (_, b)| b.last_seen.cmp(&a.last_seen));
+
+        peers
+            .into_iter()
+            .take(limit)
+            .map(|(peer_id, record)| {
+                let addresses = record
+                    .addresses
+                    .iter()
+                    .filter_map(|address| Multiaddr::try_from(address.clone()).ok())
+                    .collect();
+                (*peer_id, addresses)
+            })
+            .collect()
+    }
+
+    /// Returns the last known application score of the peer.
+    pub fn score(&self, peer_id: &PeerId) -> Option<AppScore> {
+        self.records.get(peer_id).map(|record| record.app_score)
+    }
+
+    /// Records the peer as seen now with the `addresses`.
+    pub fn update_addresses(&mut self, peer_id: &PeerId, addresses: &[Multiaddr]) {
+        let record = self.record_mut(peer_id);
+        for address in addresses {
+            let address = address.to_vec();
+            // The address seen again becomes the most recent one.
+            record.addresses.retain(|known| *known != address);
+            record.addresses.push(address);
+        }
+        evict_oldest_addresses(&mut record.addresses);
+    }
+
+    /// Records the peer as seen now with the `score`.
+    pub fn update_score(&mut self, peer_id: &PeerId, score: AppScore) {
+        self.record_mut(peer_id).app_score = score;
+    }
+
+    /// Records the ban of the peer for the `duration`, or permanently if it is not specified.
+    pub fn ban(&mut self, peer_id: &PeerId, duration: Option<Duration>) {
+        let record = self.record_mut(peer_id);
+        record.banned = true;
+        record.banned_until =
+            duration.map(|duration| record.last_seen.saturating_add(duration.as_secs()));
+        self.evict_oldest_bans();
+    }
+
+    /// Lifts the ban of the peer. Returns `true` if the peer was banned.
+    pub fn unban(&mut self, peer_id: &PeerId) -> bool {
+        match self.records.get_mut(peer_id) {
+            Some(record) if record.banned => {
+                record.banned = false;
+                record.banned_until = None;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the banned peers with the expired bans.
+    pub fn expired_bans(&self) -> Vec<PeerId> {
+        let now = now();
+        self.records
+            .iter()
+            .filter(|(_, record)| {
+                record.banned && record.banned_until.map_or(false, |until| until <= now)
+            })
+            .map(|(peer_id, _)| *peer_id)
+            .collect()
+    }
+
+    /// Returns the record of the peer seen now. The room for the record of
+    /// the new peer is made by evicting the peer seen the longest time ago.
+    fn record_mut(&mut self, peer_id: &PeerId) -> &mut PeerRecord {
+        if !self.records.contains_key(peer_id) {
+            self.evict_oldest_peers(MAX_STORED_PEERS.saturating_sub(1));
+        }
+        let record = self.records.entry(*peer_id).or_default();
+        record.last_seen = now();
+        record
+    }
+
+    /// Removes the records of the peers that are not banned and seen the longest
+    /// time ago, so at most `limit` of such peers are kept.
+    fn evict_oldest_peers(&mut self, limit: usize) {
+        self.evict_oldest(false, limit)
+    }
+
+    /// Removes the records of the peers banned the longest time ago,
+    /// so at most [`MAX_BANNED_PEERS`] banned peers are kept.
+    fn evict_oldest_bans(&mut self) {
+        self.evict_oldest(true, MAX_BANNED_PEERS)
+    }
+
+    fn evict_oldest(&mut self, banned: bool, limit: usize) {
+        let mut peers: Vec<_> = self
+            .records
+            .iter()
+            .filter(|(_, record)| record.banned == banned)
+            .map(|(peer_id, record)| (*peer_id, record.last_seen))
+            .collect();
+        let excess = peers.len().saturating_sub(limit);
+        if excess == 0 {
+            return
+        }
+
+        peers.sort_by_key(|(_, last_seen)| *last_seen);
+        for (peer_id, _) in peers.into_iter().take(excess) {
+            self.records.remove(&peer_id);
+        }
+    }
+}
+
+/// Removes the addresses seen the longest time ago, so at most
+/// [`MAX_ADDRESSES_PER_PEER`] addresses are kept. The addresses are ordered
+/// from the oldest to the most recent.
+fn evict_oldest_addresses(addresses: &mut Vec<Vec<u8>>) {
+    let excess = addresses.len().saturating_sub(MAX_ADDRESSES_PER_PEER);
+    addresses.drain(..excess);
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn address(port: u16) -> Multiaddr {
+        format!("/ip4/127.0.0.1/tcp/{port}").parse().unwrap()
+    }
+
+    #[test]
+    fn records_are_restored() {
+        let peer_id = PeerId::random();
+        let mut store = PeerStore::default();
+        store.update_addresses(&peer_id, &[address(4001)]);
+        store.update_score(&peer_id, 42.0);
+
+        let restored = PeerStore::from_records(store.records());
+
+        assert_eq!(restored.score(&peer_id), Some(42.0));
+        assert_eq!(
+            restored.peers_to_dial(10),
+            vec![(peer_id, vec![address(4001)])]
+        );
+    }
+
+    #[test]
+    fn banned_peers_are_not_dialed_and_always_kept() {
+        let banned_peer = PeerId::random();
+        let mut store = PeerStore::default();
+        store.update_addresses(&banned_peer, &[address(4001)]);
+        store.ban(&banned_peer, None);
+        for port in (0u16..).take(MAX_STORED_PEERS) {
+            store.update_addresses(&PeerId::random(), &[address(port)]);
+        }
+
+        let restored = PeerStore::from_records(store.records());
+
+        assert_eq!(
+            restored.banned_peers().collect::<Vec<_>>(),
+            vec![&banned_peer]
+        );
+        assert!(restored
+            .peers_to_dial(MAX_STORED_PEERS)
+            .iter()
+            .all(|(peer_id, _)| *peer_id != banned_peer));
+    }
+
+    #[test]
+    fn invalid_records_are_skipped() {
+        let record = PeerRecord {
+            addresses: vec![vec![0xff]],
+            ..Default::default()
+        };
+
+        let store = PeerStore::from_records(vec![(vec![1, 2, 3].into(), record)]);
+
+        assert!(store.records().is_empty());
+    }
+
+    #[test]
+    fn temporary_ban_expires() {
+        let permanently_banned = PeerId::random();
+        let temporarily_banned = PeerId::random();
+        let mut store = PeerStore::default();
+        store.ban(&permanently_banned, None);
+        store.ban(&temporarily_banned, Some(Duration::from_secs(0)));
+
+        assert_eq!(store.expired_bans(), vec![temporarily_banned]);
+
+        assert!(store.unban(&temporarily_banned));
+        assert!(store.expired_bans().is_empty());
+        assert_eq!(
+            store.banned_peers().collect::<Vec<_>>(),
+            vec![&permanently_banned]
+        );
+    }
+
+    #[test]
+    fn oldest_addresses_are_evicted() {
+        let peer_id = PeerId::random();
+        let mut store = PeerStore::default();
+        let addresses: Vec<_> =
+            (0u16..).take(MAX_ADDRESSES_PER_PEER).map(address).collect();
+        store.update_addresses(&peer_id, &addresses);
+
+        // The first address is seen again, so the second one is the oldest.
+        store.update_addresses(&peer_id, &[addresses[0].clone(), address(u16::MAX)]);
+
+        let (_, stored) = store.peers_to_dial(1).remove(0);
+        assert_eq!(stored.len(), MAX_ADDRESSES_PER_PEER);
+        assert!(!stored.contains(&addresses[1]));
+        assert_eq!(
+            stored[stored.len().saturating_sub(2)..],
+            [addresses[0].clone(), address(u16::MAX)]
+        );
+    }
+
+    #[test]
+    fn oldest_bans_are_evicted() {
+        let mut store = PeerStore::default();
+        let oldest_ban = PeerId::random();
+        store.ban(&oldest_ban, None);
+        store.records.get_mut(&oldest_ban).unwrap().last_seen = 0;
+
+        for _ in 0..MAX_BANNED_PEERS {
+            store.ban(&PeerId::random(), None);
+        }
+
+        assert_eq!(store.banned_peers().count(), MAX_BANNED_PEERS);
+        assert!(store.banned_peers().all(|peer_id| *peer_id != oldest_ban));
+    }
+
+    #[test]
+    fn oldest_peers_are_evicted() {
+        let mut store = PeerStore::default();
+        let banned_peer = PeerId::random();
+        store.ban(&banned_peer, None);
+        store.records.get_mut(&banned_peer).unwrap().last_seen = 0;
+        let oldest_peer = PeerId::random();
+        store.update_addresses(&oldest_peer, &[address(4001)]);
+        store.records.get_mut(&oldest_peer).unwrap().last_seen = 0;
+
+        for port in (0u16..).take(MAX_STORED_PEERS) {
+            store.update_addresses(&PeerId::random(), &[address(port)]);
+        }
+
+        assert_eq!(store.records.len(), MAX_STORED_PEERS.saturating_add(1));
+        assert!(!store.records.contains_key(&oldest_peer));
+        assert_eq!(store.banned_peers().collect::<Vec<_>>(), vec![&banned_peer]);
+    }
+}
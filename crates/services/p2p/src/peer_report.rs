@@ -38,7 +38,6 @@ use tokio::time::{
 };
 
 const HEALTH_CHECK_INTERVAL_IN_SECONDS: u64 = 10;
-const REPUTATION_DECAY_INTERVAL_IN_SECONDS: u64 = 1;
 
 /// Events emitted by PeerReportBehavior
 #[derive(Debug, Clone)]
@@ -65,15 +64,13 @@ pub struct PeerReportBehaviour {
 }
 
 impl PeerReportBehaviour {
-    pub(crate) fn new(_config: &Config) -> Self {
+    pub(crate) fn new(config: &Config) -> Self {
         Self {
             pending_events: VecDeque::default(),
             health_check: time::interval(Duration::from_secs(
                 HEALTH_CHECK_INTERVAL_IN_SECONDS,
             )),
-            decay_interval: time::interval(Duration::from_secs(
-                REPUTATION_DECAY_INTERVAL_IN_SECONDS,
-            )),
+            decay_interval: time::interval(config.reputation_config.decay_interval),
         }
     }
 }
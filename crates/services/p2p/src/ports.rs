@@ -5,10 +5,21 @@ use fuel_core_types::{
         SealedBlock,
         SealedBlockHeader,
     },
+    fuel_tx::{
+        Transaction,
+        TxId,
+    },
     fuel_types::BlockHeight,
-    services::p2p::Transactions,
+    services::p2p::{
+        PeerId,
+        PeerRecord,
+        Transactions,
+    },
+};
+use std::{
+    ops::Range,
+    sync::Arc,
 };
-use std::ops::Range;
 
 pub trait P2pDb: Send + Sync {
     fn get_sealed_block(
@@ -30,9 +41,35 @@ pub trait P2pDb: Send + Sync {
         &self,
         block_height_range: Range<u32>,
     ) -> StorageResult<Option<Vec<Transactions>>>;
+
+    /// Returns the transactions with the `tx_ids`, or `None` if any of them is unknown.
+    fn get_transactions_by_ids(
+        &self,
+        tx_ids: &[TxId],
+    ) -> StorageResult<Option<Vec<Transaction>>>;
+
+    /// Returns the peers persisted by the previous runs of the node.
+    fn get_peer_records(&self) -> StorageResult<Vec<(PeerId, PeerRecord)>>;
+
+    /// Returns the height below which the transactions of the blocks are pruned.
+    fn pruned_below(&self) -> StorageResult<Option<BlockHeight>>;
+
+    /// Persists the `records` of the known peers, replacing the previous ones.
+    fn store_peer_records(&self, records: Vec<(PeerId, PeerRecord)>)
+        -> StorageResult<()>;
+}
+
+pub trait TxPool: Send + Sync {
+    /// Returns the transactions with the `tx_ids` from the txpool,
+    /// `None` for the transactions that are not in the txpool.
+    fn find_transactions(&self, tx_ids: &[TxId]) -> Vec<Option<Transaction>>;
 }
 
 pub trait BlockHeightImporter: Send + Sync {
     /// Creates a stream of next block heights
     fn next_block_height(&self) -> BoxStream<BlockHeight>;
+
+    /// Creates a stream of blocks produced by the local node,
+    /// which are gossiped to the network.
+    fn local_blocks(&self) -> BoxStream<Arc<SealedBlock>>;
 }
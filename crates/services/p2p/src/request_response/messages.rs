@@ -3,6 +3,10 @@ use fuel_core_types::{
         SealedBlock,
         SealedBlockHeader,
     },
+    fuel_tx::{
+        Transaction,
+        TxId,
+    },
     fuel_types::BlockHeight,
     services::p2p::Transactions,
 };
@@ -15,7 +19,43 @@ use std::ops::Range;
 use thiserror::Error;
 use tokio::sync::oneshot;
 
-pub(crate) const REQUEST_RESPONSE_PROTOCOL_ID: &str = "/fuel/req_res/0.0.1";
+pub(crate) const V1_REQUEST_RESPONSE_PROTOCOL_ID: &str = "/fuel/req_res/0.0.1";
+pub(crate) const V2_REQUEST_RESPONSE_PROTOCOL_ID: &str = "/fuel/req_res/0.0.2";
+
+/// The versions of the request/response protocol.
+///
+/// Each version extends the previous one with new requests appended to the end of
+/// `RequestMessage` and `ResponseMessage`, so the encoding of the old requests stays the
+/// same. The node supports all versions side by side, and the peers negotiate the newest
+/// version supported by both of them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RequestResponseProtocol {
+    /// `Block`, `SealedHeaders` and `Transactions` requests.
+    V1,
+    /// Adds `TransactionsByIds` requests.
+    V2,
+}
+
+impl RequestResponseProtocol {
+    /// All supported versions, ordered by the preference during the negotiation.
+    pub const ALL: [Self; 2] = [Self::V2, Self::V1];
+
+    /// Returns the version identified by the `protocol_id`, if it is supported.
+    pub fn from_protocol_id(protocol_id: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|protocol| protocol.as_ref() == protocol_id)
+    }
+}
+
+impl AsRef<str> for RequestResponseProtocol {
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::V1 => V1_REQUEST_RESPONSE_PROTOCOL_ID,
+            Self::V2 => V2_REQUEST_RESPONSE_PROTOCOL_ID,
+        }
+    }
+}
 
 /// Max Size in Bytes of the Request Message
 #[cfg(test)]
@@ -26,6 +66,31 @@ pub enum RequestMessage {
     Block(BlockHeight),
     SealedHeaders(Range<u32>),
     Transactions(Range<u32>),
+    TransactionsByIds(Vec<TxId>),
+}
+
+impl RequestMessage {
+    /// Returns the first version of the protocol that supports the request.
+    pub fn min_protocol(&self) -> RequestResponseProtocol {
+        match self {
+            RequestMessage::Block(_)
+            | RequestMessage::SealedHeaders(_)
+            | RequestMessage::Transactions(_) => RequestResponseProtocol::V1,
+            RequestMessage::TransactionsByIds(_) => RequestResponseProtocol::V2,
+        }
+    }
+
+    /// Returns the response without the data, sent when the request is not served.
+    pub fn empty_response(&self) -> ResponseMessage {
+        match self {
+            RequestMessage::Block(_) => ResponseMessage::Block(None),
+            RequestMessage::SealedHeaders(_) => ResponseMessage::SealedHeaders(None),
+            RequestMessage::Transactions(_) => ResponseMessage::Transactions(None),
+            RequestMessage::TransactionsByIds(_) => {
+                ResponseMessage::TransactionsByIds(None)
+            }
+        }
+    }
 }
 
 /// Holds oneshot channels for specific responses
@@ -34,6 +99,7 @@ pub enum ResponseChannelItem {
     Block(oneshot::Sender<Option<SealedBlock>>),
     SealedHeaders(oneshot::Sender<(PeerId, Option<Vec<SealedBlockHeader>>)>),
     Transactions(oneshot::Sender<Option<Vec<Transactions>>>),
+    TransactionsByIds(oneshot::Sender<Option<Vec<Transaction>>>),
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -41,12 +107,27 @@ pub enum ResponseMessage {
     Block(Option<SealedBlock>),
     SealedHeaders(Option<Vec<SealedBlockHeader>>),
     Transactions(Option<Vec<Transactions>>),
+    TransactionsByIds(Option<Vec<Transaction>>),
+}
+
+impl ResponseMessage {
+    /// Returns the first version of the protocol that supports the response.
+    pub fn min_protocol(&self) -> RequestResponseProtocol {
+        match self {
+            ResponseMessage::Block(_)
+            | ResponseMessage::SealedHeaders(_)
+            | ResponseMessage::Transactions(_) => RequestResponseProtocol::V1,
+            ResponseMessage::TransactionsByIds(_) => RequestResponseProtocol::V2,
+        }
+    }
 }
 
 #[derive(Debug, Error)]
 pub enum RequestError {
     #[error("Not currently connected to any peers")]
     NoPeersConnected,
+    #[error("The peer doesn't support the protocol {0:?} required by the request")]
+    UnsupportedProtocol(RequestResponseProtocol),
 }
 
 /// Errors than can occur when attempting to send a response
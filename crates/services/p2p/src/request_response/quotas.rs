@@ -0,0 +1,295 @@
+use libp2p::PeerId;
+use std::{
+    collections::HashMap,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+/// The time after which the quotas of the inactive peer are forgotten,
+/// even if they are not refilled yet.
+const INACTIVE_PEER_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// The limit of `capacity` tokens refilled evenly over each `period`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Quota {
+    pub capacity: u64,
+    pub period: Duration,
+}
+
+impl Quota {
+    pub fn new(capacity: u64, period: Duration) -> Self {
+        Self { capacity, period }
+    }
+}
+
+/// The quota of the peer that was exceeded by the request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuotaExceeded {
+    /// The peer sent too many requests.
+    Requests,
+    /// The peer was served too many bytes.
+    Bytes,
+}
+
+impl QuotaExceeded {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            QuotaExceeded::Requests => "requests",
+            QuotaExceeded::Bytes => "bytes",
+        }
+    }
+}
+
+/// The number of tokens may become negative when more tokens are consumed
+/// than available. The debt has to be refilled before the bucket allows anything.
+#[derive(Debug, Clone)]
+struct TokenBucket {
+    tokens: i64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    fn full(quota: &Quota, now: Instant) -> Self {
+        Self {
+            tokens: to_tokens(quota.capacity),
+            last_refill: now,
+        }
+    }
+
+    fn consume(&mut self, tokens: u64) {
+        self.tokens = self.tokens.saturating_sub(to_tokens(tokens));
+    }
+
+    fn refill(&mut self, quota: &Quota, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.last_refill);
+        let refilled = elapsed
+            .as_nanos()
+            .saturating_mul(u128::from(quota.capacity))
+            .checked_div(quota.period.as_nanos())
+            .unwrap_or(u128::MAX);
+        // The time is not consumed until at least one token is refilled,
+        // so the frequent requests don't prevent the refill.
+        if refilled > 0 {
+            let refilled = i64::try_from(refilled).unwrap_or(i64::MAX);
+            self.tokens = self
+                .tokens
+                .saturating_add(refilled)
+                .min(to_tokens(quota.capacity));
+            self.last_refill = now;
+        }
+    }
+
+    fn is_full(&self, quota: &Quota) -> bool {
+        self.tokens >= to_tokens(quota.capacity)
+    }
+}
+
+fn to_tokens(value: u64) -> i64 {
+    i64::try_from(value).unwrap_or(i64::MAX)
+}
+
+#[derive(Debug, Clone)]
+struct PeerQuotas {
+    requests: TokenBucket,
+    bytes: TokenBucket,
+    last_active: Instant,
+}
+
+/// Tracks the per-peer quotas of the inbound requests, using the token bucket
+/// for the number of requests and the number of bytes served in the responses.
+#[derive(Debug, Clone)]
+pub struct RequestQuotas {
+    requests_quota: Quota,
+    bytes_quota: Quota,
+    peers: HashMap<PeerId, PeerQuotas>,
+}
+
+impl RequestQuotas {
+    pub fn new(requests_quota: Quota, bytes_quota: Quota) -> Self {
+        Self {
+            requests_quota,
+            bytes_quota,
+            peers: HashMap::new(),
+        }
+    }
+
+    /// Consumes the request from the quota of the peer. The request is refused
+    /// if the peer has sent too many requests or has been served too many bytes.
+    pub fn try_request(
+        &mut self,
+        peer_id: &PeerId,
+        now: Instant,
+    ) -> Result<(), QuotaExceeded> {
+        let (requests_quota, bytes_quota) = (self.requests_quota, self.bytes_quota);
+        let quotas = self.peer_quotas(peer_id, now);
+        quotas.requests.refill(&requests_quota, now);
+        quotas.bytes.refill(&bytes_quota, now);
+
+        if quotas.bytes.tokens <= 0 {
+            return Err(QuotaExceeded::Bytes)
+        }
+        if quotas.requests.tokens <= 0 {
+            return Err(QuotaExceeded::Requests)
+        }
+        quotas.requests.consume(1);
+        Ok(())
+    }
+
+    /// Consumes the `bytes` served to the peer from its quota.
+    /// The size of the response is known only after the request is accepted,
+    /// so the response larger than the rest of the quota puts it into debt.
+    /// The peer's requests are refused until the debt is refilled.
+    pub fn record_response(&mut self, peer_id: &PeerId, bytes: u64, now: Instant) {
+        let quotas = self.peer_quotas(peer_id, now);
+        quotas.bytes.consume(bytes);
+    }
+
+    /// Forgets the quotas refilled to the capacity, because they are the same as
+    /// the quotas of the new peer, and the quotas of the peers inactive for too long.
+    /// The quotas in debt are kept, so the peer can't reset them by reconnecting.
+    pub fn evict_refilled(&mut self, now: Instant) {
+        let (requests_quota, bytes_quota) = (self.requests_quota, self.bytes_quota);
+        self.peers.retain(|_, quotas| {
+            quotas.requests.refill(&requests_quota, now);
+            quotas.bytes.refill(&bytes_quota, now);
+            let is_full = quotas.requests.is_full(&requests_quota)
+                && quotas.bytes.is_full(&bytes_quota);
+            let is_inactive =
+                now.saturating_duration_since(quotas.last_active) >= INACTIVE_PEER_TTL;
+            !is_full && !is_inactive
+        });
+    }
+
+    fn peer_quotas(&mut self, peer_id: &PeerId, now: Instant) -> &mut PeerQuotas {
+        let (requests_quota, bytes_quota) = (&self.requests_quota, &self.bytes_quota);
+        let quotas = self.peers.entry(*peer_id).or_insert_with(|| PeerQuotas {
+            requests: TokenBucket::full(requests_quota, now),
+            bytes: TokenBucket::full(bytes_quota, now),
+            last_active: now,
+        });
+        quotas.last_active = now;
+        quotas
+    }
+}
+
+#[allow(clippy::arithmetic_side_effects)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn quotas() -> RequestQuotas {
+        RequestQuotas::new(
+            Quota::new(2, Duration::from_secs(1)),
+            Quota::new(100, Duration::from_secs(10)),
+        )
+    }
+
+    #[test]
+    fn requests_are_refused_until_the_quota_is_refilled() {
+        let mut quotas = quotas();
+        let peer_id = PeerId::random();
+        let now = Instant::now();
+
+        assert_eq!(quotas.try_request(&peer_id, now), Ok(()));
+        assert_eq!(quotas.try_request(&peer_id, now), Ok(()));
+        assert_eq!(
+            quotas.try_request(&peer_id, now),
+            Err(QuotaExceeded::Requests)
+        );
+
+        // other peers have their own quotas
+        assert_eq!(quotas.try_request(&PeerId::random(), now), Ok(()));
+
+        // one token is refilled in half of the period
+        let later = now + Duration::from_millis(500);
+        assert_eq!(quotas.try_request(&peer_id, later), Ok(()));
+        assert_eq!(
+            quotas.try_request(&peer_id, later),
+            Err(QuotaExceeded::Requests)
+        );
+    }
+
+    #[test]
+    fn requests_are_refused_when_too_many_bytes_are_served() {
+        let mut quotas = quotas();
+        let peer_id = PeerId::random();
+        let now = Instant::now();
+
+        assert_eq!(quotas.try_request(&peer_id, now), Ok(()));
+        quotas.record_response(&peer_id, 150, now);
+
+        // less than one byte is refilled
+        let later = now + Duration::from_millis(50);
+        assert_eq!(
+            quotas.try_request(&peer_id, later),
+            Err(QuotaExceeded::Bytes)
+        );
+
+        // the whole bytes quota is refilled after the period
+        let much_later = now + Duration::from_secs(10);
+        assert_eq!(quotas.try_request(&peer_id, much_later), Ok(()));
+    }
+
+    #[test]
+    fn large_responses_put_the_bytes_quota_into_debt() {
+        let mut quotas = quotas();
+        let peer_id = PeerId::random();
+        let now = Instant::now();
+
+        assert_eq!(quotas.try_request(&peer_id, now), Ok(()));
+        quotas.record_response(&peer_id, 1000, now);
+
+        // the refill of the whole quota doesn't cover the debt
+        let later = now + Duration::from_secs(10);
+        assert_eq!(
+            quotas.try_request(&peer_id, later),
+            Err(QuotaExceeded::Bytes)
+        );
+
+        // the debt of 900 bytes is refilled in 90 seconds
+        let much_later = now + Duration::from_secs(91);
+        assert_eq!(quotas.try_request(&peer_id, much_later), Ok(()));
+        quotas.record_response(&peer_id, 1000, much_later);
+        assert_eq!(
+            quotas.try_request(&peer_id, much_later + Duration::from_secs(1)),
+            Err(QuotaExceeded::Bytes)
+        );
+    }
+
+    #[test]
+    fn reconnecting_peer_keeps_the_quotas_until_they_are_refilled() {
+        let mut quotas = quotas();
+        let peer_id = PeerId::random();
+        let now = Instant::now();
+        assert_eq!(quotas.try_request(&peer_id, now), Ok(()));
+        quotas.record_response(&peer_id, 1000, now);
+
+        // the peer disconnects and reconnects with the debt
+        quotas.evict_refilled(now);
+        assert_eq!(
+            quotas.try_request(&peer_id, now + Duration::from_secs(1)),
+            Err(QuotaExceeded::Bytes)
+        );
+
+        // the quotas are forgotten once they are refilled
+        quotas.evict_refilled(now + Duration::from_secs(100));
+        assert!(quotas.peers.is_empty());
+    }
+
+    #[test]
+    fn quotas_of_inactive_peers_are_forgotten() {
+        let mut quotas = quotas();
+        let peer_id = PeerId::random();
+        let now = Instant::now();
+        assert_eq!(quotas.try_request(&peer_id, now), Ok(()));
+        quotas.record_response(&peer_id, u64::MAX, now);
+
+        quotas.evict_refilled(now + Duration::from_secs(60));
+        assert_eq!(quotas.peers.len(), 1);
+
+        quotas.evict_refilled(now + INACTIVE_PEER_TTL);
+        assert!(quotas.peers.is_empty());
+    }
+}
@@ -8,11 +8,13 @@ use crate::{
     p2p_service::{
         FuelP2PEvent,
         FuelP2PService,
+        PeerManagementRequest,
     },
     peer_manager::PeerInfo,
     ports::{
         BlockHeightImporter,
         P2pDb,
+        TxPool,
     },
     request_response::messages::{
         RequestMessage,
@@ -28,13 +30,16 @@ use fuel_core_services::{
     ServiceRunner,
     StateWatcher,
 };
+use fuel_core_storage::Result as StorageResult;
 use fuel_core_types::{
     blockchain::{
+        consensus::bft::ConsensusMessage,
         SealedBlock,
         SealedBlockHeader,
     },
     fuel_tx::{
         Transaction,
+        TxId,
         UniqueIdentifier,
     },
     fuel_types::{
@@ -46,11 +51,15 @@ use fuel_core_types::{
             AppScore,
             PeerReport,
         },
+        BlockGossipData,
         BlockHeightHeartbeatData,
+        CompactBlock,
+        ConsensusGossipData,
         GossipData,
         GossipsubMessageAcceptance,
         GossipsubMessageInfo,
         PeerId as FuelPeerId,
+        PeerRecord,
         TransactionGossipData,
         Transactions,
     },
@@ -82,11 +91,15 @@ use tokio::{
 };
 use tracing::warn;
 
-pub type Service<D> = ServiceRunner<Task<FuelP2PService, D, SharedState>>;
+pub type Service<D, T> = ServiceRunner<Task<FuelP2PService, D, SharedState, T>>;
+
+/// The interval between persisting the known peers into the database.
+const PEER_STORE_PERSIST_INTERVAL: Duration = Duration::from_secs(60);
 
 enum TaskRequest {
     // Broadcast requests to p2p network
     BroadcastTransaction(Arc<Transaction>),
+    BroadcastConsensusMessage(Arc<ConsensusMessage>),
     // Request to get one-off data from p2p network
     GetPeerIds(oneshot::Sender<Vec<PeerId>>),
     // Request to get information about all connected peers
@@ -99,13 +112,25 @@ enum TaskRequest {
     },
     GetSealedHeaders {
         block_height_range: Range<u32>,
+        from_peer: Option<PeerId>,
         channel: oneshot::Sender<(PeerId, Option<Vec<SealedBlockHeader>>)>,
     },
+    // Request to select the peers to sync the blocks from
+    SelectSyncPeers {
+        block_height_range: Range<u32>,
+        max_peers: usize,
+        channel: oneshot::Sender<Vec<PeerId>>,
+    },
     GetTransactions {
         block_height_range: Range<u32>,
         from_peer: PeerId,
         channel: oneshot::Sender<Option<Vec<Transactions>>>,
     },
+    GetTransactionsByIds {
+        tx_ids: Vec<TxId>,
+        from_peer: PeerId,
+        channel: oneshot::Sender<Option<Vec<Transaction>>>,
+    },
     // Responds back to the p2p network
     RespondWithGossipsubMessageReport((GossipsubMessageInfo, GossipsubMessageAcceptance)),
     RespondWithPeerReport {
@@ -113,6 +138,11 @@ enum TaskRequest {
         score: AppScore,
         reporting_service: &'static str,
     },
+    // Request of the node operator to manage peers
+    ManagePeers {
+        request: PeerManagementRequest,
+        channel: oneshot::Sender<anyhow::Result<()>>,
+    },
 }
 
 impl Debug for TaskRequest {
@@ -121,6 +151,9 @@ impl Debug for TaskRequest {
             TaskRequest::BroadcastTransaction(_) => {
                 write!(f, "TaskRequest::BroadcastTransaction")
             }
+            TaskRequest::BroadcastConsensusMessage(_) => {
+                write!(f, "TaskRequest::BroadcastConsensusMessage")
+            }
             TaskRequest::GetPeerIds(_) => {
                 write!(f, "TaskRequest::GetPeerIds")
             }
@@ -130,9 +163,15 @@ impl Debug for TaskRequest {
             TaskRequest::GetSealedHeaders { .. } => {
                 write!(f, "TaskRequest::GetSealedHeaders")
             }
+            TaskRequest::SelectSyncPeers { .. } => {
+                write!(f, "TaskRequest::SelectSyncPeers")
+            }
             TaskRequest::GetTransactions { .. } => {
                 write!(f, "TaskRequest::GetTransactions")
             }
+            TaskRequest::GetTransactionsByIds { .. } => {
+                write!(f, "TaskRequest::GetTransactionsByIds")
+            }
             TaskRequest::RespondWithGossipsubMessageReport(_) => {
                 write!(f, "TaskRequest::RespondWithGossipsubMessageReport")
             }
@@ -142,6 +181,9 @@ impl Debug for TaskRequest {
             TaskRequest::GetAllPeerInfo { .. } => {
                 write!(f, "TaskRequest::GetPeerInfo")
             }
+            TaskRequest::ManagePeers { .. } => {
+                write!(f, "TaskRequest::ManagePeers")
+            }
         }
     }
 }
@@ -155,7 +197,7 @@ pub enum HeartBeatPeerReportReason {
 pub trait TaskP2PService: Send {
     fn get_peer_ids(&self) -> Vec<PeerId>;
     fn get_all_peer_info(&self) -> Vec<(&PeerId, &PeerInfo)>;
-    fn get_peer_id_with_height(&self, height: &BlockHeight) -> Option<PeerId>;
+    fn get_peer_id_with_blocks(&self, block_height_range: &Range<u32>) -> Option<PeerId>;
 
     fn next_event(&mut self) -> BoxFuture<'_, Option<FuelP2PEvent>>;
 
@@ -191,6 +233,14 @@ pub trait TaskP2PService: Send {
     ) -> anyhow::Result<()>;
 
     fn update_block_height(&mut self, height: BlockHeight) -> anyhow::Result<()>;
+
+    fn update_lowest_block_height(&mut self, height: BlockHeight) -> anyhow::Result<()>;
+
+    fn peer_records(&mut self) -> Vec<(FuelPeerId, PeerRecord)>;
+
+    fn manage_peers(&mut self, request: PeerManagementRequest) -> anyhow::Result<()>;
+
+    fn unban_expired_peers(&mut self);
 }
 
 impl TaskP2PService for FuelP2PService {
@@ -202,8 +252,9 @@ impl TaskP2PService for FuelP2PService {
         self.peer_manager().get_all_peers().collect()
     }
 
-    fn get_peer_id_with_height(&self, height: &BlockHeight) -> Option<PeerId> {
-        self.peer_manager().get_peer_id_with_height(height)
+    fn get_peer_id_with_blocks(&self, block_height_range: &Range<u32>) -> Option<PeerId> {
+        self.peer_manager()
+            .get_peer_id_with_blocks(block_height_range)
     }
 
     fn next_event(&mut self) -> BoxFuture<'_, Option<FuelP2PEvent>> {
@@ -260,6 +311,23 @@ impl TaskP2PService for FuelP2PService {
         self.update_block_height(height);
         Ok(())
     }
+
+    fn update_lowest_block_height(&mut self, height: BlockHeight) -> anyhow::Result<()> {
+        self.update_lowest_block_height(height);
+        Ok(())
+    }
+
+    fn peer_records(&mut self) -> Vec<(FuelPeerId, PeerRecord)> {
+        self.peer_records()
+    }
+
+    fn manage_peers(&mut self, request: PeerManagementRequest) -> anyhow::Result<()> {
+        self.manage_peers(request)
+    }
+
+    fn unban_expired_peers(&mut self) {
+        self.unban_expired_peers()
+    }
 }
 
 pub trait Broadcast: Send {
@@ -276,6 +344,10 @@ pub trait Broadcast: Send {
     ) -> anyhow::Result<()>;
 
     fn tx_broadcast(&self, transaction: TransactionGossipData) -> anyhow::Result<()>;
+
+    fn consensus_broadcast(&self, message: ConsensusGossipData) -> anyhow::Result<()>;
+
+    fn block_broadcast(&self, block: BlockGossipData) -> anyhow::Result<()>;
 }
 
 impl Broadcast for SharedState {
@@ -300,25 +372,39 @@ impl Broadcast for SharedState {
         self.tx_broadcast.send(transaction)?;
         Ok(())
     }
+
+    fn consensus_broadcast(&self, message: ConsensusGossipData) -> anyhow::Result<()> {
+        self.consensus_broadcast.send(message)?;
+        Ok(())
+    }
+
+    fn block_broadcast(&self, block: BlockGossipData) -> anyhow::Result<()> {
+        self.block_broadcast.send(block)?;
+        Ok(())
+    }
 }
 
 /// Orchestrates various p2p-related events between the inner `P2pService`
 /// and the top level `NetworkService`.
-pub struct Task<P, D, B> {
+pub struct Task<P, D, B, T> {
     chain_id: ChainId,
     p2p_service: P,
     db: Arc<D>,
+    tx_pool: Arc<T>,
     next_block_height: BoxStream<BlockHeight>,
+    local_blocks: BoxStream<Arc<SealedBlock>>,
     /// Receive internal Task Requests
     request_receiver: mpsc::Receiver<TaskRequest>,
     broadcast: B,
     max_headers_per_request: u32,
+    max_txs_per_request: u32,
     // milliseconds wait time between peer heartbeat reputation checks
     heartbeat_check_interval: Duration,
     heartbeat_max_avg_interval: Duration,
     heartbeat_max_time_since_last: Duration,
     next_check_time: Instant,
     heartbeat_peer_reputation_config: HeartbeatPeerReputationConfig,
+    next_peer_store_persist_time: Instant,
 }
 
 #[derive(Clone)]
@@ -327,16 +413,18 @@ pub struct HeartbeatPeerReputationConfig {
     low_heartbeat_frequency_penalty: AppScore,
 }
 
-impl<D> Task<FuelP2PService, D, SharedState> {
+impl<D, T> Task<FuelP2PService, D, SharedState, T> {
     pub fn new<B: BlockHeightImporter>(
         chain_id: ChainId,
         config: Config,
         db: Arc<D>,
+        tx_pool: Arc<T>,
         block_importer: Arc<B>,
     ) -> Self {
         let Config {
             max_block_size,
             max_headers_per_request,
+            max_txs_per_request,
             heartbeat_check_interval,
             heartbeat_max_avg_interval,
             heartbeat_max_time_since_last,
@@ -344,16 +432,19 @@ impl<D> Task<FuelP2PService, D, SharedState> {
         } = config;
         let (request_sender, request_receiver) = mpsc::channel(1024 * 10);
         let (tx_broadcast, _) = broadcast::channel(1024 * 10);
+        let (consensus_broadcast, _) = broadcast::channel(1024 * 10);
+        let (block_broadcast, _) = broadcast::channel(1024 * 10);
         let (block_height_broadcast, _) = broadcast::channel(1024 * 10);
 
-        // Hardcoded for now, but left here to be configurable in the future.
-        // TODO: https://github.com/FuelLabs/fuel-core/issues/1340
         let heartbeat_peer_reputation_config = HeartbeatPeerReputationConfig {
-            old_heartbeat_penalty: -5.,
-            low_heartbeat_frequency_penalty: -5.,
+            old_heartbeat_penalty: config.reputation_config.old_heartbeat_penalty,
+            low_heartbeat_frequency_penalty: config
+                .reputation_config
+                .low_heartbeat_frequency_penalty,
         };
 
         let next_block_height = block_importer.next_block_height();
+        let local_blocks = block_importer.local_blocks();
         let p2p_service = FuelP2PService::new(config, PostcardCodec::new(max_block_size));
 
         let reserved_peers_broadcast =
@@ -363,29 +454,81 @@ impl<D> Task<FuelP2PService, D, SharedState> {
             Instant::now().checked_add(heartbeat_check_interval).expect(
                 "The heartbeat check interval should be small enough to do frequently",
             );
+        let next_peer_store_persist_time = Instant::now()
+            .checked_add(PEER_STORE_PERSIST_INTERVAL)
+            .expect("The persist interval is small enough");
 
         Self {
             chain_id,
             p2p_service,
             db,
+            tx_pool,
             request_receiver,
             next_block_height,
+            local_blocks,
             broadcast: SharedState {
                 request_sender,
                 tx_broadcast,
+                consensus_broadcast,
+                block_broadcast,
                 reserved_peers_broadcast,
                 block_height_broadcast,
             },
             max_headers_per_request,
+            max_txs_per_request,
             heartbeat_check_interval,
             heartbeat_max_avg_interval,
             heartbeat_max_time_since_last,
             next_check_time,
             heartbeat_peer_reputation_config,
+            next_peer_store_persist_time,
         }
     }
 }
-impl<P: TaskP2PService, D, B: Broadcast> Task<P, D, B> {
+impl<P: TaskP2PService, D: P2pDb, B: Broadcast, T: TxPool> Task<P, D, B, T> {
+    fn persist_peer_records(&mut self) {
+        let records = self.p2p_service.peer_records();
+        if let Err(e) = self.db.store_peer_records(records) {
+            tracing::error!("Failed to persist the known peers: {:?}", e);
+        }
+    }
+
+    /// Advertises the lowest height of the block served with the transactions,
+    /// so the peers don't request the pruned blocks from the node.
+    fn update_lowest_block_height(&mut self) -> anyhow::Result<()> {
+        let lowest_block_height = self.db.pruned_below()?.unwrap_or_default();
+        self.p2p_service
+            .update_lowest_block_height(lowest_block_height)
+    }
+
+    /// Returns the transactions with the `tx_ids`, or `None` if any of them is unknown.
+    /// The transactions of the gossiped block may be not committed yet, so they are
+    /// looked up in the txpool first, and only the rest of them in the database.
+    fn get_transactions_by_ids(
+        &self,
+        tx_ids: &[TxId],
+    ) -> StorageResult<Option<Vec<Transaction>>> {
+        let mut transactions = self.tx_pool.find_transactions(tx_ids);
+        let missing: Vec<_> = tx_ids
+            .iter()
+            .zip(&transactions)
+            .filter(|(_, tx)| tx.is_none())
+            .map(|(tx_id, _)| *tx_id)
+            .collect();
+
+        if !missing.is_empty() {
+            let mut fetched = match self.db.get_transactions_by_ids(&missing)? {
+                Some(fetched) => fetched.into_iter(),
+                None => return Ok(None),
+            };
+            for tx in transactions.iter_mut().filter(|tx| tx.is_none()) {
+                *tx = fetched.next();
+            }
+        }
+
+        Ok(transactions.into_iter().collect())
+    }
+
     fn peer_heartbeat_reputation_checks(&self) -> anyhow::Result<()> {
         for (peer_id, peer_info) in self.p2p_service.get_all_peer_info() {
             if peer_info.heartbeat_data.duration_since_last_heartbeat()
@@ -407,6 +550,35 @@ impl<P: TaskP2PService, D, B: Broadcast> Task<P, D, B> {
         Ok(())
     }
 
+    /// Selects up to `max_peers` peers that have the blocks with the transactions
+    /// in the `block_height_range` and pass the heartbeat checks, ordered from
+    /// the best reputation.
+    fn select_sync_peers(
+        &self,
+        block_height_range: &Range<u32>,
+        max_peers: usize,
+    ) -> Vec<PeerId> {
+        let mut peers = self
+            .p2p_service
+            .get_all_peer_info()
+            .into_iter()
+            .filter(|(_, peer_info)| {
+                let heartbeat = &peer_info.heartbeat_data;
+                heartbeat.has_blocks(block_height_range)
+                    && heartbeat.duration_since_last_heartbeat()
+                        <= self.heartbeat_max_time_since_last
+                    && heartbeat.average_time_between_heartbeats()
+                        <= self.heartbeat_max_avg_interval
+            })
+            .collect::<Vec<_>>();
+        peers.sort_by(|(_, a), (_, b)| b.score.total_cmp(&a.score));
+        peers
+            .into_iter()
+            .take(max_peers)
+            .map(|(peer_id, _)| *peer_id)
+            .collect()
+    }
+
     fn report_peer(
         &self,
         peer_id: FuelPeerId,
@@ -434,14 +606,16 @@ fn convert_peer_id(peer_id: &PeerId) -> anyhow::Result<FuelPeerId> {
 }
 
 #[async_trait::async_trait]
-impl<D> RunnableService for Task<FuelP2PService, D, SharedState>
+impl<D, T> RunnableService for Task<FuelP2PService, D, SharedState, T>
 where
     Self: RunnableTask,
+    D: P2pDb,
+    T: TxPool,
 {
     const NAME: &'static str = "P2P";
 
     type SharedData = SharedState;
-    type Task = Task<FuelP2PService, D, SharedState>;
+    type Task = Task<FuelP2PService, D, SharedState, T>;
     type TaskParams = ();
 
     fn shared_data(&self) -> Self::SharedData {
@@ -453,6 +627,9 @@ where
         _: &StateWatcher,
         _: Self::TaskParams,
     ) -> anyhow::Result<Self::Task> {
+        let peer_records = self.db.get_peer_records()?;
+        self.p2p_service.restore_peers(peer_records);
+        self.update_lowest_block_height()?;
         self.p2p_service.start().await?;
         Ok(self)
     }
@@ -460,11 +637,12 @@ where
 
 // TODO: Add tests https://github.com/FuelLabs/fuel-core/issues/1275
 #[async_trait::async_trait]
-impl<P, D, B> RunnableTask for Task<P, D, B>
+impl<P, D, B, T> RunnableTask for Task<P, D, B, T>
 where
     P: TaskP2PService + 'static,
     D: P2pDb + 'static,
     B: Broadcast + 'static,
+    T: TxPool + 'static,
 {
     async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
         tracing::debug!("P2P task is running");
@@ -488,6 +666,13 @@ where
                             tracing::error!("Got an error during transaction {} broadcasting {}", tx_id, e);
                         }
                     }
+                    Some(TaskRequest::BroadcastConsensusMessage(message)) => {
+                        let broadcast = GossipsubBroadcastRequest::Consensus(message);
+                        let result = self.p2p_service.publish_message(broadcast);
+                        if let Err(e) = result {
+                            tracing::debug!("Got an error during consensus message broadcasting {}", e);
+                        }
+                    }
                     Some(TaskRequest::GetPeerIds(channel)) => {
                         let peer_ids = self.p2p_service.get_peer_ids();
                         let _ = channel.send(peer_ids);
@@ -495,32 +680,46 @@ where
                     Some(TaskRequest::GetBlock { height, channel }) => {
                         let request_msg = RequestMessage::Block(height);
                         let channel_item = ResponseChannelItem::Block(channel);
-                        let peer = self.p2p_service.get_peer_id_with_height(&height);
+                        let block_height = u32::from(height);
+                        let block_height_range = block_height..block_height.saturating_add(1);
+                        let peer = self.p2p_service.get_peer_id_with_blocks(&block_height_range);
                         let found_peers = self.p2p_service.send_request_msg(peer, request_msg, channel_item).is_ok();
                         if !found_peers {
                             tracing::debug!("No peers found for block at height {:?}", height);
                         }
                     }
-                    Some(TaskRequest::GetSealedHeaders { block_height_range, channel: response}) => {
+                    Some(TaskRequest::GetSealedHeaders { block_height_range, from_peer, channel: response}) => {
                         let request_msg = RequestMessage::SealedHeaders(block_height_range.clone());
                         let channel_item = ResponseChannelItem::SealedHeaders(response);
 
                         // Note: this range has already been checked for
                         // validity in `SharedState::get_sealed_block_headers`.
-                        let block_height = BlockHeight::from(block_height_range.end.saturating_sub(1));
-                        let peer = self.p2p_service
-                             .get_peer_id_with_height(&block_height);
+                        // The transactions of the blocks are requested from the same peer,
+                        // so it should keep them for the whole range.
+                        let peer = from_peer.or_else(|| self.p2p_service
+                             .get_peer_id_with_blocks(&block_height_range));
                         let found_peers = self.p2p_service.send_request_msg(peer, request_msg, channel_item).is_ok();
                         if !found_peers {
-                            tracing::debug!("No peers found for block at height {:?}", block_height);
+                            tracing::debug!("No peers found for blocks in range {:?}", block_height_range);
                         }
                     }
+                    Some(TaskRequest::SelectSyncPeers { block_height_range, max_peers, channel }) => {
+                        let peers = self.select_sync_peers(&block_height_range, max_peers);
+                        let _ = channel.send(peers);
+                    }
                     Some(TaskRequest::GetTransactions { block_height_range, from_peer, channel }) => {
                         let request_msg = RequestMessage::Transactions(block_height_range);
                         let channel_item = ResponseChannelItem::Transactions(channel);
                         self.p2p_service.send_request_msg(Some(from_peer), request_msg, channel_item)
                             .expect("We always a peer here, so send has a target");
                     }
+                    Some(TaskRequest::GetTransactionsByIds { tx_ids, from_peer, channel }) => {
+                        let request_msg = RequestMessage::TransactionsByIds(tx_ids);
+                        let channel_item = ResponseChannelItem::TransactionsByIds(channel);
+                        if let Err(e) = self.p2p_service.send_request_msg(Some(from_peer), request_msg, channel_item) {
+                            tracing::debug!("Failed to request transactions by ids from {:?}: {:?}", from_peer, e);
+                        }
+                    }
                     Some(TaskRequest::RespondWithGossipsubMessageReport((message, acceptance))) => {
                         // report_message(&mut self.p2p_service, message, acceptance);
                         self.p2p_service.report_message(message, acceptance)?;
@@ -535,6 +734,10 @@ where
                             .collect::<Vec<_>>();
                         let _ = channel.send(peers);
                     }
+                    Some(TaskRequest::ManagePeers { request, channel }) => {
+                        tracing::info!("Managing peers by the request of the operator: {:?}", request);
+                        let _ = channel.send(self.p2p_service.manage_peers(request));
+                    }
                     None => {
                         unreachable!("The `Task` is holder of the `Sender`, so it should not be possible");
                     }
@@ -560,6 +763,14 @@ where
                                 let next_transaction = GossipData::new(transaction, peer_id, message_id);
                                 let _ = self.broadcast.tx_broadcast(next_transaction);
                             },
+                            GossipsubMessage::Consensus(consensus_message) => {
+                                let next_message = GossipData::new(consensus_message, peer_id, message_id);
+                                let _ = self.broadcast.consensus_broadcast(next_message);
+                            },
+                            GossipsubMessage::NewBlock(block) => {
+                                let next_block = GossipData::new(block, peer_id, message_id);
+                                let _ = self.broadcast.block_broadcast(next_block);
+                            },
                         }
                     },
                     Some(FuelP2PEvent::InboundRequestMessage { request_message, request_id }) => {
@@ -590,6 +801,26 @@ where
                                     }
                                 }
                             }
+                            RequestMessage::TransactionsByIds(tx_ids) => {
+                                let max_len = self.max_txs_per_request.try_into().expect("u32 should always fit into usize");
+                                if tx_ids.len() > max_len {
+                                    tracing::error!("Requested too many transactions by ids. Requested length: {:?}, Max length: {:?}", tx_ids.len(), max_len);
+                                    let response = None;
+                                    let _ = self.p2p_service.send_response_msg(request_id, ResponseMessage::TransactionsByIds(response));
+                                } else {
+                                    match self.get_transactions_by_ids(&tx_ids) {
+                                        Ok(response) => {
+                                            let _ = self.p2p_service.send_response_msg(request_id, ResponseMessage::TransactionsByIds(response));
+                                        },
+                                        Err(e) => {
+                                            tracing::error!("Failed to get transactions by ids: {:?}", e);
+                                            let response = None;
+                                            let _ = self.p2p_service.send_response_msg(request_id, ResponseMessage::TransactionsByIds(response));
+                                            return Err(e.into())
+                                        }
+                                    }
+                                }
+                            }
                             RequestMessage::SealedHeaders(range) => {
                                 let max_len = self.max_headers_per_request.try_into().expect("u32 should always fit into usize");
                                 if range.len() > max_len {
@@ -619,6 +850,7 @@ where
             },
             _  = tokio::time::sleep_until(self.next_check_time) => {
                 should_continue = true;
+                self.p2p_service.unban_expired_peers();
                 let res = self.peer_heartbeat_reputation_checks();
                 match res {
                     Ok(_) => tracing::debug!("Peer heartbeat reputation checks completed"),
@@ -628,9 +860,31 @@ where
                 }
                 self.next_check_time += self.heartbeat_check_interval;
             },
+            _ = tokio::time::sleep_until(self.next_peer_store_persist_time) => {
+                should_continue = true;
+                self.persist_peer_records();
+                self.next_peer_store_persist_time += PEER_STORE_PERSIST_INTERVAL;
+            },
             latest_block_height = self.next_block_height.next() => {
                 if let Some(latest_block_height) = latest_block_height {
                     let _ = self.p2p_service.update_block_height(latest_block_height);
+                    if let Err(e) = self.update_lowest_block_height() {
+                        tracing::error!("Failed to update the lowest block height: {:?}", e);
+                    }
+                    should_continue = true;
+                } else {
+                    should_continue = false;
+                }
+            }
+            local_block = self.local_blocks.next() => {
+                if let Some(block) = local_block {
+                    let height = *block.entity.header().height();
+                    let compact_block = CompactBlock::new(&block, &self.chain_id);
+                    let broadcast = GossipsubBroadcastRequest::NewBlock(Arc::new(compact_block));
+                    let result = self.p2p_service.publish_message(broadcast);
+                    if let Err(e) = result {
+                        tracing::debug!("Got an error during block {} broadcasting {}", height, e);
+                    }
                     should_continue = true;
                 } else {
                     should_continue = false;
@@ -642,9 +896,10 @@ where
         Ok(should_continue)
     }
 
-    async fn shutdown(self) -> anyhow::Result<()> {
-        // Nothing to shut down because we don't have any temporary state that should be dumped,
-        // and we don't spawn any sub-tasks that we need to finish or await.
+    async fn shutdown(mut self) -> anyhow::Result<()> {
+        // The known peers are persisted to reconnect to them after the restart.
+        // We don't spawn any sub-tasks that we need to finish or await.
+        self.persist_peer_records();
 
         // `FuelP2PService` doesn't support graceful shutdown(with informing of connected peers).
         // https://github.com/libp2p/specs/blob/master/ROADMAP.md#%EF%B8%8F-polite-peering
@@ -658,6 +913,10 @@ where
 pub struct SharedState {
     /// Sender of p2p transaction used for subscribing.
     tx_broadcast: broadcast::Sender<TransactionGossipData>,
+    /// Sender of p2p consensus messages used for subscribing.
+    consensus_broadcast: broadcast::Sender<ConsensusGossipData>,
+    /// Sender of p2p blocks used for subscribing.
+    block_broadcast: broadcast::Sender<BlockGossipData>,
     /// Sender of reserved peers connection updates.
     reserved_peers_broadcast: broadcast::Sender<usize>,
     /// Used for communicating with the `Task`.
@@ -680,6 +939,32 @@ impl SharedState {
         Ok(())
     }
 
+    pub fn notify_gossip_consensus_message_validity(
+        &self,
+        message_info: GossipsubMessageInfo,
+        acceptance: GossipsubMessageAcceptance,
+    ) -> anyhow::Result<()> {
+        self.request_sender
+            .try_send(TaskRequest::RespondWithGossipsubMessageReport((
+                message_info,
+                acceptance,
+            )))?;
+        Ok(())
+    }
+
+    pub fn notify_gossip_block_validity(
+        &self,
+        message_info: GossipsubMessageInfo,
+        acceptance: GossipsubMessageAcceptance,
+    ) -> anyhow::Result<()> {
+        self.request_sender
+            .try_send(TaskRequest::RespondWithGossipsubMessageReport((
+                message_info,
+                acceptance,
+            )))?;
+        Ok(())
+    }
+
     pub async fn get_block(
         &self,
         height: BlockHeight,
@@ -711,6 +996,7 @@ impl SharedState {
         self.request_sender
             .send(TaskRequest::GetSealedHeaders {
                 block_height_range,
+                from_peer: None,
                 channel: sender,
             })
             .await?;
@@ -721,6 +1007,57 @@ impl SharedState {
             .map_err(|e| anyhow!("{}", e))
     }
 
+    pub async fn get_sealed_block_headers_from_peer(
+        &self,
+        peer_id: Vec<u8>,
+        block_height_range: Range<u32>,
+    ) -> anyhow::Result<Option<Vec<SealedBlockHeader>>> {
+        let (sender, receiver) = oneshot::channel();
+        let from_peer = PeerId::from_bytes(&peer_id).expect("Valid PeerId");
+
+        if block_height_range.is_empty() {
+            return Err(anyhow!(
+                "Cannot retrieve headers for an empty range of block heights"
+            ))
+        }
+
+        self.request_sender
+            .send(TaskRequest::GetSealedHeaders {
+                block_height_range,
+                from_peer: Some(from_peer),
+                channel: sender,
+            })
+            .await?;
+
+        receiver
+            .await
+            .map(|(_, headers)| headers)
+            .map_err(|e| anyhow!("{}", e))
+    }
+
+    /// Returns the peers to sync the blocks in the `block_height_range` from,
+    /// ordered from the best reputation.
+    pub async fn select_sync_peers(
+        &self,
+        block_height_range: Range<u32>,
+        max_peers: usize,
+    ) -> anyhow::Result<Vec<Vec<u8>>> {
+        let (sender, receiver) = oneshot::channel();
+
+        self.request_sender
+            .send(TaskRequest::SelectSyncPeers {
+                block_height_range,
+                max_peers,
+                channel: sender,
+            })
+            .await?;
+
+        receiver
+            .await
+            .map(|peers| peers.into_iter().map(PeerId::to_bytes).collect())
+            .map_err(|e| anyhow!("{}", e))
+    }
+
     pub async fn get_transactions_from_peer(
         &self,
         peer_id: Vec<u8>,
@@ -739,6 +1076,24 @@ impl SharedState {
         receiver.await.map_err(|e| anyhow!("{}", e))
     }
 
+    pub async fn get_transactions_by_ids_from_peer(
+        &self,
+        peer_id: Vec<u8>,
+        tx_ids: Vec<TxId>,
+    ) -> anyhow::Result<Option<Vec<Transaction>>> {
+        let (sender, receiver) = oneshot::channel();
+        let from_peer = PeerId::from_bytes(&peer_id).expect("Valid PeerId");
+
+        let request = TaskRequest::GetTransactionsByIds {
+            tx_ids,
+            from_peer,
+            channel: sender,
+        };
+        self.request_sender.send(request).await?;
+
+        receiver.await.map_err(|e| anyhow!("{}", e))
+    }
+
     pub fn broadcast_transaction(
         &self,
         transaction: Arc<Transaction>,
@@ -748,6 +1103,15 @@ impl SharedState {
         Ok(())
     }
 
+    pub fn broadcast_consensus_message(
+        &self,
+        message: Arc<ConsensusMessage>,
+    ) -> anyhow::Result<()> {
+        self.request_sender
+            .try_send(TaskRequest::BroadcastConsensusMessage(message))?;
+        Ok(())
+    }
+
     pub async fn get_peer_ids(&self) -> anyhow::Result<Vec<PeerId>> {
         let (sender, receiver) = oneshot::channel();
 
@@ -768,10 +1132,37 @@ impl SharedState {
         receiver.await.map_err(|e| anyhow!("{}", e))
     }
 
+    /// Sends the request of the node operator to manage the peers.
+    pub async fn manage_peers(
+        &self,
+        request: PeerManagementRequest,
+    ) -> anyhow::Result<()> {
+        let (sender, receiver) = oneshot::channel();
+
+        self.request_sender
+            .send(TaskRequest::ManagePeers {
+                request,
+                channel: sender,
+            })
+            .await?;
+
+        receiver.await.map_err(|e| anyhow!("{}", e))?
+    }
+
     pub fn subscribe_tx(&self) -> broadcast::Receiver<TransactionGossipData> {
         self.tx_broadcast.subscribe()
     }
 
+    pub fn subscribe_consensus_messages(
+        &self,
+    ) -> broadcast::Receiver<ConsensusGossipData> {
+        self.consensus_broadcast.subscribe()
+    }
+
+    pub fn subscribe_blocks(&self) -> broadcast::Receiver<BlockGossipData> {
+        self.block_broadcast.subscribe()
+    }
+
     pub fn subscribe_block_height(
         &self,
     ) -> broadcast::Receiver<BlockHeightHeartbeatData> {
@@ -809,17 +1200,25 @@ impl SharedState {
     }
 }
 
-pub fn new_service<D, B>(
+pub fn new_service<D, T, B>(
     chain_id: ChainId,
     p2p_config: Config,
     db: D,
+    tx_pool: T,
     block_importer: B,
-) -> Service<D>
+) -> Service<D, T>
 where
     D: P2pDb + 'static,
+    T: TxPool + 'static,
     B: BlockHeightImporter,
 {
-    let task = Task::new(chain_id, p2p_config, Arc::new(db), Arc::new(block_importer));
+    let task = Task::new(
+        chain_id,
+        p2p_config,
+        Arc::new(db),
+        Arc::new(tx_pool),
+        Arc::new(block_importer),
+    );
     Service::new(task)
 }
 
@@ -866,7 +1265,6 @@ pub mod tests {
         Service,
         State,
     };
-    use fuel_core_storage::Result as StorageResult;
     use fuel_core_types::fuel_types::BlockHeight;
     use futures::FutureExt;
     use std::{
@@ -905,6 +1303,47 @@ pub mod tests {
         ) -> StorageResult<Option<Vec<Transactions>>> {
             unimplemented!()
         }
+
+        fn get_transactions_by_ids(
+            &self,
+            _tx_ids: &[TxId],
+        ) -> StorageResult<Option<Vec<Transaction>>> {
+            unimplemented!()
+        }
+
+        fn get_peer_records(&self) -> StorageResult<Vec<(FuelPeerId, PeerRecord)>> {
+            Ok(vec![])
+        }
+
+        fn pruned_below(&self) -> StorageResult<Option<BlockHeight>> {
+            Ok(None)
+        }
+
+        fn store_peer_records(
+            &self,
+            _records: Vec<(FuelPeerId, PeerRecord)>,
+        ) -> StorageResult<()> {
+            Ok(())
+        }
+    }
+
+    #[derive(Clone, Debug, Default)]
+    struct FakeTxPool {
+        transactions: Vec<Transaction>,
+    }
+
+    impl TxPool for FakeTxPool {
+        fn find_transactions(&self, tx_ids: &[TxId]) -> Vec<Option<Transaction>> {
+            tx_ids
+                .iter()
+                .map(|tx_id| {
+                    self.transactions
+                        .iter()
+                        .find(|tx| tx.id(&ChainId::default()) == *tx_id)
+                        .cloned()
+                })
+                .collect()
+        }
     }
 
     #[derive(Clone, Debug)]
@@ -914,13 +1353,22 @@ pub mod tests {
         fn next_block_height(&self) -> BoxStream<BlockHeight> {
             Box::pin(fuel_core_services::stream::pending())
         }
+
+        fn local_blocks(&self) -> BoxStream<Arc<SealedBlock>> {
+            Box::pin(fuel_core_services::stream::pending())
+        }
     }
 
     #[tokio::test]
     async fn start_and_stop_awaits_works() {
         let p2p_config = Config::default_initialized("start_stop_works");
-        let service =
-            new_service(ChainId::default(), p2p_config, FakeDb, FakeBlockImporter);
+        let service = new_service(
+            ChainId::default(),
+            p2p_config,
+            FakeDb,
+            FakeTxPool::default(),
+            FakeBlockImporter,
+        );
 
         // Node with p2p service started
         assert!(service.start_and_await().await.unwrap().started());
@@ -944,7 +1392,10 @@ pub mod tests {
                 .collect()
         }
 
-        fn get_peer_id_with_height(&self, _height: &BlockHeight) -> Option<PeerId> {
+        fn get_peer_id_with_blocks(
+            &self,
+            _block_height_range: &Range<u32>,
+        ) -> Option<PeerId> {
             todo!()
         }
 
@@ -996,6 +1447,26 @@ pub mod tests {
         fn update_block_height(&mut self, _height: BlockHeight) -> anyhow::Result<()> {
             todo!()
         }
+
+        fn update_lowest_block_height(
+            &mut self,
+            _height: BlockHeight,
+        ) -> anyhow::Result<()> {
+            todo!()
+        }
+
+        fn peer_records(&mut self) -> Vec<(FuelPeerId, PeerRecord)> {
+            vec![]
+        }
+
+        fn manage_peers(
+            &mut self,
+            _request: PeerManagementRequest,
+        ) -> anyhow::Result<()> {
+            todo!()
+        }
+
+        fn unban_expired_peers(&mut self) {}
     }
 
     struct FakeDB;
@@ -1028,6 +1499,28 @@ pub mod tests {
         ) -> StorageResult<Option<Vec<Transactions>>> {
             todo!()
         }
+
+        fn get_transactions_by_ids(
+            &self,
+            _tx_ids: &[TxId],
+        ) -> StorageResult<Option<Vec<Transaction>>> {
+            Ok(None)
+        }
+
+        fn get_peer_records(&self) -> StorageResult<Vec<(FuelPeerId, PeerRecord)>> {
+            Ok(vec![])
+        }
+
+        fn pruned_below(&self) -> StorageResult<Option<BlockHeight>> {
+            Ok(None)
+        }
+
+        fn store_peer_records(
+            &self,
+            _records: Vec<(FuelPeerId, PeerRecord)>,
+        ) -> StorageResult<()> {
+            Ok(())
+        }
     }
 
     struct FakeBroadcast {
@@ -1062,6 +1555,17 @@ pub mod tests {
         ) -> anyhow::Result<()> {
             todo!()
         }
+
+        fn consensus_broadcast(
+            &self,
+            _message: ConsensusGossipData,
+        ) -> anyhow::Result<()> {
+            todo!()
+        }
+
+        fn block_broadcast(&self, _block: BlockGossipData) -> anyhow::Result<()> {
+            todo!()
+        }
     }
 
     #[tokio::test]
@@ -1075,6 +1579,7 @@ pub mod tests {
 
         let heartbeat_data = HeartbeatData {
             block_height: None,
+            lowest_block_height: None,
             last_heartbeat: Instant::now(),
             last_heartbeat_sys: SystemTime::now(),
             window: 0,
@@ -1083,6 +1588,7 @@ pub mod tests {
         let peer_info = PeerInfo {
             peer_addresses: Default::default(),
             client_version: None,
+            req_res_protocols: vec![],
             heartbeat_data,
             score: 100.0,
         };
@@ -1110,15 +1616,19 @@ pub mod tests {
             chain_id: Default::default(),
             p2p_service,
             db: Arc::new(FakeDB),
+            tx_pool: Arc::new(FakeTxPool::default()),
             next_block_height: FakeBlockImporter.next_block_height(),
+            local_blocks: FakeBlockImporter.local_blocks(),
             request_receiver,
             broadcast,
             max_headers_per_request: 0,
+            max_txs_per_request: 0,
             heartbeat_check_interval: Duration::from_secs(0),
             heartbeat_max_avg_interval,
             heartbeat_max_time_since_last,
             next_check_time: Instant::now(),
             heartbeat_peer_reputation_config: heartbeat_peer_reputation_config.clone(),
+            next_peer_store_persist_time: Instant::now() + PEER_STORE_PERSIST_INTERVAL,
         };
         let (watch_sender, watch_receiver) = tokio::sync::watch::channel(State::Started);
         let mut watcher = StateWatcher::from(watch_receiver);
@@ -1156,6 +1666,7 @@ pub mod tests {
 
         let heartbeat_data = HeartbeatData {
             block_height: None,
+            lowest_block_height: None,
             last_heartbeat,
             last_heartbeat_sys,
             window: 0,
@@ -1164,6 +1675,7 @@ pub mod tests {
         let peer_info = PeerInfo {
             peer_addresses: Default::default(),
             client_version: None,
+            req_res_protocols: vec![],
             heartbeat_data,
             score: 100.0,
         };
@@ -1191,15 +1703,19 @@ pub mod tests {
             chain_id: Default::default(),
             p2p_service,
             db: Arc::new(FakeDB),
+            tx_pool: Arc::new(FakeTxPool::default()),
             next_block_height: FakeBlockImporter.next_block_height(),
+            local_blocks: FakeBlockImporter.local_blocks(),
             request_receiver,
             broadcast,
             max_headers_per_request: 0,
+            max_txs_per_request: 0,
             heartbeat_check_interval: Duration::from_secs(0),
             heartbeat_max_avg_interval,
             heartbeat_max_time_since_last,
             next_check_time: Instant::now(),
             heartbeat_peer_reputation_config: heartbeat_peer_reputation_config.clone(),
+            next_peer_store_persist_time: Instant::now() + PEER_STORE_PERSIST_INTERVAL,
         };
         let (watch_sender, watch_receiver) = tokio::sync::watch::channel(State::Started);
         let mut watcher = StateWatcher::from(watch_receiver);
@@ -1223,4 +1739,189 @@ pub mod tests {
         );
         assert_eq!(reporting_service, "p2p");
     }
+
+    fn peer_info_with_heartbeat(
+        block_height: u32,
+        since_last_heartbeat: Duration,
+        score: AppScore,
+    ) -> PeerInfo {
+        let mut durations = VecDeque::new();
+        durations.push_front(Duration::from_secs(5));
+        let heartbeat_data = HeartbeatData {
+            block_height: Some(block_height.into()),
+            lowest_block_height: None,
+            last_heartbeat: Instant::now() - since_last_heartbeat,
+            last_heartbeat_sys: SystemTime::now() - since_last_heartbeat,
+            window: 0,
+            durations,
+        };
+        PeerInfo {
+            peer_addresses: Default::default(),
+            client_version: None,
+            req_res_protocols: vec![],
+            heartbeat_data,
+            score,
+        }
+    }
+
+    #[tokio::test]
+    async fn select_sync_peers__returns_peers_with_good_heartbeats_ordered_by_score() {
+        // given
+        let good_peer = PeerId::random();
+        let best_peer = PeerId::random();
+        let old_heartbeat_peer = PeerId::random();
+        let behind_peer = PeerId::random();
+        let peer_info = vec![
+            (
+                good_peer,
+                peer_info_with_heartbeat(10, Duration::from_secs(1), 50.0),
+            ),
+            (
+                best_peer,
+                peer_info_with_heartbeat(12, Duration::from_secs(1), 80.0),
+            ),
+            (
+                old_heartbeat_peer,
+                peer_info_with_heartbeat(10, Duration::from_secs(50), 100.0),
+            ),
+            (
+                behind_peer,
+                peer_info_with_heartbeat(5, Duration::from_secs(1), 100.0),
+            ),
+        ];
+        let p2p_service = FakeP2PService { peer_info };
+        let (_request_sender, request_receiver) = mpsc::channel(100);
+        let (report_sender, _report_receiver) = mpsc::channel(100);
+        let broadcast = FakeBroadcast {
+            peer_reports: report_sender,
+        };
+        let task = Task {
+            chain_id: Default::default(),
+            p2p_service,
+            db: Arc::new(FakeDB),
+            tx_pool: Arc::new(FakeTxPool::default()),
+            next_block_height: FakeBlockImporter.next_block_height(),
+            local_blocks: FakeBlockImporter.local_blocks(),
+            request_receiver,
+            broadcast,
+            max_headers_per_request: 0,
+            max_txs_per_request: 0,
+            heartbeat_check_interval: Duration::from_secs(0),
+            heartbeat_max_avg_interval: Duration::from_secs(20),
+            heartbeat_max_time_since_last: Duration::from_secs(40),
+            next_check_time: Instant::now(),
+            heartbeat_peer_reputation_config: HeartbeatPeerReputationConfig {
+                old_heartbeat_penalty: -5.0,
+                low_heartbeat_frequency_penalty: -5.0,
+            },
+            next_peer_store_persist_time: Instant::now() + PEER_STORE_PERSIST_INTERVAL,
+        };
+
+        // when
+        let all_peers = task.select_sync_peers(&(10..11), 10);
+        let limited_peers = task.select_sync_peers(&(10..11), 1);
+
+        // then
+        assert_eq!(all_peers, vec![best_peer, good_peer]);
+        assert_eq!(limited_peers, vec![best_peer]);
+    }
+
+    #[tokio::test]
+    async fn select_sync_peers__skips_peers_with_pruned_transactions_of_the_range() {
+        // given
+        let full_peer = PeerId::random();
+        let pruning_peer = PeerId::random();
+        let mut pruning_peer_info =
+            peer_info_with_heartbeat(20, Duration::from_secs(1), 100.0);
+        pruning_peer_info.heartbeat_data.lowest_block_height = Some(15.into());
+        let peer_info = vec![
+            (
+                full_peer,
+                peer_info_with_heartbeat(20, Duration::from_secs(1), 50.0),
+            ),
+            (pruning_peer, pruning_peer_info),
+        ];
+        let p2p_service = FakeP2PService { peer_info };
+        let (_request_sender, request_receiver) = mpsc::channel(100);
+        let (report_sender, _report_receiver) = mpsc::channel(100);
+        let broadcast = FakeBroadcast {
+            peer_reports: report_sender,
+        };
+        let task = Task {
+            chain_id: Default::default(),
+            p2p_service,
+            db: Arc::new(FakeDB),
+            tx_pool: Arc::new(FakeTxPool::default()),
+            next_block_height: FakeBlockImporter.next_block_height(),
+            local_blocks: FakeBlockImporter.local_blocks(),
+            request_receiver,
+            broadcast,
+            max_headers_per_request: 0,
+            max_txs_per_request: 0,
+            heartbeat_check_interval: Duration::from_secs(0),
+            heartbeat_max_avg_interval: Duration::from_secs(20),
+            heartbeat_max_time_since_last: Duration::from_secs(40),
+            next_check_time: Instant::now(),
+            heartbeat_peer_reputation_config: HeartbeatPeerReputationConfig {
+                old_heartbeat_penalty: -5.0,
+                low_heartbeat_frequency_penalty: -5.0,
+            },
+            next_peer_store_persist_time: Instant::now() + PEER_STORE_PERSIST_INTERVAL,
+        };
+
+        // when
+        let old_range_peers = task.select_sync_peers(&(10..20), 10);
+        let new_range_peers = task.select_sync_peers(&(15..20), 10);
+
+        // then
+        assert_eq!(old_range_peers, vec![full_peer]);
+        assert_eq!(new_range_peers, vec![pruning_peer, full_peer]);
+    }
+
+    #[tokio::test]
+    async fn get_transactions_by_ids__looks_in_txpool_before_db() {
+        // given
+        let pooled_tx = Transaction::default_test_tx();
+        let pooled_tx_id = pooled_tx.id(&ChainId::default());
+        let unknown_tx_id = TxId::from([1; 32]);
+        let p2p_service = FakeP2PService { peer_info: vec![] };
+        let (_request_sender, request_receiver) = mpsc::channel(100);
+        let (report_sender, _report_receiver) = mpsc::channel(100);
+        let broadcast = FakeBroadcast {
+            peer_reports: report_sender,
+        };
+        let task = Task {
+            chain_id: Default::default(),
+            p2p_service,
+            db: Arc::new(FakeDB),
+            tx_pool: Arc::new(FakeTxPool {
+                transactions: vec![pooled_tx.clone()],
+            }),
+            next_block_height: FakeBlockImporter.next_block_height(),
+            local_blocks: FakeBlockImporter.local_blocks(),
+            request_receiver,
+            broadcast,
+            max_headers_per_request: 0,
+            max_txs_per_request: 0,
+            heartbeat_check_interval: Duration::from_secs(0),
+            heartbeat_max_avg_interval: Duration::from_secs(20),
+            heartbeat_max_time_since_last: Duration::from_secs(40),
+            next_check_time: Instant::now(),
+            heartbeat_peer_reputation_config: HeartbeatPeerReputationConfig {
+                old_heartbeat_penalty: -5.0,
+                low_heartbeat_frequency_penalty: -5.0,
+            },
+            next_peer_store_persist_time: Instant::now() + PEER_STORE_PERSIST_INTERVAL,
+        };
+
+        // when
+        let pooled = task.get_transactions_by_ids(&[pooled_tx_id]).unwrap();
+        let unknown = task
+            .get_transactions_by_ids(&[pooled_tx_id, unknown_tx_id])
+            .unwrap();
+
+        // then
+        assert_eq!(pooled, Some(vec![pooled_tx]));
+        assert_eq!(unknown, None);
+    }
 }
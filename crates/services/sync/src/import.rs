@@ -65,6 +65,9 @@ pub struct Config {
     pub block_stream_buffer_size: usize,
     /// The maximum number of headers to request in a single batch.
     pub header_batch_size: usize,
+    /// The maximum number of peers to download the batches from in parallel.
+    /// The batches are downloaded from a single peer at a time if it is `1`.
+    pub max_parallel_peers: usize,
 }
 
 impl Default for Config {
@@ -72,6 +75,7 @@ impl Default for Config {
         Self {
             block_stream_buffer_size: 10,
             header_batch_size: 100,
+            max_parallel_peers: 1,
         }
     }
 }
@@ -208,8 +212,22 @@ where
         let (shutdown_guard, mut shutdown_guard_recv) =
             tokio::sync::mpsc::channel::<()>(1);
 
-        let block_stream =
-            get_block_stream(range.clone(), params, p2p.clone(), consensus.clone());
+        let peers = self.select_peers(&range).await;
+        let block_stream = if peers.is_empty() {
+            get_block_stream(range.clone(), params, p2p.clone(), consensus.clone())
+                .map(FutureExt::boxed)
+                .left_stream()
+        } else {
+            get_parallel_block_stream(
+                range.clone(),
+                params,
+                peers,
+                p2p.clone(),
+                consensus.clone(),
+            )
+            .map(FutureExt::boxed)
+            .right_stream()
+        };
         let result = block_stream
             .map(move |stream_block_batch| {
                 let shutdown_guard = shutdown_guard.clone();
@@ -293,6 +311,21 @@ where
         let _ = shutdown_guard_recv.recv().await;
         result
     }
+
+    /// Selects the peers to download the blocks in the `range` from in parallel.
+    /// Returns no peers if the parallel download is disabled or
+    /// there are no suitable peers, so the batches are requested one by one.
+    async fn select_peers(&self, range: &RangeInclusive<u32>) -> Vec<PeerId> {
+        if self.params.max_parallel_peers <= 1 {
+            return vec![]
+        }
+        let block_height_range = *range.start()..range.end().saturating_add(1);
+        self.p2p
+            .select_peers(block_height_range, self.params.max_parallel_peers)
+            .await
+            .trace_err("Failed to select peers for the parallel download")
+            .unwrap_or_default()
+    }
 }
 
 fn get_block_stream<
@@ -352,6 +385,101 @@ fn get_block_stream<
         })
 }
 
+/// Splits the range into batches and assigns them to the `peers` in turn,
+/// so the batches are downloaded from several peers in parallel.
+/// The batch that failed to be downloaded is retried on the other peers.
+fn get_parallel_block_stream<
+    P: PeerToPeerPort + Send + Sync + 'static,
+    C: ConsensusPort + Send + Sync + 'static,
+>(
+    range: RangeInclusive<u32>,
+    params: &Config,
+    peers: Vec<PeerId>,
+    p2p: Arc<P>,
+    consensus: Arc<C>,
+) -> impl Stream<Item = impl Future<Output = SealedBlockBatch>> {
+    let peers = Arc::new(peers);
+    let ranges = range_chunks(range, params.header_batch_size);
+    let assigned_peers = peers.as_ref().clone().into_iter().cycle();
+    futures::stream::iter(ranges.zip(assigned_peers)).map(move |(range, peer)| {
+        let peers = peers.clone();
+        let p2p = p2p.clone();
+        let consensus = consensus.clone();
+        async move {
+            let mut batch =
+                get_block_batch_from_peer(peer.clone(), range.clone(), &p2p, &consensus)
+                    .await;
+            // Peers are ordered from the best, so the retries go to the best peers first.
+            for other_peer in peers.iter().filter(|other_peer| **other_peer != peer) {
+                if !batch.is_err() {
+                    break
+                }
+                tracing::debug!(
+                    "Retrying the range {:?} failed by the peer {:?} on the peer {:?}",
+                    batch.range,
+                    batch.peer,
+                    other_peer
+                );
+                let retry = get_block_batch_from_peer(
+                    other_peer.clone(),
+                    range.clone(),
+                    &p2p,
+                    &consensus,
+                )
+                .await;
+                if retry.results.len() >= batch.results.len() {
+                    batch = retry;
+                }
+            }
+            batch
+        }
+        .instrument(tracing::debug_span!("parallel_download"))
+        .in_current_span()
+    })
+}
+
+/// Downloads the headers and the transactions of the range from the `peer`.
+/// The batch is cut at the first header that doesn't continue the range or
+/// doesn't pass the consensus check, so only a continuous prefix is imported.
+async fn get_block_batch_from_peer<
+    P: PeerToPeerPort + Send + Sync + 'static,
+    C: ConsensusPort + Send + Sync + 'static,
+>(
+    peer: PeerId,
+    range: Range<u32>,
+    p2p: &Arc<P>,
+    consensus: &Arc<C>,
+) -> SealedBlockBatch {
+    tracing::debug!(
+        "getting header range from {} to {} inclusive from peer {:?}",
+        range.start,
+        range.end,
+        peer
+    );
+    let headers = p2p
+        .get_sealed_block_headers_from_peer(peer.clone().bind(range.clone()))
+        .await
+        .trace_err("Failed to get headers")
+        .unwrap_or_default()
+        .unwrap_or_default();
+    let Batch {
+        peer,
+        range,
+        results,
+    } = check_header_heights(peer, range, headers, p2p);
+    let checked_headers = results
+        .into_iter()
+        .take_while(|header| check_sealed_header(header, peer.clone(), p2p, consensus))
+        .collect::<Vec<_>>();
+
+    match checked_headers.last() {
+        Some(last) => await_da_height(last, consensus).await,
+        None => return SealedBlockBatch::new(peer, range, vec![]),
+    }
+    let headers = SealedHeaderBatch::new(peer, range, checked_headers);
+    get_blocks(p2p, headers).await
+}
+
 fn get_header_batch_stream<P: PeerToPeerPort + Send + Sync + 'static>(
     range: RangeInclusive<u32>,
     params: &Config,
@@ -482,6 +610,19 @@ where
         peer_id,
         data: headers,
     } = sourced_headers;
+    check_header_heights(peer_id, range, headers, p2p)
+}
+
+/// Takes the headers while they follow the heights of the range one by one.
+fn check_header_heights<P>(
+    peer_id: PeerId,
+    range: Range<u32>,
+    headers: Vec<SealedBlockHeader>,
+    p2p: &Arc<P>,
+) -> SealedHeaderBatch
+where
+    P: PeerToPeerPort + Send + Sync + 'static,
+{
     let heights = range.clone().map(BlockHeight::from);
     let headers = headers
         .into_iter()
@@ -498,7 +639,7 @@ where
     Batch::new(peer_id, range, headers)
 }
 
-fn report_peer<P>(p2p: &Arc<P>, peer_id: PeerId, reason: PeerReportReason)
+pub(crate) fn report_peer<P>(p2p: &Arc<P>, peer_id: PeerId, reason: PeerReportReason)
 where
     P: PeerToPeerPort + Send + Sync + 'static,
 {
@@ -13,8 +13,15 @@ use crate::{
 use fuel_core_services::stream::BoxStream;
 use fuel_core_types::{
     blockchain::SealedBlockHeader,
+    fuel_tx::{
+        Transaction,
+        TxId,
+    },
     fuel_types::BlockHeight,
     services::p2p::{
+        BlockGossipData,
+        GossipsubMessageAcceptance,
+        GossipsubMessageInfo,
         PeerId,
         SourcePeer,
         Transactions,
@@ -50,6 +57,29 @@ impl PeerToPeerPort for PressurePeerToPeer {
         self.p2p.get_sealed_block_headers(block_height_range).await
     }
 
+    async fn select_peers(
+        &self,
+        block_height_range: Range<u32>,
+        max_peers: usize,
+    ) -> anyhow::Result<Vec<PeerId>> {
+        self.p2p.select_peers(block_height_range, max_peers).await
+    }
+
+    async fn get_sealed_block_headers_from_peer(
+        &self,
+        block_height_range: SourcePeer<Range<u32>>,
+    ) -> anyhow::Result<Option<Vec<SealedBlockHeader>>> {
+        self.counts.apply(|c| c.inc_headers());
+        tokio::time::sleep(self.durations[0]).await;
+        self.counts.apply(|c| c.dec_headers());
+        for _ in block_height_range.data.clone() {
+            self.counts.apply(|c| c.inc_blocks());
+        }
+        self.p2p
+            .get_sealed_block_headers_from_peer(block_height_range)
+            .await
+    }
+
     async fn get_transactions(
         &self,
         block_ids: SourcePeer<Range<u32>>,
@@ -63,6 +93,13 @@ impl PeerToPeerPort for PressurePeerToPeer {
         self.p2p.get_transactions(block_ids).await
     }
 
+    async fn get_transactions_by_ids(
+        &self,
+        tx_ids: SourcePeer<Vec<TxId>>,
+    ) -> anyhow::Result<Option<Vec<Transaction>>> {
+        self.p2p.get_transactions_by_ids(tx_ids).await
+    }
+
     fn report_peer(
         &self,
         _peer: PeerId,
@@ -70,6 +107,18 @@ impl PeerToPeerPort for PressurePeerToPeer {
     ) -> anyhow::Result<()> {
         Ok(())
     }
+
+    fn gossiped_blocks(&self) -> BoxStream<BlockGossipData> {
+        self.p2p.gossiped_blocks()
+    }
+
+    fn notify_gossip_block_validity(
+        &self,
+        _message_info: GossipsubMessageInfo,
+        _validity: GossipsubMessageAcceptance,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
 }
 
 impl PressurePeerToPeer {
@@ -85,6 +134,20 @@ impl PressurePeerToPeer {
             let headers = peer.bind(Some(headers));
             Ok(headers)
         });
+        mock.expect_select_peers().returning(|_, max_peers| {
+            Ok((0..max_peers)
+                .map(|i| PeerId::from(i.to_be_bytes().to_vec()))
+                .collect())
+        });
+        mock.expect_get_sealed_block_headers_from_peer()
+            .returning(|range| {
+                let headers = range
+                    .data
+                    .map(BlockHeight::from)
+                    .map(empty_header)
+                    .collect();
+                Ok(Some(headers))
+            });
         mock.expect_get_transactions().returning(|block_ids| {
             let data = block_ids.data;
             let v = data.into_iter().map(|_| Transactions::default()).collect();
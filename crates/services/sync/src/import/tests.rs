@@ -53,6 +53,7 @@ async fn test_import_0_to_5() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_parallel_peers: 1,
     };
     let mocks = Mocks {
         consensus_port,
@@ -99,6 +100,7 @@ async fn test_import_3_to_5() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_parallel_peers: 1,
     };
     let mocks = Mocks {
         consensus_port,
@@ -217,6 +219,7 @@ async fn import__signature_fails_on_header_5_only() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_parallel_peers: 1,
     };
 
     // when
@@ -265,6 +268,7 @@ async fn import__signature_fails_on_header_4_only() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_parallel_peers: 1,
     };
 
     // when
@@ -296,6 +300,7 @@ async fn import__header_not_found() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_parallel_peers: 1,
     };
 
     // when
@@ -327,6 +332,7 @@ async fn import__header_response_incomplete() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_parallel_peers: 1,
     };
 
     // when
@@ -366,6 +372,7 @@ async fn import__header_5_not_found() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_parallel_peers: 1,
     };
 
     // when
@@ -398,6 +405,7 @@ async fn import__header_4_not_found() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_parallel_peers: 1,
     };
 
     // when
@@ -442,6 +450,7 @@ async fn import__transactions_not_found() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_parallel_peers: 1,
     };
 
     // when
@@ -496,6 +505,7 @@ async fn import__transactions_not_found_for_header_4() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_parallel_peers: 1,
     };
 
     // when
@@ -541,6 +551,7 @@ async fn import__transactions_not_found_for_header_5() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_parallel_peers: 1,
     };
 
     // when
@@ -568,6 +579,7 @@ async fn import__p2p_error() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_parallel_peers: 1,
     };
 
     // when
@@ -612,6 +624,7 @@ async fn import__p2p_error_on_4_transactions() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_parallel_peers: 1,
     };
 
     // when
@@ -660,6 +673,7 @@ async fn import__consensus_error_on_4() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_parallel_peers: 1,
     };
 
     // when
@@ -714,6 +728,7 @@ async fn import__consensus_error_on_5() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_parallel_peers: 1,
     };
 
     // when
@@ -774,6 +789,7 @@ async fn import__execution_error_on_header_4() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_parallel_peers: 1,
     };
 
     // when
@@ -834,6 +850,7 @@ async fn import__execution_error_on_header_5() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_parallel_peers: 1,
     };
 
     // when
@@ -862,6 +879,7 @@ async fn signature_always_fails() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_parallel_peers: 1,
     };
 
     // when
@@ -914,6 +932,7 @@ async fn import__can_work_in_two_loops() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_parallel_peers: 1,
     };
 
     // when
@@ -923,6 +942,171 @@ async fn import__can_work_in_two_loops() {
     assert_eq!((State::new(6, None), true), res);
 }
 
+fn peer(i: u8) -> PeerId {
+    PeerId::from(vec![i])
+}
+
+#[tokio::test]
+async fn import__parallel_download_splits_range_across_peers() {
+    // given
+    let mut consensus_port = MockConsensusPort::default();
+    consensus_port
+        .expect_check_sealed_header()
+        .times(30)
+        .returning(|_| Ok(true));
+    consensus_port
+        .expect_await_da_height()
+        .times(3)
+        .returning(|_| Ok(()));
+
+    let requested_peers = Arc::new(std::sync::Mutex::new(vec![]));
+    let mut p2p = MockPeerToPeerPort::default();
+    p2p.expect_select_peers()
+        .times(1)
+        .returning(|_, _| Ok(vec![peer(1), peer(2), peer(3)]));
+    p2p.expect_get_sealed_block_headers_from_peer()
+        .times(3)
+        .returning({
+            let requested_peers = requested_peers.clone();
+            move |range| {
+                requested_peers.lock().unwrap().push(range.peer_id);
+                Ok(Some(range.data.map(empty_header).collect()))
+            }
+        });
+    p2p.expect_get_transactions()
+        .times(3)
+        .returning(|block_ids| {
+            let data = block_ids.data;
+            let v = data.into_iter().map(|_| Transactions::default()).collect();
+            Ok(Some(v))
+        });
+
+    let mocks = Mocks {
+        consensus_port,
+        p2p,
+        executor: DefaultMocks::times([30]),
+    };
+    let params = Config {
+        block_stream_buffer_size: 10,
+        header_batch_size: 10,
+        max_parallel_peers: 3,
+    };
+    let state = SharedMutex::new(State::new(None, 29));
+
+    // when
+    let res = test_import_inner(state, mocks, None, params).await;
+
+    // then
+    assert_eq!((State::new(29, None), true), res);
+    let mut requested_peers = requested_peers.lock().unwrap().clone();
+    requested_peers.sort();
+    assert_eq!(requested_peers, vec![peer(1), peer(2), peer(3)]);
+}
+
+#[tokio::test]
+async fn import__parallel_download_retries_failed_range_on_other_peer() {
+    // given
+    let mut consensus_port = MockConsensusPort::default();
+    consensus_port
+        .expect_check_sealed_header()
+        .times(20)
+        .returning(|_| Ok(true));
+    consensus_port
+        .expect_await_da_height()
+        .times(2)
+        .returning(|_| Ok(()));
+
+    let mut p2p = MockPeerToPeerPort::default();
+    p2p.expect_select_peers()
+        .times(1)
+        .returning(|_, _| Ok(vec![peer(1), peer(2)]));
+    // the first peer fails to serve its range, so it is retried on the second peer
+    p2p.expect_get_sealed_block_headers_from_peer()
+        .times(3)
+        .returning(|range| {
+            if range.peer_id == peer(1) {
+                Ok(None)
+            } else {
+                Ok(Some(range.data.map(empty_header).collect()))
+            }
+        });
+    p2p.expect_get_transactions()
+        .times(2)
+        .returning(|block_ids| {
+            assert_eq!(block_ids.peer_id, peer(2));
+            let data = block_ids.data;
+            let v = data.into_iter().map(|_| Transactions::default()).collect();
+            Ok(Some(v))
+        });
+
+    let mocks = Mocks {
+        consensus_port,
+        p2p,
+        executor: DefaultMocks::times([20]),
+    };
+    let params = Config {
+        block_stream_buffer_size: 10,
+        header_batch_size: 10,
+        max_parallel_peers: 2,
+    };
+    let state = SharedMutex::new(State::new(None, 19));
+
+    // when
+    let res = test_import_inner(state, mocks, None, params).await;
+
+    // then
+    assert_eq!((State::new(19, None), true), res);
+}
+
+#[tokio::test]
+async fn import__parallel_download_stops_at_range_failed_by_all_peers() {
+    // given
+    let mut consensus_port = MockConsensusPort::default();
+    consensus_port
+        .expect_check_sealed_header()
+        .returning(|_| Ok(true));
+    consensus_port
+        .expect_await_da_height()
+        .returning(|_| Ok(()));
+
+    let mut p2p = MockPeerToPeerPort::default();
+    p2p.expect_select_peers()
+        .times(1)
+        .returning(|_, _| Ok(vec![peer(1), peer(2)]));
+    // no peer has the headers of the second range
+    p2p.expect_get_sealed_block_headers_from_peer()
+        .returning(|range| {
+            if range.data.start >= 10 && range.data.start < 20 {
+                Ok(None)
+            } else {
+                Ok(Some(range.data.map(empty_header).collect()))
+            }
+        });
+    p2p.expect_get_transactions().returning(|block_ids| {
+        let data = block_ids.data;
+        let v = data.into_iter().map(|_| Transactions::default()).collect();
+        Ok(Some(v))
+    });
+
+    let mocks = Mocks {
+        consensus_port,
+        p2p,
+        executor: DefaultMocks::times([10]),
+    };
+    let params = Config {
+        block_stream_buffer_size: 10,
+        header_batch_size: 10,
+        max_parallel_peers: 2,
+    };
+    let state = SharedMutex::new(State::new(None, 29));
+
+    // when
+    let res = test_import_inner(state, mocks, None, params).await;
+
+    // then
+    assert_eq!((State::new(9, None), false), res);
+}
+
 async fn test_import_inner(
     state: SharedMutex<State>,
     mocks: Mocks,
@@ -1101,6 +1285,7 @@ impl PeerReportTestBuilder {
         let params = Config {
             block_stream_buffer_size: 10,
             header_batch_size: 10,
+            max_parallel_peers: 1,
         };
 
         let import = Import {
@@ -7,8 +7,15 @@ use fuel_core_types::{
         SealedBlock,
         SealedBlockHeader,
     },
+    fuel_tx::{
+        Transaction,
+        TxId,
+    },
     fuel_types::BlockHeight,
     services::p2p::{
+        BlockGossipData,
+        GossipsubMessageAcceptance,
+        GossipsubMessageInfo,
         PeerId,
         SourcePeer,
         Transactions,
@@ -47,6 +54,21 @@ pub trait PeerToPeerPort {
         block_height_range: Range<u32>,
     ) -> anyhow::Result<SourcePeer<Option<Vec<SealedBlockHeader>>>>;
 
+    /// Select up to `max_peers` peers with good heartbeats that have the blocks
+    /// with the transactions in the `block_height_range`.
+    /// The peers with the better reputation go first.
+    async fn select_peers(
+        &self,
+        block_height_range: Range<u32>,
+        max_peers: usize,
+    ) -> anyhow::Result<Vec<PeerId>>;
+
+    /// Request a range of sealed block headers from the given peer.
+    async fn get_sealed_block_headers_from_peer(
+        &self,
+        block_height_range: SourcePeer<Range<u32>>,
+    ) -> anyhow::Result<Option<Vec<SealedBlockHeader>>>;
+
     /// Request transactions from the network for the given block
     /// and source peer.
     async fn get_transactions(
@@ -54,8 +76,25 @@ pub trait PeerToPeerPort {
         block_ids: SourcePeer<Range<u32>>,
     ) -> anyhow::Result<Option<Vec<Transactions>>>;
 
+    /// Request the transactions with the given ids from the source peer.
+    async fn get_transactions_by_ids(
+        &self,
+        tx_ids: SourcePeer<Vec<TxId>>,
+    ) -> anyhow::Result<Option<Vec<Transaction>>>;
+
     /// Report a peer for some reason to modify their reputation.
     fn report_peer(&self, peer: PeerId, report: PeerReportReason) -> anyhow::Result<()>;
+
+    /// Stream of blocks gossiped by the network.
+    fn gossiped_blocks(&self) -> BoxStream<BlockGossipData>;
+
+    /// Report the validity of the gossiped block,
+    /// so only the valid blocks are propagated further.
+    fn notify_gossip_block_validity(
+        &self,
+        message_info: GossipsubMessageInfo,
+        validity: GossipsubMessageAcceptance,
+    ) -> anyhow::Result<()>;
 }
 
 #[cfg_attr(any(test, feature = "benchmarking"), mockall::automock)]
@@ -79,3 +118,11 @@ pub trait BlockImporterPort {
     /// and commit it to the database.
     async fn execute_and_commit(&self, block: SealedBlock) -> anyhow::Result<()>;
 }
+
+#[cfg_attr(any(test, feature = "benchmarking"), mockall::automock)]
+/// Port for communication with the transaction pool.
+pub trait TxPoolPort {
+    /// Returns the transactions with the `tx_ids` from the pool,
+    /// or `None` for the transactions that are not in the pool.
+    fn find_transactions(&self, tx_ids: &[TxId]) -> Vec<Option<Transaction>>;
+}
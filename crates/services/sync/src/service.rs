@@ -1,8 +1,12 @@
 //! Service utilities for running fuel sync.
-use std::sync::Arc;
+use std::{
+    sync::Arc,
+    time::Duration,
+};
 
 use crate::{
     import::{
+        report_peer,
         Config,
         Import,
     },
@@ -10,7 +14,9 @@ use crate::{
         self,
         BlockImporterPort,
         ConsensusPort,
+        PeerReportReason,
         PeerToPeerPort,
+        TxPoolPort,
     },
     state::State,
     sync::SyncHeights,
@@ -28,68 +34,112 @@ use fuel_core_services::{
     SharedMutex,
     StateWatcher,
 };
-use fuel_core_types::fuel_types::BlockHeight;
+use fuel_core_types::{
+    blockchain::SealedBlock,
+    fuel_types::BlockHeight,
+    services::p2p::{
+        BlockGossipData,
+        CompactBlock,
+        GossipData,
+        GossipsubMessageAcceptance,
+        GossipsubMessageInfo,
+        PeerId,
+    },
+};
 use futures::StreamExt;
-use tokio::sync::Notify;
+use tokio::{
+    sync::Notify,
+    task::JoinHandle,
+};
 
 #[cfg(test)]
 mod tests;
 
 /// Creates an instance of runnable sync service.
-pub fn new_service<P, E, C>(
+pub fn new_service<P, E, C, T>(
     current_fuel_block_height: BlockHeight,
     p2p: P,
     executor: E,
     consensus: C,
+    txpool: T,
     params: Config,
-) -> anyhow::Result<ServiceRunner<SyncTask<P, E, C>>>
+) -> anyhow::Result<ServiceRunner<SyncTask<P, E, C, T>>>
 where
     P: ports::PeerToPeerPort + Send + Sync + 'static,
     E: ports::BlockImporterPort + Send + Sync + 'static,
     C: ports::ConsensusPort + Send + Sync + 'static,
+    T: ports::TxPoolPort + Send + Sync + 'static,
 {
     let height_stream = p2p.height_stream();
+    let gossiped_blocks = p2p.gossiped_blocks();
     let committed_height_stream = executor.committed_height_stream();
     let state = State::new(Some(current_fuel_block_height.into()), None);
     Ok(ServiceRunner::new(SyncTask::new(
         height_stream,
         committed_height_stream,
+        gossiped_blocks,
         state,
         params,
         p2p,
         executor,
         consensus,
+        txpool,
     )?))
 }
 
 /// Task for syncing heights.
 /// Contains import task as a child task.
-pub struct SyncTask<P, E, C>
+pub struct SyncTask<P, E, C, T>
 where
     P: PeerToPeerPort + Send + Sync + 'static,
     E: BlockImporterPort + Send + Sync + 'static,
     C: ConsensusPort + Send + Sync + 'static,
+    T: TxPoolPort + Send + Sync + 'static,
 {
     sync_heights: SyncHeights,
+    gossiped_blocks: BoxStream<BlockGossipData>,
+    gossip_import: Arc<GossipImport<P, E, C, T>>,
+    gossip_import_handles: Vec<JoinHandle<()>>,
     import_task_handle: ServiceRunner<ImportTask<P, E, C>>,
 }
 
 struct ImportTask<P, E, C>(Import<P, E, C>);
 
-impl<P, E, C> SyncTask<P, E, C>
+/// The maximum number of the gossiped blocks imported at the same time.
+/// The blocks gossiped above the limit are ignored.
+const MAX_PARALLEL_GOSSIP_IMPORTS: usize = 4;
+
+/// The maximum duration of each step of the import of the gossiped block:
+/// the reconstruction of the block and its execution.
+const GOSSIP_IMPORT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Imports the gossiped blocks in the separate tasks, so the slow network
+/// or DA layer doesn't block the sync of the heights.
+struct GossipImport<P, E, C, T> {
+    state: SharedMutex<State>,
+    p2p: Arc<P>,
+    executor: Arc<E>,
+    consensus: Arc<C>,
+    txpool: T,
+}
+
+impl<P, E, C, T> SyncTask<P, E, C, T>
 where
     P: PeerToPeerPort + Send + Sync + 'static,
     E: BlockImporterPort + Send + Sync + 'static,
     C: ConsensusPort + Send + Sync + 'static,
+    T: TxPoolPort + Send + Sync + 'static,
 {
     fn new(
         height_stream: BoxStream<BlockHeight>,
         committed_height_stream: BoxStream<BlockHeight>,
+        gossiped_blocks: BoxStream<BlockGossipData>,
         state: State,
         params: Config,
         p2p: P,
         executor: E,
         consensus: C,
+        txpool: T,
     ) -> anyhow::Result<Self> {
         let notify = Arc::new(Notify::new());
         let state = SharedMutex::new(state);
@@ -102,45 +152,245 @@ where
             state.clone(),
             notify.clone(),
         );
-        let import = Import::new(state, notify, params, p2p, executor, consensus);
+        let import = Import::new(
+            state.clone(),
+            notify,
+            params,
+            p2p.clone(),
+            executor.clone(),
+            consensus.clone(),
+        );
         let import_task_handle = ServiceRunner::new(ImportTask(import));
+        let gossip_import = Arc::new(GossipImport {
+            state,
+            p2p,
+            executor,
+            consensus,
+            txpool,
+        });
         Ok(Self {
             sync_heights,
+            gossiped_blocks,
+            gossip_import,
+            gossip_import_handles: vec![],
             import_task_handle,
         })
     }
+
+    /// Starts the import of the gossiped block in the separate task.
+    fn spawn_gossip_import(&mut self, gossip: BlockGossipData) {
+        self.gossip_import_handles
+            .retain(|handle| !handle.is_finished());
+        if self.gossip_import_handles.len() >= MAX_PARALLEL_GOSSIP_IMPORTS {
+            tracing::debug!(
+                "Too many gossiped blocks are imported, the block is ignored"
+            );
+            self.gossip_import.notify_validity(
+                gossip.message_id,
+                gossip.peer_id,
+                GossipsubMessageAcceptance::Ignore,
+            );
+            return
+        }
+
+        let gossip_import = self.gossip_import.clone();
+        let handle = tokio::spawn(async move {
+            gossip_import.import_gossiped_block(gossip).await;
+        });
+        self.gossip_import_handles.push(handle);
+    }
+}
+
+impl<P, E, C, T> GossipImport<P, E, C, T>
+where
+    P: PeerToPeerPort + Send + Sync + 'static,
+    E: BlockImporterPort + Send + Sync + 'static,
+    C: ConsensusPort + Send + Sync + 'static,
+    T: TxPoolPort + Send + Sync + 'static,
+{
+    /// Imports the gossiped block if it extends the local tip. The block is propagated
+    /// further only if its consensus seal is valid and it is rebuilt from its transactions.
+    async fn import_gossiped_block(&self, gossip: BlockGossipData) {
+        let GossipData {
+            data,
+            peer_id,
+            message_id,
+        } = gossip;
+        let compact_block = match data {
+            Some(compact_block) => compact_block,
+            None => return,
+        };
+        let height = **compact_block.header.entity.height();
+        let committed = self.state.apply(|s| s.committed_height());
+        if committed.map_or(false, |committed| height <= committed) {
+            // The block is already known.
+            return
+        }
+
+        let seal_is_valid = match self
+            .consensus
+            .check_sealed_header(&compact_block.header)
+        {
+            Ok(seal_is_valid) => seal_is_valid,
+            Err(err) => {
+                tracing::debug!("Failed to check the seal of the gossiped block: {err}");
+                false
+            }
+        };
+        if !seal_is_valid {
+            self.notify_validity(message_id, peer_id, GossipsubMessageAcceptance::Reject);
+            return
+        }
+
+        let reconstructed = tokio::time::timeout(
+            GOSSIP_IMPORT_TIMEOUT,
+            self.reconstruct_block(compact_block, peer_id.clone()),
+        )
+        .await
+        .unwrap_or_else(|_| {
+            tracing::debug!("Timed out rebuilding the gossiped block {height}");
+            Err(PeerReportReason::MissingTransactions)
+        });
+        let block = match reconstructed {
+            Ok(block) => block,
+            Err(reason) => {
+                let acceptance = match reason {
+                    PeerReportReason::InvalidTransactions => {
+                        GossipsubMessageAcceptance::Reject
+                    }
+                    _ => GossipsubMessageAcceptance::Ignore,
+                };
+                report_peer(&self.p2p, peer_id.clone(), reason);
+                self.notify_validity(message_id, peer_id, acceptance);
+                return
+            }
+        };
+        self.notify_validity(message_id, peer_id, GossipsubMessageAcceptance::Accept);
+
+        let extends_tip = committed
+            .and_then(|committed| committed.checked_add(1))
+            .map_or(false, |next| next == height);
+        if !extends_tip {
+            return
+        }
+
+        // The rest of the block is validated during the execution.
+        let result = tokio::time::timeout(GOSSIP_IMPORT_TIMEOUT, async {
+            self.consensus
+                .await_da_height(&block.entity.header().da_height)
+                .await?;
+            self.executor.execute_and_commit(block).await
+        })
+        .await
+        .unwrap_or_else(|_| Err(anyhow::anyhow!("Timed out")));
+        if let Err(err) = result {
+            tracing::debug!("Failed to import the gossiped block {height}: {err}");
+        }
+    }
+
+    fn notify_validity(
+        &self,
+        message_id: Vec<u8>,
+        peer_id: PeerId,
+        acceptance: GossipsubMessageAcceptance,
+    ) {
+        let message_info = GossipsubMessageInfo {
+            message_id,
+            peer_id,
+        };
+        let _ = self
+            .p2p
+            .notify_gossip_block_validity(message_info, acceptance);
+    }
+
+    /// Rebuilds the block from the transactions in the pool. Only the transactions
+    /// missing in the pool are requested from the peer that gossiped the block.
+    /// Returns the reason to report the peer if the block can't be rebuilt.
+    async fn reconstruct_block(
+        &self,
+        compact_block: CompactBlock,
+        peer_id: PeerId,
+    ) -> Result<SealedBlock, PeerReportReason> {
+        let tx_ids = compact_block.pooled_tx_ids();
+        let mut transactions = self.txpool.find_transactions(&tx_ids);
+        let missing: Vec<_> = tx_ids
+            .iter()
+            .zip(&transactions)
+            .filter(|(_, tx)| tx.is_none())
+            .map(|(tx_id, _)| *tx_id)
+            .collect();
+
+        if !missing.is_empty() {
+            let fetched = self
+                .p2p
+                .get_transactions_by_ids(peer_id.bind(missing))
+                .await
+                .map_err(|err| {
+                    tracing::debug!("Failed to request the missing transactions: {err}");
+                    PeerReportReason::MissingTransactions
+                })?
+                .ok_or(PeerReportReason::MissingTransactions)?;
+            let mut fetched = fetched.into_iter();
+            for tx in transactions.iter_mut().filter(|tx| tx.is_none()) {
+                *tx = fetched.next();
+            }
+        }
+
+        let transactions = transactions
+            .into_iter()
+            .collect::<Option<Vec<_>>>()
+            .ok_or(PeerReportReason::MissingTransactions)?;
+        // The transactions don't match the block header.
+        compact_block
+            .into_block(transactions)
+            .ok_or(PeerReportReason::InvalidTransactions)
+    }
 }
 
 #[async_trait::async_trait]
-impl<P, E, C> RunnableTask for SyncTask<P, E, C>
+impl<P, E, C, T> RunnableTask for SyncTask<P, E, C, T>
 where
     P: PeerToPeerPort + Send + Sync + 'static,
     E: BlockImporterPort + Send + Sync + 'static,
     C: ConsensusPort + Send + Sync + 'static,
+    T: TxPoolPort + Send + Sync + 'static,
 {
     #[tracing::instrument(level = "debug", skip_all, err, ret)]
     async fn run(&mut self, _: &mut StateWatcher) -> anyhow::Result<bool> {
-        Ok(self.sync_heights.sync().await.is_some())
+        tokio::select! {
+            result = self.sync_heights.sync() => Ok(result.is_some()),
+            gossip = self.gossiped_blocks.next() => match gossip {
+                Some(gossip) => {
+                    self.spawn_gossip_import(gossip);
+                    Ok(true)
+                }
+                None => Ok(false),
+            },
+        }
     }
 
     async fn shutdown(self) -> anyhow::Result<()> {
+        for handle in self.gossip_import_handles {
+            handle.abort();
+        }
         self.import_task_handle.stop_and_await().await?;
         Ok(())
     }
 }
 
 #[async_trait::async_trait]
-impl<P, E, C> RunnableService for SyncTask<P, E, C>
+impl<P, E, C, T> RunnableService for SyncTask<P, E, C, T>
 where
     P: PeerToPeerPort + Send + Sync + 'static,
     E: BlockImporterPort + Send + Sync + 'static,
     C: ConsensusPort + Send + Sync + 'static,
+    T: TxPoolPort + Send + Sync + 'static,
 {
     const NAME: &'static str = "SyncTask";
 
     type SharedData = ();
 
-    type Task = SyncTask<P, E, C>;
+    type Task = SyncTask<P, E, C, T>;
     type TaskParams = ();
 
     fn shared_data(&self) -> Self::SharedData {}
@@ -2,11 +2,29 @@ use fuel_core_services::{
     stream::IntoBoxStream,
     Service,
 };
-use fuel_core_types::services::p2p::Transactions;
+use fuel_core_types::{
+    blockchain::{
+        block::Block,
+        consensus::Consensus,
+        header::PartialBlockHeader,
+        SealedBlock,
+    },
+    fuel_tx::{
+        Transaction,
+        TransactionBuilder,
+        UniqueIdentifier,
+    },
+    fuel_types::ChainId,
+    services::p2p::{
+        CompactBlock,
+        Transactions,
+    },
+};
 use futures::{
     stream,
     StreamExt,
 };
+use test_case::test_case;
 
 use crate::{
     import::test_helpers::{
@@ -17,6 +35,7 @@ use crate::{
         MockBlockImporterPort,
         MockConsensusPort,
         MockPeerToPeerPort,
+        MockTxPoolPort,
     },
 };
 
@@ -26,6 +45,8 @@ use super::*;
 async fn test_new_service() {
     let mut p2p = MockPeerToPeerPort::default();
     p2p.expect_report_peer().returning(|_, _| Ok(()));
+    p2p.expect_gossiped_blocks()
+        .returning(|| futures::stream::pending().into_boxed());
     p2p.expect_height_stream().returning(|| {
         stream::iter(
             std::iter::successors(Some(6u32), |n| Some(n + 1)).map(BlockHeight::from),
@@ -66,8 +87,10 @@ async fn test_new_service() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_parallel_peers: 1,
     };
-    let s = new_service(4u32.into(), p2p, importer, consensus, params).unwrap();
+    let txpool = MockTxPoolPort::default();
+    let s = new_service(4u32.into(), p2p, importer, consensus, txpool, params).unwrap();
 
     assert_eq!(
         s.start_and_await().await.unwrap(),
@@ -87,3 +110,197 @@ async fn test_new_service() {
         fuel_core_services::State::Stopped
     );
 }
+
+fn sealed_block(height: u32, transactions: Vec<Transaction>) -> SealedBlock {
+    let mut header = PartialBlockHeader::default();
+    header.consensus.height = height.into();
+    SealedBlock {
+        entity: Block::new(header, transactions, &[]),
+        consensus: Consensus::default(),
+    }
+}
+
+fn gossiped_block(block: &SealedBlock) -> BlockGossipData {
+    let compact_block = CompactBlock::new(block, &ChainId::default());
+    let message_id = block.entity.header().height().to_bytes();
+    GossipData::new(compact_block, random_peer(), message_id)
+}
+
+fn script(data: u8) -> Transaction {
+    TransactionBuilder::script(vec![], vec![data]).finalize_as_transaction()
+}
+
+struct GossipTestContext {
+    p2p: MockPeerToPeerPort,
+    importer: MockBlockImporterPort,
+    consensus: MockConsensusPort,
+    txpool: MockTxPoolPort,
+    acceptance: tokio::sync::mpsc::Receiver<GossipsubMessageAcceptance>,
+    imported: tokio::sync::mpsc::Receiver<SealedBlock>,
+}
+
+impl GossipTestContext {
+    fn new(block: SealedBlock, seal_is_valid: bool) -> Self {
+        let mut p2p = MockPeerToPeerPort::default();
+        p2p.expect_height_stream()
+            .returning(|| futures::stream::pending().into_boxed());
+        p2p.expect_gossiped_blocks().returning(move || {
+            stream::iter([gossiped_block(&block)])
+                .chain(stream::pending())
+                .into_boxed()
+        });
+        let (acceptance_tx, acceptance) = tokio::sync::mpsc::channel(1);
+        p2p.expect_notify_gossip_block_validity()
+            .returning(move |_, acceptance| {
+                acceptance_tx.try_send(acceptance).unwrap();
+                Ok(())
+            });
+        let mut importer = MockBlockImporterPort::default();
+        importer
+            .expect_committed_height_stream()
+            .returning(|| futures::stream::pending::<BlockHeight>().into_boxed());
+        let (imported_tx, imported) = tokio::sync::mpsc::channel(1);
+        importer
+            .expect_execute_and_commit()
+            .returning(move |block| {
+                imported_tx.try_send(block).unwrap();
+                Ok(())
+            });
+        let mut consensus = MockConsensusPort::default();
+        consensus
+            .expect_check_sealed_header()
+            .returning(move |_| Ok(seal_is_valid));
+        consensus.expect_await_da_height().returning(|_| Ok(()));
+        let mut txpool = MockTxPoolPort::default();
+        txpool
+            .expect_find_transactions()
+            .returning(|tx_ids| tx_ids.iter().map(|_| None).collect());
+        Self {
+            p2p,
+            importer,
+            consensus,
+            txpool,
+            acceptance,
+            imported,
+        }
+    }
+
+    /// Runs the service until the gossiped block is validated and imported,
+    /// if it is expected to be imported.
+    async fn run(self) -> (GossipsubMessageAcceptance, Option<SealedBlock>) {
+        let Self {
+            p2p,
+            importer,
+            consensus,
+            txpool,
+            mut acceptance,
+            mut imported,
+        } = self;
+        let params = Config {
+            block_stream_buffer_size: 10,
+            header_batch_size: 10,
+            max_parallel_peers: 1,
+        };
+        let s =
+            new_service(4u32.into(), p2p, importer, consensus, txpool, params).unwrap();
+
+        s.start_and_await().await.unwrap();
+        let acceptance = acceptance.recv().await.unwrap();
+        // The block is imported in the background after it is accepted.
+        let imported = tokio::time::timeout(Duration::from_millis(100), imported.recv())
+            .await
+            .ok()
+            .flatten();
+        s.stop_and_await().await.unwrap();
+        (acceptance, imported)
+    }
+}
+
+#[test_case(5, true, GossipsubMessageAcceptance::Accept, Some(5); "next block is imported")]
+#[test_case(5, false, GossipsubMessageAcceptance::Reject, None; "invalid seal is rejected")]
+#[test_case(7, true, GossipsubMessageAcceptance::Accept, None; "block with gap is only propagated")]
+#[tokio::test]
+async fn gossiped_block_is_imported_if_extends_tip(
+    height: u32,
+    seal_is_valid: bool,
+    expected_acceptance: GossipsubMessageAcceptance,
+    expected_import: Option<u32>,
+) {
+    let context = GossipTestContext::new(sealed_block(height, vec![]), seal_is_valid);
+
+    let (acceptance, imported) = context.run().await;
+
+    assert_eq!(acceptance, expected_acceptance);
+    let imported_height = imported.map(|block| **block.entity.header().height());
+    assert_eq!(imported_height, expected_import);
+}
+
+#[tokio::test]
+async fn gossiped_block_is_rebuilt_from_txpool_and_missing_transactions() {
+    let pooled = script(1);
+    let missing = script(2);
+    let block = sealed_block(5, vec![pooled.clone(), missing.clone()]);
+    let mut context = GossipTestContext::new(block.clone(), true);
+    context.txpool.checkpoint();
+    context
+        .txpool
+        .expect_find_transactions()
+        .returning(move |tx_ids| {
+            let mut transactions = vec![None; tx_ids.len()];
+            transactions[0] = Some(pooled.clone());
+            transactions
+        });
+    let missing_id = missing.id(&ChainId::default());
+    context
+        .p2p
+        .expect_get_transactions_by_ids()
+        .withf(move |tx_ids| tx_ids.data == vec![missing_id])
+        .returning(move |_| Ok(Some(vec![missing.clone()])));
+
+    let (acceptance, imported) = context.run().await;
+
+    assert_eq!(acceptance, GossipsubMessageAcceptance::Accept);
+    assert_eq!(imported, Some(block));
+}
+
+#[tokio::test]
+async fn gossiped_block_with_invalid_transactions_is_rejected_and_peer_is_reported() {
+    let block = sealed_block(5, vec![script(1)]);
+    let mut context = GossipTestContext::new(block, true);
+    context
+        .p2p
+        .expect_get_transactions_by_ids()
+        .returning(|_| Ok(Some(vec![script(2)])));
+    context
+        .p2p
+        .expect_report_peer()
+        .withf(|_, reason| *reason == PeerReportReason::InvalidTransactions)
+        .times(1)
+        .returning(|_, _| Ok(()));
+
+    let (acceptance, imported) = context.run().await;
+
+    assert_eq!(acceptance, GossipsubMessageAcceptance::Reject);
+    assert_eq!(imported, None);
+}
+
+#[tokio::test]
+async fn gossiped_block_without_transactions_from_peer_is_ignored_and_peer_is_reported() {
+    let block = sealed_block(5, vec![script(1)]);
+    let mut context = GossipTestContext::new(block, true);
+    context
+        .p2p
+        .expect_get_transactions_by_ids()
+        .returning(|_| Ok(None));
+    context
+        .p2p
+        .expect_report_peer()
+        .withf(|_, reason| *reason == PeerReportReason::MissingTransactions)
+        .times(1)
+        .returning(|_, _| Ok(()));
+
+    let (acceptance, imported) = context.run().await;
+
+    assert_eq!(acceptance, GossipsubMessageAcceptance::Ignore);
+    assert_eq!(imported, None);
+}
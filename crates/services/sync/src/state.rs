@@ -200,6 +200,15 @@ impl State {
         }
     }
 
+    /// Get the last committed height.
+    pub fn committed_height(&self) -> Option<u32> {
+        match &self.status {
+            Status::Committed(height) => Some(*height),
+            Status::Processing(range) => range.start().checked_sub(1),
+            Status::Uninitialized => None,
+        }
+    }
+
     #[cfg(test)]
     /// Get the current observed height.
     pub fn proposed_height(&self) -> Option<&u32> {
@@ -1,5 +1,8 @@
 use fuel_core_chain_config::ChainConfig;
-use std::time::Duration;
+use std::{
+    path::PathBuf,
+    time::Duration,
+};
 
 #[derive(Debug, Clone)]
 pub struct Config {
@@ -19,6 +22,16 @@ pub struct Config {
     pub transaction_ttl: Duration,
     /// The number of allowed active transaction status subscriptions.
     pub number_of_active_subscription: usize,
+    /// The minimum increase of the gas price, in percent, required to replace
+    /// a transaction that spends the same coins, messages or creates the same contract.
+    pub replacement_price_bump_percent: u64,
+    /// Maximum number of transactions of a single owner inside the pool
+    pub max_tx_per_owner: usize,
+    /// Maximum sum of the max gas of the transactions of a single owner inside the pool
+    pub max_gas_per_owner: u64,
+    /// The journal of the pending transactions. If set, the transactions are restored
+    /// after the restart of the node.
+    pub journal: Option<JournalConfig>,
 }
 
 impl Default for Config {
@@ -31,15 +44,24 @@ impl Default for Config {
         // 5 minute TTL
         let transaction_ttl = Duration::from_secs(60 * 5);
         let number_of_active_subscription = max_tx;
+        let replacement_price_bump_percent = 10;
+        let max_tx_per_owner = 1024;
+        let chain_config = ChainConfig::default();
+        let max_gas_per_owner = chain_config.block_gas_limit.saturating_mul(10);
+        let journal = None;
         Self::new(
             max_tx,
             max_depth,
-            ChainConfig::default(),
+            chain_config,
             min_gas_price,
             utxo_validation,
             metrics,
             transaction_ttl,
             number_of_active_subscription,
+            replacement_price_bump_percent,
+            max_tx_per_owner,
+            max_gas_per_owner,
+            journal,
         )
     }
 }
@@ -55,6 +77,10 @@ impl Config {
         metrics: bool,
         transaction_ttl: Duration,
         number_of_active_subscription: usize,
+        replacement_price_bump_percent: u64,
+        max_tx_per_owner: usize,
+        max_gas_per_owner: u64,
+        journal: Option<JournalConfig>,
     ) -> Self {
         // # Dev-note: If you add a new field, be sure that this field is propagated correctly
         //  in all places where `new` is used.
@@ -67,6 +93,21 @@ impl Config {
             metrics,
             transaction_ttl,
             number_of_active_subscription,
+            replacement_price_bump_percent,
+            max_tx_per_owner,
+            max_gas_per_owner,
+            journal,
         }
     }
 }
+
+#[derive(Debug, Clone)]
+pub struct JournalConfig {
+    /// The file where the pending transactions are stored
+    pub path: PathBuf,
+    /// Transactions submitted earlier than `max_age` ago are dropped when the journal
+    /// is loaded
+    pub max_age: Duration,
+    /// How often the pending transactions are written to the journal
+    pub flush_interval: Duration,
+}
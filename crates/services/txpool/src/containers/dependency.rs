@@ -47,6 +47,8 @@ pub struct Dependency {
     max_depth: usize,
     /// utxo-validation feature flag
     utxo_validation: bool,
+    /// The minimum gas price bump in percent to replace the collided transaction.
+    replacement_price_bump_percent: u64,
 }
 
 #[derive(Debug, Clone)]
@@ -92,16 +94,137 @@ pub struct MessageState {
 }
 
 impl Dependency {
-    pub fn new(max_depth: usize, utxo_validation: bool) -> Self {
+    pub fn new(
+        max_depth: usize,
+        utxo_validation: bool,
+        replacement_price_bump_percent: u64,
+    ) -> Self {
         Self {
             coins: HashMap::new(),
             contracts: HashMap::new(),
             messages: HashMap::new(),
             max_depth,
             utxo_validation,
+            replacement_price_bump_percent,
         }
     }
 
+    /// Returns `true` if both transactions spend the same coin or message, or
+    /// create the same contract, so only one of them can be included.
+    pub(crate) fn is_conflicting(tx: &PoolTransaction, other: &PoolTransaction) -> bool {
+        let spent_coins = tx
+            .inputs()
+            .iter()
+            .filter_map(spent_coin)
+            .collect::<HashSet<_>>();
+        let spent_messages = tx
+            .inputs()
+            .iter()
+            .filter_map(spent_message)
+            .collect::<HashSet<_>>();
+        let created_contracts = tx
+            .outputs()
+            .iter()
+            .filter_map(created_contract)
+            .collect::<HashSet<_>>();
+
+        other.inputs().iter().any(|input| {
+            spent_coin(input).map_or(false, |utxo_id| spent_coins.contains(utxo_id))
+                || spent_message(input)
+                    .map_or(false, |nonce| spent_messages.contains(nonce))
+        }) || other
+            .outputs()
+            .iter()
+            .filter_map(created_contract)
+            .any(|contract_id| created_contracts.contains(contract_id))
+    }
+
+    /// find all transactions inside txpool that depend on the outputs of the transaction,
+    /// including the transaction itself. Does not check db.
+    fn find_dependents(
+        &self,
+        tx_id: TxId,
+        seen: &mut HashMap<TxId, ArcPoolTx>,
+        txs: &HashMap<TxId, TxInfo>,
+    ) {
+        let mut check = vec![tx_id];
+        while let Some(tx_id) = check.pop() {
+            if seen.contains_key(&tx_id) {
+                continue
+            }
+            let tx = txs.get(&tx_id).expect("To have tx in txpool").tx().clone();
+            for (index, output) in tx.outputs().iter().enumerate() {
+                match output {
+                    Output::Coin { .. }
+                    | Output::Change { .. }
+                    | Output::Variable { .. } => {
+                        let index = u8::try_from(index)
+                            .expect("The number of outputs is more than `u8::max`. \
+                            But it should be impossible because we don't include transactions with so many outputs.");
+                        let utxo_id = UtxoId::new(tx_id, index);
+                        if let Some(spend_by) =
+                            self.coins.get(&utxo_id).and_then(|state| state.is_spend_by)
+                        {
+                            check.push(spend_by);
+                        }
+                    }
+                    Output::ContractCreated { contract_id, .. } => {
+                        if let Some(contract) = self.contracts.get(contract_id) {
+                            check.extend(contract.used_by.iter().copied());
+                        }
+                    }
+                    Output::Contract(_) => {
+                        // no other transactions can depend on these types of outputs
+                    }
+                }
+            }
+            seen.insert(tx_id, tx);
+        }
+    }
+
+    /// Checks that the gas price of the `tx` is bumped enough to replace
+    /// the `replaced` transaction with the `replaced_price`.
+    fn check_replacement_price(
+        &self,
+        replaced: TxId,
+        replaced_price: GasPrice,
+        tx: &ArcPoolTx,
+    ) -> anyhow::Result<()> {
+        let bump = replaced_price
+            .saturating_mul(self.replacement_price_bump_percent)
+            .checked_div(100)
+            .unwrap_or_default();
+        let min_price = replaced_price.saturating_add(bump);
+        if tx.price() < min_price {
+            return Err(
+                Error::NotInsertedReplacementUnderpriced(replaced, min_price).into(),
+            )
+        }
+        Ok(())
+    }
+
+    /// Checks that the `tx` pays more in total than all `collided` transactions
+    /// together with the transactions that depend on them.
+    fn check_replacement_fee(
+        &self,
+        txs: &HashMap<TxId, TxInfo>,
+        tx: &ArcPoolTx,
+        collided: &[TxId],
+    ) -> anyhow::Result<()> {
+        let mut replaced = HashMap::new();
+        for collided in collided {
+            self.find_dependents(*collided, &mut replaced, txs);
+        }
+        let replaced_fee = replaced
+            .values()
+            .fold(0u64, |fee, tx| fee.saturating_add(total_fee(tx)));
+        let fee = total_fee(tx);
+        if fee <= replaced_fee {
+            return Err(Error::NotInsertedReplacementFeeTooLow { fee, replaced_fee }.into())
+        }
+        Ok(())
+    }
+
     /// find all dependent Transactions that are inside txpool.
     /// Does not check db. They can be sorted by gasPrice to get order of dependency
     pub(crate) fn find_dependent(
@@ -293,6 +416,11 @@ impl Dependency {
                                 )
                                 .into())
                             } else {
+                                self.check_replacement_price(
+                                    *spend_by,
+                                    txpool_tx.price(),
+                                    tx,
+                                )?;
                                 if state.is_in_database() {
                                     // this means it is loaded from db. Get tx to compare output.
                                     if self.utxo_validation {
@@ -388,6 +516,11 @@ impl Dependency {
                             )
                             .into())
                         } else {
+                            self.check_replacement_price(
+                                state.spent_by,
+                                state.gas_price,
+                                tx,
+                            )?;
                             collided.push(state.spent_by);
                         }
                     }
@@ -462,12 +595,21 @@ impl Dependency {
                     let origin = contract.origin.expect(
                         "Only contract without origin are the ones that are inside DB. And we check depth for that, so we are okay to just unwrap"
                         );
+                    self.check_replacement_price(
+                        *origin.tx_id(),
+                        contract.gas_price,
+                        tx,
+                    )?;
                     collided.push(*origin.tx_id());
                 }
             }
             // collision of other outputs is not possible.
         }
 
+        if !collided.is_empty() {
+            self.check_replacement_fee(txs, tx, &collided)?;
+        }
+
         Ok((max_depth, db_coins, db_contracts, db_messages, collided))
     }
 
@@ -674,6 +816,36 @@ impl Dependency {
     }
 }
 
+/// The maximum fee that the transaction pays.
+fn total_fee(tx: &PoolTransaction) -> Word {
+    tx.price().saturating_mul(tx.max_gas())
+}
+
+fn spent_coin(input: &Input) -> Option<&UtxoId> {
+    match input {
+        Input::CoinSigned(CoinSigned { utxo_id, .. })
+        | Input::CoinPredicate(CoinPredicate { utxo_id, .. }) => Some(utxo_id),
+        _ => None,
+    }
+}
+
+fn spent_message(input: &Input) -> Option<&Nonce> {
+    match input {
+        Input::MessageCoinSigned(MessageCoinSigned { nonce, .. })
+        | Input::MessageCoinPredicate(MessageCoinPredicate { nonce, .. })
+        | Input::MessageDataSigned(MessageDataSigned { nonce, .. })
+        | Input::MessageDataPredicate(MessageDataPredicate { nonce, .. }) => Some(nonce),
+        _ => None,
+    }
+}
+
+fn created_contract(output: &Output) -> Option<&ContractId> {
+    match output {
+        Output::ContractCreated { contract_id, .. } => Some(contract_id),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
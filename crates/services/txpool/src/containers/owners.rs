@@ -0,0 +1,152 @@
+use crate::{
+    containers::{
+        dependency::Dependency,
+        price_sort::PriceSort,
+    },
+    types::*,
+    Error,
+    TxInfo,
+};
+use fuel_core_types::{
+    fuel_tx::{
+        input::{
+            coin::{
+                CoinPredicate,
+                CoinSigned,
+            },
+            message::{
+                MessageCoinPredicate,
+                MessageCoinSigned,
+                MessageDataPredicate,
+                MessageDataSigned,
+            },
+        },
+        Address,
+        Input,
+    },
+    services::txpool::ArcPoolTx,
+};
+use std::collections::{
+    HashMap,
+    HashSet,
+};
+
+/// The transactions inside txpool of a single owner.
+#[derive(Debug, Clone, Default)]
+struct OwnerTxs {
+    /// transactions of the owner sorted by price
+    by_gas_price: PriceSort,
+    /// the sum of the max gas of the transactions
+    gas: Word,
+}
+
+impl OwnerTxs {
+    fn len(&self) -> usize {
+        self.by_gas_price.sort.len()
+    }
+}
+
+/// Tracks the transactions of every owner of the coin and message inputs,
+/// so a single owner can't fill the whole txpool.
+#[derive(Debug, Clone)]
+pub struct Owners {
+    owners: HashMap<Address, OwnerTxs>,
+    /// max number of transactions of a single owner
+    max_tx_per_owner: usize,
+    /// max sum of the gas of transactions of a single owner
+    max_gas_per_owner: Word,
+}
+
+impl Owners {
+    pub fn new(max_tx_per_owner: usize, max_gas_per_owner: Word) -> Self {
+        Self {
+            owners: HashMap::new(),
+            max_tx_per_owner,
+            max_gas_per_owner,
+        }
+    }
+
+    /// Checks that the owners of the transaction stay within their limits after
+    /// the insertion. The transactions that would be replaced by the new one
+    /// are not counted.
+    pub fn check_limits(&self, tx: &ArcPoolTx) -> Result<(), Error> {
+        for owner in owners(tx) {
+            let (count, gas) = match self.owners.get(&owner) {
+                Some(owner_txs) => {
+                    let (replaced_count, replaced_gas) = owner_txs
+                        .by_gas_price
+                        .sort
+                        .values()
+                        .filter(|pooled| Dependency::is_conflicting(pooled, tx))
+                        .fold((0usize, 0u64), |(count, gas), pooled| {
+                            (
+                                count.saturating_add(1),
+                                gas.saturating_add(pooled.max_gas()),
+                            )
+                        });
+                    (
+                        owner_txs.len().saturating_sub(replaced_count),
+                        owner_txs.gas.saturating_sub(replaced_gas),
+                    )
+                }
+                None => (0, 0),
+            };
+
+            if count.saturating_add(1) > self.max_tx_per_owner {
+                return Err(Error::NotInsertedOwnerTxLimitHit(owner))
+            }
+            let gas = gas.saturating_add(tx.max_gas());
+            if gas > self.max_gas_per_owner {
+                return Err(Error::NotInsertedOwnerGasLimitHit(owner))
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the lowest priced transaction of the owner
+    /// that uses the most gas inside txpool.
+    pub fn heaviest_owner_lowest_tx(&self) -> Option<ArcPoolTx> {
+        self.owners
+            .values()
+            .max_by_key(|owner_txs| (owner_txs.gas, owner_txs.len()))
+            .and_then(|owner_txs| owner_txs.by_gas_price.lowest_tx())
+    }
+
+    pub fn insert(&mut self, info: &TxInfo) {
+        for owner in owners(info.tx()) {
+            let owner_txs = self.owners.entry(owner).or_default();
+            owner_txs.by_gas_price.insert(info);
+            owner_txs.gas = owner_txs.gas.saturating_add(info.tx().max_gas());
+        }
+    }
+
+    pub fn remove(&mut self, info: &TxInfo) {
+        for owner in owners(info.tx()) {
+            if let Some(owner_txs) = self.owners.get_mut(&owner) {
+                owner_txs.by_gas_price.remove(info);
+                owner_txs.gas = owner_txs.gas.saturating_sub(info.tx().max_gas());
+                if owner_txs.len() == 0 {
+                    self.owners.remove(&owner);
+                }
+            }
+        }
+    }
+}
+
+/// The owners of the coins and the recipients of the messages spent by the transaction.
+fn owners(tx: &PoolTransaction) -> HashSet<Address> {
+    tx.inputs()
+        .iter()
+        .filter_map(|input| match input {
+            Input::CoinSigned(CoinSigned { owner, .. })
+            | Input::CoinPredicate(CoinPredicate { owner, .. }) => Some(*owner),
+            Input::MessageCoinSigned(MessageCoinSigned { recipient, .. })
+            | Input::MessageCoinPredicate(MessageCoinPredicate { recipient, .. })
+            | Input::MessageDataSigned(MessageDataSigned { recipient, .. })
+            | Input::MessageDataPredicate(MessageDataPredicate { recipient, .. }) => {
+                Some(*recipient)
+            }
+            Input::Contract(_) => None,
+        })
+        .collect()
+}
@@ -0,0 +1,213 @@
+//! The journal of the pending transactions of the `TxPool`. It allows restoring
+//! the transactions after the restart of the node.
+//!
+//! Each record of the journal file contains:
+//! - the submission time in nanoseconds since the UNIX epoch, `u64` big-endian;
+//! - the length of the transaction, `u32` big-endian;
+//! - the transaction in the canonical encoding.
+
+use crate::config::JournalConfig;
+use fuel_core_types::{
+    fuel_tx::Transaction,
+    fuel_types::canonical::{
+        Deserialize,
+        Serialize,
+    },
+};
+use std::{
+    fs,
+    io,
+    path::Path,
+    time::Duration,
+};
+
+const SUBMITTED_TIME_SIZE: usize = 8;
+const LEN_SIZE: usize = 4;
+
+pub(crate) struct Journal {
+    config: JournalConfig,
+}
+
+impl Journal {
+    pub fn new(config: JournalConfig) -> Self {
+        Self { config }
+    }
+
+    pub fn flush_interval(&self) -> Duration {
+        self.config.flush_interval
+    }
+
+    /// Replaces the content of the journal with `txs`. The records are written in
+    /// the order of the vector, so dependent transactions should go after
+    /// the transactions they depend on.
+    ///
+    /// The encoding and the file system calls are done on the blocking thread pool.
+    pub async fn write(&self, txs: Vec<(Duration, Transaction)>) -> anyhow::Result<()> {
+        let path = self.config.path.clone();
+        tokio::task::spawn_blocking(move || write_records(&path, txs)).await?
+    }
+
+    /// Returns the transactions from the journal with their submission times
+    /// in the order they were written. Transactions submitted earlier than `max_age`
+    /// before `now` are skipped. `now` and the submission times are the time since
+    /// the UNIX epoch.
+    pub async fn read(
+        &self,
+        now: Duration,
+    ) -> anyhow::Result<Vec<(Duration, Transaction)>> {
+        let path = self.config.path.clone();
+        let bytes = match tokio::task::spawn_blocking(move || fs::read(path)).await? {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+            Err(e) => return Err(e.into()),
+        };
+
+        let mut txs = vec![];
+        let mut rest = bytes.as_slice();
+        while !rest.is_empty() {
+            let (submitted_time, tx, tail) = match decode_record(rest) {
+                Some(record) => record,
+                None => {
+                    tracing::warn!(
+                        "The txpool journal {} is truncated, ignoring the last record",
+                        self.config.path.display()
+                    );
+                    break
+                }
+            };
+            rest = tail;
+
+            let submitted_time = Duration::from_nanos(submitted_time);
+            if now.saturating_sub(submitted_time) > self.config.max_age {
+                continue
+            }
+            match Transaction::from_bytes(tx) {
+                Ok(tx) => txs.push((submitted_time, tx)),
+                Err(e) => {
+                    tracing::warn!(
+                        "Unable to decode the transaction from the txpool journal: {e:?}"
+                    );
+                }
+            }
+        }
+        Ok(txs)
+    }
+}
+
+fn write_records(path: &Path, txs: Vec<(Duration, Transaction)>) -> anyhow::Result<()> {
+    let mut bytes = vec![];
+    for (submitted_time, tx) in txs {
+        let submitted_time = u64::try_from(submitted_time.as_nanos())?;
+        let tx = tx.to_bytes();
+        let len = u32::try_from(tx.len())?;
+        bytes.extend_from_slice(&submitted_time.to_be_bytes());
+        bytes.extend_from_slice(&len.to_be_bytes());
+        bytes.extend_from_slice(&tx);
+    }
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    // Write into a temporary file first, so a crash during the write
+    // doesn't leave a partially written journal behind.
+    let tmp_path = path.with_extension("tmp");
+    fs::write(&tmp_path, bytes)?;
+    fs::rename(&tmp_path, path)?;
+    Ok(())
+}
+
+fn decode_record(bytes: &[u8]) -> Option<(u64, &[u8], &[u8])> {
+    let (submitted_time, rest) = split(bytes, SUBMITTED_TIME_SIZE)?;
+    let (len, rest) = split(rest, LEN_SIZE)?;
+    let len = u32::from_be_bytes(len.try_into().ok()?);
+    let (tx, rest) = split(rest, usize::try_from(len).ok()?)?;
+    let submitted_time = u64::from_be_bytes(submitted_time.try_into().ok()?);
+    Some((submitted_time, tx, rest))
+}
+
+fn split(bytes: &[u8], at: usize) -> Option<(&[u8], &[u8])> {
+    (bytes.len() >= at).then(|| bytes.split_at(at))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_types::fuel_tx::{
+        TransactionBuilder,
+        UniqueIdentifier,
+    };
+
+    fn journal(dir: &tempfile::TempDir) -> Journal {
+        Journal::new(JournalConfig {
+            path: dir.path().join("txpool.journal"),
+            max_age: Duration::from_secs(60),
+            flush_interval: Duration::from_secs(10),
+        })
+    }
+
+    fn tx(gas_price: u64) -> Transaction {
+        TransactionBuilder::script(vec![], vec![])
+            .gas_price(gas_price)
+            .finalize_as_transaction()
+    }
+
+    #[tokio::test]
+    async fn missing_journal_is_empty() {
+        let dir = tempfile::tempdir().unwrap();
+        let txs = journal(&dir).read(Duration::from_secs(1000)).await.unwrap();
+        assert!(txs.is_empty());
+    }
+
+    #[tokio::test]
+    async fn read_skips_expired_transactions_and_keeps_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let journal = journal(&dir);
+        let (tx1, tx2, tx3) = (tx(1), tx(2), tx(3));
+
+        journal
+            .write(vec![
+                (Duration::from_secs(1000), tx1.clone()),
+                (Duration::from_secs(900), tx2),
+                (Duration::from_millis(1_010_500), tx3.clone()),
+            ])
+            .await
+            .unwrap();
+
+        let txs = journal.read(Duration::from_secs(1030)).await.unwrap();
+        let ids: Vec<_> = txs
+            .iter()
+            .map(|(submitted_time, tx)| (*submitted_time, tx.id(&Default::default())))
+            .collect();
+        assert_eq!(
+            ids,
+            vec![
+                (Duration::from_secs(1000), tx1.id(&Default::default())),
+                (
+                    Duration::from_millis(1_010_500),
+                    tx3.id(&Default::default())
+                )
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn read_ignores_truncated_record() {
+        let dir = tempfile::tempdir().unwrap();
+        let journal = journal(&dir);
+        let tx1 = tx(1);
+        journal
+            .write(vec![
+                (Duration::from_secs(1000), tx1.clone()),
+                (Duration::from_secs(1000), tx(2)),
+            ])
+            .await
+            .unwrap();
+
+        let mut bytes = fs::read(&journal.config.path).unwrap();
+        bytes.truncate(bytes.len().saturating_sub(1));
+        fs::write(&journal.config.path, bytes).unwrap();
+
+        let txs = journal.read(Duration::from_secs(1000)).await.unwrap();
+        assert_eq!(txs, vec![(Duration::from_secs(1000), tx1)]);
+    }
+}
@@ -17,6 +17,7 @@ use std::{
 
 pub mod config;
 mod containers;
+mod journal;
 pub mod ports;
 pub mod service;
 mod transaction_selector;
@@ -64,6 +65,20 @@ impl TxInfo {
         }
     }
 
+    /// Creates the info of the transaction submitted at the `submitted_time` since
+    /// the UNIX epoch. The creation instant is moved back by the age of the transaction,
+    /// so it expires at the same time as if it had never left the pool.
+    pub fn with_submitted_time(tx: ArcPoolTx, submitted_time: Duration) -> Self {
+        let mut info = Self::new(tx);
+        let age = info.submitted_time.saturating_sub(submitted_time);
+        info.submitted_time = info.submitted_time.min(submitted_time);
+        info.creation_instant = info
+            .creation_instant
+            .checked_sub(age)
+            .unwrap_or(info.creation_instant);
+        info
+    }
+
     pub fn tx(&self) -> &ArcPoolTx {
         &self.tx
     }
@@ -1,4 +1,5 @@
 use crate::{
+    journal::Journal,
     ports::{
         BlockImporter,
         PeerToPeer,
@@ -55,7 +56,11 @@ use fuel_core_types::services::block_importer::SharedImportResult;
 use parking_lot::Mutex as ParkingMutex;
 use std::{
     sync::Arc,
-    time::Duration,
+    time::{
+        Duration,
+        SystemTime,
+        UNIX_EPOCH,
+    },
 };
 use tokio::{
     sync::broadcast,
@@ -146,6 +151,8 @@ pub struct Task<P2P, DB> {
     committed_block_stream: BoxStream<SharedImportResult>,
     shared: SharedState<P2P, DB>,
     ttl_timer: tokio::time::Interval,
+    journal: Option<Journal>,
+    journal_timer: tokio::time::Interval,
 }
 
 #[async_trait::async_trait]
@@ -170,6 +177,14 @@ where
         _: Self::TaskParams,
     ) -> anyhow::Result<Self::Task> {
         self.ttl_timer.reset();
+        self.journal_timer.reset();
+        if let Some(journal) = &self.journal {
+            if let Err(e) = self.shared.restore_from_journal(journal).await {
+                tracing::error!(
+                    "Unable to restore transactions from the txpool journal: {e}"
+                );
+            }
+        }
         Ok(self)
     }
 }
@@ -199,6 +214,11 @@ where
                 should_continue = true
             }
 
+            _ = self.journal_timer.tick(), if self.journal.is_some() => {
+                self.write_journal().await;
+                should_continue = true
+            }
+
             result = self.committed_block_stream.next() => {
                 if let Some(result) = result {
                     let block = &result
@@ -270,14 +290,37 @@ where
     }
 
     async fn shutdown(self) -> anyhow::Result<()> {
-        // Nothing to shut down because we don't have any temporary state that should be dumped,
-        // and we don't spawn any sub-tasks that we need to finish or await.
-        // Maybe we will save and load the previous list of transactions in the future to
-        // avoid losing them.
+        // We don't spawn any sub-tasks that we need to finish or await.
+        // The only state that should be dumped is the journal of the pending transactions.
+        self.write_journal().await;
         Ok(())
     }
 }
 
+impl<P2P, DB> Task<P2P, DB>
+where
+    DB: TxPoolDb,
+{
+    async fn write_journal(&self) {
+        if let Some(journal) = &self.journal {
+            let mut txs: Vec<_> =
+                self.shared.txpool.lock().txs().values().cloned().collect();
+            // The order of insertion keeps dependent transactions after
+            // the transactions they depend on.
+            txs.sort_by_key(TxInfo::created);
+            let txs = txs
+                .iter()
+                .map(|info| {
+                    (info.submitted_time(), Transaction::from(info.tx().as_ref()))
+                })
+                .collect();
+            if let Err(e) = journal.write(txs).await {
+                tracing::error!("Unable to write the txpool journal: {e}");
+            }
+        }
+    }
+}
+
 // TODO: Remove `find` and `find_one` methods from `txpool`. It is used only by GraphQL.
 //  Instead, `fuel-core` can create a `DatabaseWithTxPool` that aggregates `TxPool` and
 //  storage `Database` together. GraphQL will retrieve data from this `DatabaseWithTxPool` via
@@ -294,6 +337,14 @@ where
         self.txpool.lock().consumable_gas()
     }
 
+    pub fn pending_transactions(&self) -> Vec<TxInfo> {
+        self.txpool.lock().txs().values().cloned().collect()
+    }
+
+    pub fn sorted_includable(&self) -> Vec<ArcPoolTx> {
+        self.txpool.lock().sorted_includable().collect()
+    }
+
     pub fn remove_txs(&self, ids: Vec<TxId>) -> Vec<ArcPoolTx> {
         self.txpool.lock().remove(&self.tx_status_sender, &ids)
     }
@@ -335,6 +386,44 @@ where
             .try_subscribe::<MpscChannel>(tx_id)
             .ok_or(anyhow!("Maximum number of subscriptions reached"))
     }
+
+    /// Inserts the transactions from the journal. Transactions that became invalid
+    /// since the journal was written are discarded.
+    async fn restore_from_journal(&self, journal: &Journal) -> anyhow::Result<()> {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?;
+        let (submitted_times, txs): (Vec<_>, Vec<_>) = journal
+            .read(now)
+            .await?
+            .into_iter()
+            .map(|(submitted_time, tx)| (submitted_time, Arc::new(tx)))
+            .unzip();
+        if txs.is_empty() {
+            return Ok(())
+        }
+
+        let current_height = self.db.current_block_height()?;
+        // The transactions keep their original submission time, so the TTL isn't reset.
+        let valid_txs = check_transactions(&txs, current_height, &self.config)
+            .await
+            .into_iter()
+            .zip(submitted_times)
+            .filter_map(|(result, submitted_time)| {
+                result.ok().map(|tx| (tx, submitted_time))
+            })
+            .collect();
+        let inserted = self
+            .txpool
+            .lock()
+            .insert_restored(&self.tx_status_sender, valid_txs)
+            .into_iter()
+            .filter(Result::is_ok)
+            .count();
+        tracing::info!(
+            "Restored {inserted} of {} transactions from the txpool journal",
+            txs.len()
+        );
+        Ok(())
+    }
 }
 
 impl<P2P, DB> SharedState<P2P, DB>
@@ -449,6 +538,14 @@ where
     let consensus_params = config.chain_config.consensus_parameters.clone();
     let number_of_active_subscription = config.number_of_active_subscription;
     let txpool = Arc::new(ParkingMutex::new(TxPool::new(config.clone(), db.clone())));
+    let journal = config.journal.clone().map(Journal::new);
+    // The timer is only polled when the journal is enabled.
+    let journal_flush_interval = journal
+        .as_ref()
+        .map(Journal::flush_interval)
+        .unwrap_or(config.transaction_ttl);
+    let mut journal_timer = tokio::time::interval(journal_flush_interval);
+    journal_timer.set_missed_tick_behavior(MissedTickBehavior::Skip);
     let task = Task {
         gossiped_tx_stream,
         committed_block_stream,
@@ -468,6 +565,8 @@ where
             config,
         },
         ttl_timer,
+        journal,
+        journal_timer,
     };
 
     Service::new(task)
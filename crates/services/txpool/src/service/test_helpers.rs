@@ -45,6 +45,10 @@ impl TestContext {
         &self.service
     }
 
+    pub fn mock_db(&self) -> &MockDb {
+        &self.mock_db
+    }
+
     pub fn setup_script_tx(&self, gas_price: Word) -> Transaction {
         let (_, gas_coin) = self.setup_coin();
         let mut tx = TransactionBuilder::script(vec![], vec![])
@@ -162,6 +166,11 @@ impl TestContextBuilder {
         self
     }
 
+    pub fn with_mock_db(mut self, mock_db: MockDb) -> Self {
+        self.mock_db = mock_db;
+        self
+    }
+
     pub fn with_importer(&mut self, importer: MockImporter) {
         self.importer = Some(importer)
     }
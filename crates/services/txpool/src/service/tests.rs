@@ -1,11 +1,18 @@
 use super::*;
-use crate::service::test_helpers::{
-    TestContext,
-    TestContextBuilder,
+use crate::{
+    config::JournalConfig,
+    service::test_helpers::{
+        TestContext,
+        TestContextBuilder,
+    },
 };
 use fuel_core_services::Service as ServiceTrait;
 use fuel_core_types::{
-    fuel_tx::UniqueIdentifier,
+    fuel_tx::{
+        Cacheable,
+        TransactionBuilder,
+        UniqueIdentifier,
+    },
     fuel_types::ChainId,
 };
 use std::time::Duration;
@@ -267,3 +274,123 @@ async fn simple_insert_removal_subscription() {
 
     service.stop_and_await().await.unwrap();
 }
+
+#[tokio::test]
+async fn replaced_tx_is_squeezed_out_with_replacement_reason() {
+    let ctx = TestContextBuilder::new().build_and_start().await;
+
+    let (_, gas_coin) = ctx.setup_coin();
+    let build_tx = |gas_price| {
+        let mut tx = TransactionBuilder::script(vec![], vec![])
+            .gas_price(gas_price)
+            .script_gas_limit(1000)
+            .add_input(gas_coin.clone())
+            .finalize_as_transaction();
+        tx.precompute(&Default::default())
+            .expect("Should be able to cache");
+        Arc::new(tx)
+    };
+    let tx1 = build_tx(10);
+    let tx2 = build_tx(20);
+    let service = ctx.service();
+
+    let mut tx1_subscribe_updates = service
+        .shared
+        .tx_update_subscribe(tx1.cached_id().unwrap())
+        .unwrap();
+
+    let out = service.shared.insert(vec![tx1.clone()]).await;
+    assert!(out[0].is_ok(), "Tx1 should be OK, got err:{out:?}");
+    let out = service.shared.insert(vec![tx2.clone()]).await;
+    assert!(out[0].is_ok(), "Tx2 should be OK, got err:{out:?}");
+
+    let update = tx1_subscribe_updates.next().await.unwrap();
+    assert!(
+        matches!(
+            update,
+            TxStatusMessage::Status(TransactionStatus::Submitted { .. })
+        ),
+        "First message in tx1 stream should be Submitted"
+    );
+    let update = tx1_subscribe_updates.next().await.unwrap();
+    assert_eq!(
+        update,
+        TxStatusMessage::Status(TransactionStatus::SqueezedOut {
+            reason: TxPoolError::ReplacedByFee(tx2.cached_id().unwrap()).to_string()
+        }),
+        "Second message in tx1 stream should be squeezed out by tx2"
+    );
+
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn pending_txs_are_restored_from_journal_after_restart() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = Config {
+        journal: Some(JournalConfig {
+            path: dir.path().join("txpool.journal"),
+            max_age: Duration::from_secs(60),
+            flush_interval: Duration::from_secs(60),
+        }),
+        ..Default::default()
+    };
+    let ctx = TestContextBuilder::new()
+        .with_config(config.clone())
+        .build_and_start()
+        .await;
+
+    let tx1 = Arc::new(ctx.setup_script_tx(10));
+    let (tx2_coin, gas_coin) = ctx.setup_coin();
+    let mut tx2 = TransactionBuilder::script(vec![], vec![])
+        .gas_price(20)
+        .script_gas_limit(1000)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+    tx2.precompute(&Default::default())
+        .expect("Should be able to cache");
+    let tx2 = Arc::new(tx2);
+    let out = ctx
+        .service()
+        .shared
+        .insert(vec![tx1.clone(), tx2.clone()])
+        .await;
+    assert!(
+        out.iter().all(Result::is_ok),
+        "Txs should be OK, got err:{out:?}"
+    );
+    let submitted_time = ctx
+        .service()
+        .shared
+        .find_one(tx1.id(&Default::default()))
+        .expect("Tx1 should be in the pool")
+        .submitted_time();
+    ctx.service().stop_and_await().await.unwrap();
+
+    // the coin of the second transaction is spent while the node is down
+    ctx.mock_db()
+        .data
+        .lock()
+        .unwrap()
+        .coins
+        .remove(&tx2_coin.utxo_id);
+
+    let restarted = TestContextBuilder::new()
+        .with_config(config)
+        .with_mock_db(ctx.mock_db().clone())
+        .build_and_start()
+        .await;
+
+    let out = restarted.service().shared.find(vec![
+        tx1.id(&Default::default()),
+        tx2.id(&Default::default()),
+    ]);
+    assert!(out[0].is_some(), "Tx1 should be restored:{out:?}");
+    assert!(out[1].is_none(), "Tx2 should be discarded:{out:?}");
+    assert_eq!(
+        out[0].as_ref().unwrap().submitted_time(),
+        submitted_time,
+        "Tx1 should keep the original submission time"
+    );
+    restarted.service().stop_and_await().await.unwrap();
+}
@@ -1,6 +1,7 @@
 use crate::{
     containers::{
         dependency::Dependency,
+        owners::Owners,
         price_sort::PriceSort,
         time_sort::TimeSort,
     },
@@ -50,6 +51,7 @@ use std::{
     collections::HashMap,
     ops::Deref,
     sync::Arc,
+    time::Duration,
 };
 use tokio_rayon::AsyncRayonHandle;
 
@@ -59,6 +61,7 @@ pub struct TxPool<DB> {
     by_gas_price: PriceSort,
     by_time: TimeSort,
     by_dependency: Dependency,
+    by_owner: Owners,
     config: Config,
     database: DB,
 }
@@ -74,7 +77,12 @@ where
             by_hash: HashMap::new(),
             by_gas_price: PriceSort::default(),
             by_time: TimeSort::default(),
-            by_dependency: Dependency::new(max_depth, config.utxo_validation),
+            by_dependency: Dependency::new(
+                max_depth,
+                config.utxo_validation,
+                config.replacement_price_bump_percent,
+            ),
+            by_owner: Owners::new(config.max_tx_per_owner, config.max_gas_per_owner),
             config,
             database,
         }
@@ -93,11 +101,19 @@ where
         &self.by_dependency
     }
 
+    fn insert_inner(
+        &mut self,
+        tx: Checked<Transaction>,
+    ) -> anyhow::Result<InsertionResult> {
+        self.insert_inner_with_submitted_time(tx, None)
+    }
+
     #[tracing::instrument(level = "info", skip_all, fields(tx_id = %tx.id()), ret, err)]
     // this is atomic operation. Return removed(pushed out/replaced) transactions
-    fn insert_inner(
+    fn insert_inner_with_submitted_time(
         &mut self,
         tx: Checked<Transaction>,
+        submitted_time: Option<Duration>,
     ) -> anyhow::Result<InsertionResult> {
         let tx: CheckedTransaction = tx.into();
 
@@ -126,14 +142,23 @@ where
             return Err(Error::NotInsertedTxKnown.into())
         }
 
-        let mut max_limit_hit = false;
+        self.by_owner.check_limits(&tx)?;
+
+        let mut to_push_out = None;
         // check if we are hitting limit of pool
         if self.by_hash.len() >= self.config.max_tx {
-            max_limit_hit = true;
-            // limit is hit, check if we can push out lowest priced tx
-            let lowest_price = self.by_gas_price.lowest_value().unwrap_or_default();
-            if lowest_price >= tx.price() {
-                return Err(Error::NotInsertedLimitHit.into())
+            // limit is hit, check if we can push out a lower priced tx. The cheapest tx
+            // of the owner that uses the most gas goes first, so a single owner can't
+            // squeeze out the transactions of everyone else.
+            let candidate = self
+                .by_owner
+                .heaviest_owner_lowest_tx()
+                .into_iter()
+                .chain(self.by_gas_price.lowest_tx())
+                .find(|candidate| candidate.price() < tx.price());
+            match candidate {
+                Some(candidate) => to_push_out = Some(candidate),
+                None => return Err(Error::NotInsertedLimitHit.into()),
             }
         }
         if self.config.metrics {
@@ -149,21 +174,26 @@ where
         let rem = self
             .by_dependency
             .insert(&self.by_hash, &self.database, &tx)?;
-        let info = TxInfo::new(tx.clone());
+        let info = match submitted_time {
+            Some(submitted_time) => {
+                TxInfo::with_submitted_time(tx.clone(), submitted_time)
+            }
+            None => TxInfo::new(tx.clone()),
+        };
         let submitted_time = info.submitted_time();
         self.by_gas_price.insert(&info);
         self.by_time.insert(&info);
+        self.by_owner.insert(&info);
         self.by_hash.insert(tx.id(), info);
 
         // if some transaction were removed so we don't need to check limit
         let removed = if rem.is_empty() {
-            if max_limit_hit {
-                // remove last tx from sort
-                let rem_tx = self.by_gas_price.lowest_tx().unwrap(); // safe to unwrap limit is hit
-                self.remove_inner(&rem_tx);
-                vec![rem_tx]
-            } else {
-                Vec::new()
+            match to_push_out {
+                Some(rem_tx) => {
+                    self.remove_inner(&rem_tx);
+                    vec![rem_tx]
+                }
+                None => Vec::new(),
             }
         } else {
             // remove ret from by_hash and from by_price
@@ -214,6 +244,7 @@ where
         if let Some(info) = &info {
             self.by_time.remove(info);
             self.by_gas_price.remove(info);
+            self.by_owner.remove(info);
         }
 
         info
@@ -243,7 +274,34 @@ where
             res.push(self.insert_inner(tx));
         }
 
-        // announce to subscribers
+        Self::announce_inserted(tx_status_sender, &res);
+        res
+    }
+
+    #[tracing::instrument(level = "info", skip_all)]
+    /// Import a set of transactions restored after the restart of the node. Each
+    /// transaction keeps its original submission time since the UNIX epoch,
+    /// so its TTL is not reset.
+    pub fn insert_restored(
+        &mut self,
+        tx_status_sender: &TxStatusChange,
+        txs: Vec<(Checked<Transaction>, Duration)>,
+    ) -> Vec<anyhow::Result<InsertionResult>> {
+        let mut res = Vec::new();
+
+        for (tx, submitted_time) in txs.into_iter() {
+            res.push(self.insert_inner_with_submitted_time(tx, Some(submitted_time)));
+        }
+
+        Self::announce_inserted(tx_status_sender, &res);
+        res
+    }
+
+    /// Announces the inserted and squeezed out transactions to the subscribers.
+    fn announce_inserted(
+        tx_status_sender: &TxStatusChange,
+        res: &[anyhow::Result<InsertionResult>],
+    ) {
         for ret in res.iter() {
             match ret {
                 Ok(InsertionResult {
@@ -251,10 +309,18 @@ where
                     inserted,
                     submitted_time,
                 }) => {
+                    let is_replacement = removed
+                        .iter()
+                        .any(|removed| Dependency::is_conflicting(removed, inserted));
                     for removed in removed {
-                        // small todo there is possibility to have removal reason (ReplacedByHigherGas, DependencyRemoved)
-                        // but for now it is okay to just use Error::Removed.
-                        tx_status_sender.send_squeezed_out(removed.id(), Error::Removed);
+                        let reason = if Dependency::is_conflicting(removed, inserted) {
+                            Error::ReplacedByFee(inserted.id())
+                        } else if is_replacement {
+                            Error::DependencyReplacedByFee(inserted.id())
+                        } else {
+                            Error::Removed
+                        };
+                        tx_status_sender.send_squeezed_out(removed.id(), reason);
                     }
                     tx_status_sender.send_submitted(
                         inserted.id(),
@@ -266,7 +332,6 @@ where
                 }
             }
         }
-        res
     }
 
     /// find all tx by its hash
@@ -465,8 +465,9 @@ async fn more_priced_tx3_removes_tx1_and_dependent_tx2() {
         .add_input(input)
         .finalize_as_transaction();
 
+    // pays more than tx1 and tx2 together
     let tx3 = TransactionBuilder::script(vec![], vec![])
-        .gas_price(20)
+        .gas_price(30)
         .script_gas_limit(GAS_LIMIT)
         .add_input(gas_coin)
         .finalize_as_transaction();
@@ -534,6 +535,95 @@ async fn more_priced_tx2_removes_tx1_and_more_priced_tx3_removes_tx2() {
     );
 }
 
+#[tokio::test]
+async fn replacement_with_too_small_price_bump_is_rejected() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    let mut txpool = TxPool::new(Default::default(), db.clone());
+
+    let (_, gas_coin) = setup_coin(&mut rng, Some(&txpool.database));
+
+    let tx1 = TransactionBuilder::script(vec![], vec![])
+        .gas_price(100)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin.clone())
+        .finalize_as_transaction();
+
+    // less than the default 10% bump
+    let tx2 = TransactionBuilder::script(vec![], vec![])
+        .gas_price(105)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+
+    let tx1_id = tx1.id(&ChainId::default());
+    let tx1 = check_unwrap_tx(tx1, db.clone(), &txpool.config).await;
+    let tx2 = check_unwrap_tx(tx2, db.clone(), &txpool.config).await;
+
+    txpool.insert_inner(tx1).expect("Tx1 should be OK, got Err");
+    let err = txpool
+        .insert_inner(tx2)
+        .expect_err("Tx2 should be Err, got Ok");
+    assert!(
+        matches!(
+            err.downcast_ref::<Error>(),
+            Some(Error::NotInsertedReplacementUnderpriced(id, 110)) if id == &tx1_id
+        ),
+        "wrong err {err:?}"
+    );
+}
+
+#[tokio::test]
+async fn replacement_paying_less_than_replaced_tx_and_dependents_is_rejected() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    let mut txpool = TxPool::new(Default::default(), db.clone());
+
+    let (_, gas_coin) = setup_coin(&mut rng, Some(&txpool.database));
+
+    let (output, unset_input) = create_output_and_input(&mut rng, 10);
+    let tx1 = TransactionBuilder::script(vec![], vec![])
+        .gas_price(10)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin.clone())
+        .add_output(output)
+        .finalize_as_transaction();
+
+    let input = unset_input.into_input(UtxoId::new(tx1.id(&Default::default()), 0));
+
+    // the dependent transaction pays much more than tx1
+    let tx2 = TransactionBuilder::script(vec![], vec![])
+        .gas_price(100)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(input)
+        .finalize_as_transaction();
+
+    // bumps the price of tx1, but pays less than tx1 and tx2 together
+    let tx3 = TransactionBuilder::script(vec![], vec![])
+        .gas_price(20)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+
+    let tx1 = check_unwrap_tx(tx1, db.clone(), &txpool.config).await;
+    let tx2 = check_unwrap_tx(tx2, db.clone(), &txpool.config).await;
+    let tx3 = check_unwrap_tx(tx3, db.clone(), &txpool.config).await;
+
+    txpool.insert_inner(tx1).expect("Tx1 should be OK, got Err");
+    txpool.insert_inner(tx2).expect("Tx2 should be OK, got Err");
+    let err = txpool
+        .insert_inner(tx3)
+        .expect_err("Tx3 should be Err, got Ok");
+    assert!(
+        matches!(
+            err.downcast_ref::<Error>(),
+            Some(Error::NotInsertedReplacementFeeTooLow { .. })
+        ),
+        "wrong err {err:?}"
+    );
+    assert_eq!(txpool.pending_number(), 2);
+}
+
 #[tokio::test]
 async fn tx_limit_hit() {
     let mut rng = StdRng::seed_from_u64(0);
@@ -572,6 +662,181 @@ async fn tx_limit_hit() {
     ));
 }
 
+/// Returns a coin that is always owned by the same predicate owner.
+fn setup_owner_coin(rng: &mut StdRng, mock_db: &MockDb) -> Input {
+    let coin = custom_predicate(
+        rng,
+        AssetId::BASE,
+        TEST_COIN_AMOUNT,
+        vec![op::ret(1)].into_iter().collect(),
+        None,
+    )
+    .into_default_estimated();
+    let (_, gas_coin) = add_coin_to_state(coin, Some(mock_db));
+    gas_coin
+}
+
+#[tokio::test]
+async fn owner_tx_limit_hit() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    let mut txpool = TxPool::new(
+        Config {
+            max_tx_per_owner: 1,
+            ..Default::default()
+        },
+        db.clone(),
+    );
+
+    let gas_coin = setup_owner_coin(&mut rng, &db);
+    let owner = *gas_coin.input_owner().unwrap();
+    let tx1 = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+
+    let gas_coin = setup_owner_coin(&mut rng, &db);
+    let tx2 = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+
+    let (_, gas_coin) = setup_coin(&mut rng, Some(&txpool.database));
+    let tx3 = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+
+    let tx1 = check_unwrap_tx(tx1, db.clone(), &txpool.config).await;
+    let tx2 = check_unwrap_tx(tx2, db.clone(), &txpool.config).await;
+    let tx3 = check_unwrap_tx(tx3, db.clone(), &txpool.config).await;
+    txpool.insert_inner(tx1).expect("Tx1 should be Ok, got Err");
+
+    let err = txpool
+        .insert_inner(tx2)
+        .expect_err("Tx2 should be Err, got Ok");
+    assert!(matches!(
+        err.downcast_ref::<Error>(),
+        Some(Error::NotInsertedOwnerTxLimitHit(limited)) if *limited == owner
+    ));
+
+    // other owners are not affected
+    txpool.insert_inner(tx3).expect("Tx3 should be Ok, got Err");
+}
+
+#[tokio::test]
+async fn owner_gas_limit_hit() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+
+    let gas_coin = setup_owner_coin(&mut rng, &db);
+    let owner = *gas_coin.input_owner().unwrap();
+    let tx1 = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+
+    let gas_coin = setup_owner_coin(&mut rng, &db);
+    let tx2 = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+
+    let config = Config::default();
+    let tx1 = check_unwrap_tx(tx1, db.clone(), &config).await;
+    let tx2 = check_unwrap_tx(tx2, db.clone(), &config).await;
+
+    // measure the max gas of the transaction with the default limits
+    let tx1_gas = TxPool::new(config.clone(), db.clone())
+        .insert_inner(tx1.clone())
+        .expect("Tx1 should be Ok, got Err")
+        .inserted
+        .max_gas();
+
+    let mut txpool = TxPool::new(
+        Config {
+            max_gas_per_owner: tx1_gas.saturating_add(1),
+            ..config
+        },
+        db.clone(),
+    );
+    txpool.insert_inner(tx1).expect("Tx1 should be Ok, got Err");
+
+    let err = txpool
+        .insert_inner(tx2)
+        .expect_err("Tx2 should be Err, got Ok");
+    assert!(matches!(
+        err.downcast_ref::<Error>(),
+        Some(Error::NotInsertedOwnerGasLimitHit(limited)) if *limited == owner
+    ));
+}
+
+#[tokio::test]
+async fn tx_limit_hit_pushes_out_cheapest_tx_of_heaviest_owner() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    let mut txpool = TxPool::new(
+        Config {
+            max_tx: 3,
+            ..Default::default()
+        },
+        db.clone(),
+    );
+
+    // the heavy owner has two transactions inside of the pool
+    let gas_coin = setup_owner_coin(&mut rng, &db);
+    let heavy_tx1 = TransactionBuilder::script(vec![], vec![])
+        .gas_price(5)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+    let gas_coin = setup_owner_coin(&mut rng, &db);
+    let heavy_tx2 = TransactionBuilder::script(vec![], vec![])
+        .gas_price(10)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+
+    // the light owner has the cheapest transaction inside of the pool
+    let (_, gas_coin) = setup_coin(&mut rng, Some(&txpool.database));
+    let light_tx = TransactionBuilder::script(vec![], vec![])
+        .gas_price(1)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+
+    let (_, gas_coin) = setup_coin(&mut rng, Some(&txpool.database));
+    let new_tx = TransactionBuilder::script(vec![], vec![])
+        .gas_price(6)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+
+    let heavy_tx1_id = heavy_tx1.id(&ChainId::default());
+    let light_tx_id = light_tx.id(&ChainId::default());
+    let heavy_tx1 = check_unwrap_tx(heavy_tx1, db.clone(), &txpool.config).await;
+    let heavy_tx2 = check_unwrap_tx(heavy_tx2, db.clone(), &txpool.config).await;
+    let light_tx = check_unwrap_tx(light_tx, db.clone(), &txpool.config).await;
+    let new_tx = check_unwrap_tx(new_tx, db.clone(), &txpool.config).await;
+
+    txpool
+        .insert_inner(heavy_tx1)
+        .expect("Heavy tx1 should be Ok, got Err");
+    txpool
+        .insert_inner(heavy_tx2)
+        .expect("Heavy tx2 should be Ok, got Err");
+    txpool
+        .insert_inner(light_tx)
+        .expect("Light tx should be Ok, got Err");
+
+    let squeezed = txpool
+        .insert_inner(new_tx)
+        .expect("New tx should be Ok, got Err");
+    assert_eq!(squeezed.removed.len(), 1);
+    assert_eq!(squeezed.removed[0].id(), heavy_tx1_id);
+    assert!(txpool.txs().contains_key(&light_tx_id));
+}
+
 #[tokio::test]
 async fn tx_depth_hit() {
     let mut rng = StdRng::seed_from_u64(0);
@@ -643,8 +908,9 @@ async fn sorted_out_tx1_2_4() {
         .finalize_as_transaction();
 
     let (_, gas_coin) = setup_coin(&mut rng, Some(&txpool.database));
+    // pays more than tx1 and tx2 together
     let tx3 = TransactionBuilder::script(vec![], vec![])
-        .gas_price(20)
+        .gas_price(30)
         .script_gas_limit(GAS_LIMIT)
         .add_input(gas_coin)
         .finalize_as_transaction();
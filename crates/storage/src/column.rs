@@ -113,6 +113,18 @@ column_definition! {
         TransactionsByOwnerBlockIdx = 24,
         /// The column of the table that stores `true` if `owner` owns `Message` with `message_id`
         OwnedMessageIds = 25,
+        /// The values of the state before the changes made by the block.
+        /// Allows restoring the state at previous heights.
+        StateHistory = 26,
+        /// The known peers of the p2p network with their addresses, scores and bans.
+        /// Allows reconnecting to the peers after the restart.
+        PeerStore = 27,
+        /// The height of the block of each pruned transaction.
+        /// Allows distinguishing the pruned transactions from the unknown ones.
+        PrunedTransactions = 28,
+        /// The relayed messages by the DA height, including the spent ones.
+        /// Allows finding the messages relayed within the range of DA heights.
+        RelayedMessages = 29,
     }
 }
 
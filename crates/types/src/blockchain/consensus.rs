@@ -10,8 +10,10 @@ use crate::{
 };
 
 // Different types of consensus are represented as separate modules
+pub mod bft;
 pub mod poa;
 
+use bft::BftConsensus;
 use poa::PoAConsensus;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -23,6 +25,8 @@ pub enum Consensus {
     Genesis(Genesis),
     /// Proof of authority consensus
     PoA(PoAConsensus),
+    /// Byzantine fault tolerant consensus
+    Bft(BftConsensus),
 }
 
 impl Consensus {
@@ -38,6 +42,9 @@ impl Consensus {
                 let address = Input::owner(&public_key);
                 Ok(address)
             }
+            Consensus::Bft(_) => Err(anyhow::anyhow!(
+                "The BFT block is committed by the validator set, not a single producer"
+            )),
         }
     }
 }
@@ -53,6 +60,8 @@ impl Default for Consensus {
 pub enum ConsensusType {
     /// Proof of authority
     PoA,
+    /// Byzantine fault tolerance
+    Bft,
 }
 
 /// A sealed entity with consensus info.
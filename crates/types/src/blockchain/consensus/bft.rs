@@ -0,0 +1,236 @@
+//! Byzantine fault tolerant consensus
+
+use crate::{
+    blockchain::{
+        block::Block,
+        primitives::BlockId,
+    },
+    fuel_crypto::{
+        Hasher,
+        Message,
+        SecretKey,
+        Signature,
+    },
+    fuel_tx::Input,
+    fuel_types::{
+        Address,
+        BlockHeight,
+        ChainId,
+    },
+};
+
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// The consensus related data that doesn't live on the
+/// header.
+pub struct BftConsensus {
+    /// The round in which validators committed the block.
+    pub round: u32,
+    /// The quorum certificate. It contains the precommit signatures of the validators
+    /// that voted for the block in the `round`.
+    pub certificate: Vec<Signature>,
+}
+
+impl BftConsensus {
+    /// Create a new block consensus.
+    pub fn new(round: u32, certificate: Vec<Signature>) -> Self {
+        Self { round, certificate }
+    }
+}
+
+/// The type of the vote.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum VoteType {
+    /// The first voting step of the round.
+    Prevote,
+    /// The second voting step of the round. The quorum of precommits commits the block.
+    Precommit,
+}
+
+/// The signed vote of the validator for the block in the round.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vote {
+    /// The height of the block.
+    pub height: BlockHeight,
+    /// The round of the voting.
+    pub round: u32,
+    /// The type of the vote.
+    pub vote_type: VoteType,
+    /// The id of the block. `None` means the vote for nothing(nil).
+    pub block_id: Option<BlockId>,
+    /// The signature of the validator.
+    pub signature: Signature,
+}
+
+impl Vote {
+    /// Creates a vote signed by the `secret` for the chain with the `chain_id`.
+    pub fn sign(
+        secret: &SecretKey,
+        chain_id: &ChainId,
+        height: BlockHeight,
+        round: u32,
+        vote_type: VoteType,
+        block_id: Option<BlockId>,
+    ) -> Self {
+        let message =
+            Self::signing_message(chain_id, height, round, vote_type, block_id.as_ref());
+        Self {
+            height,
+            round,
+            vote_type,
+            block_id,
+            signature: Signature::sign(secret, &message),
+        }
+    }
+
+    /// Returns the message signed by the validator for the vote. The `chain_id`
+    /// prevents the replay of the vote on another chain with the same validators.
+    pub fn signing_message(
+        chain_id: &ChainId,
+        height: BlockHeight,
+        round: u32,
+        vote_type: VoteType,
+        block_id: Option<&BlockId>,
+    ) -> Message {
+        let vote_type: u8 = match vote_type {
+            VoteType::Prevote => 0,
+            VoteType::Precommit => 1,
+        };
+        let mut hasher = Hasher::default();
+        hasher.input(b"fuel-bft/vote");
+        hasher.input(chain_id.to_be_bytes());
+        hasher.input(&height.to_bytes()[..]);
+        hasher.input(round.to_be_bytes());
+        hasher.input([vote_type]);
+        if let Some(block_id) = block_id {
+            hasher.input(block_id.as_slice());
+        }
+        Message::from_bytes(*hasher.digest())
+    }
+
+    /// Recovers the address of the validator that signed the vote.
+    pub fn signer(&self, chain_id: &ChainId) -> Option<Address> {
+        let message = Self::signing_message(
+            chain_id,
+            self.height,
+            self.round,
+            self.vote_type,
+            self.block_id.as_ref(),
+        );
+        recover_address(&self.signature, &message)
+    }
+}
+
+/// The signed proposal of the block for the round.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Proposal {
+    /// The round of the proposal.
+    pub round: u32,
+    /// The last round in which the block received a quorum of prevotes,
+    /// if the proposer re-proposes the block.
+    pub valid_round: Option<u32>,
+    /// The proposed block.
+    pub block: Block,
+    /// The signature of the proposer.
+    pub signature: Signature,
+}
+
+impl Proposal {
+    /// Creates a proposal signed by the `secret` for the chain with the `chain_id`.
+    pub fn sign(
+        secret: &SecretKey,
+        chain_id: &ChainId,
+        round: u32,
+        valid_round: Option<u32>,
+        block: Block,
+    ) -> Self {
+        let message = Self::signing_message(chain_id, &block.id(), round, valid_round);
+        Self {
+            round,
+            valid_round,
+            block,
+            signature: Signature::sign(secret, &message),
+        }
+    }
+
+    /// Returns the message signed by the proposer for the proposal. The `chain_id`
+    /// prevents the replay of the proposal on another chain with the same validators.
+    pub fn signing_message(
+        chain_id: &ChainId,
+        block_id: &BlockId,
+        round: u32,
+        valid_round: Option<u32>,
+    ) -> Message {
+        let mut hasher = Hasher::default();
+        hasher.input(b"fuel-bft/proposal");
+        hasher.input(chain_id.to_be_bytes());
+        hasher.input(block_id.as_slice());
+        hasher.input(round.to_be_bytes());
+        if let Some(valid_round) = valid_round {
+            hasher.input(valid_round.to_be_bytes());
+        }
+        Message::from_bytes(*hasher.digest())
+    }
+
+    /// The height of the proposed block.
+    pub fn height(&self) -> BlockHeight {
+        *self.block.header().height()
+    }
+
+    /// Recovers the address of the proposer.
+    pub fn signer(&self, chain_id: &ChainId) -> Option<Address> {
+        let message = Self::signing_message(
+            chain_id,
+            &self.block.id(),
+            self.round,
+            self.valid_round,
+        );
+        recover_address(&self.signature, &message)
+    }
+}
+
+/// The message exchanged by validators during the consensus.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ConsensusMessage {
+    /// The proposal of the block.
+    Proposal(Proposal),
+    /// The vote for the block.
+    Vote(Vote),
+}
+
+impl ConsensusMessage {
+    /// The height of the block to which the message belongs.
+    pub fn height(&self) -> BlockHeight {
+        match self {
+            ConsensusMessage::Proposal(proposal) => proposal.height(),
+            ConsensusMessage::Vote(vote) => vote.height,
+        }
+    }
+
+    /// The round to which the message belongs.
+    pub fn round(&self) -> u32 {
+        match self {
+            ConsensusMessage::Proposal(proposal) => proposal.round,
+            ConsensusMessage::Vote(vote) => vote.round,
+        }
+    }
+
+    /// Recovers the address of the validator that signed the message.
+    pub fn signer(&self, chain_id: &ChainId) -> Option<Address> {
+        match self {
+            ConsensusMessage::Proposal(proposal) => proposal.signer(chain_id),
+            ConsensusMessage::Vote(vote) => vote.signer(chain_id),
+        }
+    }
+}
+
+fn recover_address(signature: &Signature, message: &Message) -> Option<Address> {
+    signature
+        .recover(message)
+        .ok()
+        .map(|public_key| Input::owner(&public_key))
+}
@@ -1,9 +1,16 @@
 //! Types related to GraphQL API service.
 
-use crate::fuel_types::{
-    Address,
-    AssetId,
-    ContractId,
+use crate::{
+    entities::coins::coin::Coin,
+    fuel_asm::Word,
+    fuel_tx::TxId,
+    fuel_types::{
+        Address,
+        AssetId,
+        BlockHeight,
+        ContractId,
+        Nonce,
+    },
 };
 
 /// The cumulative balance(`amount`) of the `Owner` of `asset_id`.
@@ -21,3 +28,63 @@ pub type AddressBalance = Balance<Address>;
 
 /// The alias for the `Balance` of the contract.
 pub type ContractBalance = Balance<ContractId>;
+
+/// The event about the change of the coins or messages owned by the `owner`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OwnerEvent {
+    /// The owner of the coin or message.
+    pub owner: Address,
+    /// The identifier of the transaction that caused the change. It is `None`
+    /// for the messages relayed from the DA layer.
+    pub tx_id: Option<TxId>,
+    /// The height of the block that contains the change.
+    pub block_height: BlockHeight,
+    /// The change itself.
+    pub change: OwnerChange,
+}
+
+/// The change of the coins or messages owned by the user.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OwnerChange {
+    /// The transaction created a new coin.
+    CoinCreated(Coin),
+    /// The transaction spent the coin.
+    CoinSpent(Coin),
+    /// The message relayed from the DA layer became available with the block.
+    MessageCreated {
+        /// The nonce of the created message.
+        nonce: Nonce,
+        /// The amount of the base asset held by the message.
+        amount: Word,
+    },
+    /// The transaction spent the message.
+    MessageSpent {
+        /// The nonce of the spent message.
+        nonce: Nonce,
+        /// The amount of the base asset held by the message.
+        amount: Word,
+    },
+}
+
+impl OwnerChange {
+    /// Returns the amount of the asset held by the coin or message.
+    pub fn amount(&self) -> Word {
+        match self {
+            OwnerChange::CoinCreated(coin) | OwnerChange::CoinSpent(coin) => coin.amount,
+            OwnerChange::MessageCreated { amount, .. }
+            | OwnerChange::MessageSpent { amount, .. } => *amount,
+        }
+    }
+
+    /// Returns the asset id of the coin or message.
+    pub fn asset_id<'a>(&'a self, base_asset_id: &'a AssetId) -> &'a AssetId {
+        match self {
+            OwnerChange::CoinCreated(coin) | OwnerChange::CoinSpent(coin) => {
+                &coin.asset_id
+            }
+            OwnerChange::MessageCreated { .. } | OwnerChange::MessageSpent { .. } => {
+                base_asset_id
+            }
+        }
+    }
+}
@@ -1,9 +1,23 @@
 //! Contains types related to P2P data
 
 use crate::{
-    fuel_tx::Transaction,
-    fuel_types::BlockHeight,
+    blockchain::{
+        block::Block,
+        consensus::bft::ConsensusMessage,
+        SealedBlock,
+        SealedBlockHeader,
+    },
+    fuel_tx::{
+        Transaction,
+        TxId,
+        UniqueIdentifier,
+    },
+    fuel_types::{
+        BlockHeight,
+        ChainId,
+    },
 };
+use peer_reputation::AppScore;
 use std::{
     collections::HashSet,
     fmt::{
@@ -23,6 +37,75 @@ pub mod peer_reputation;
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Transactions(pub Vec<Transaction>);
 
+/// The block announced by its header and the ids of its transactions.
+/// Followers already have most of the transactions in their txpools,
+/// so only the missing ones are requested from the peer.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CompactBlock {
+    /// The sealed header of the block.
+    pub header: SealedBlockHeader,
+    /// The ids of all transactions of the block in order.
+    pub tx_ids: Vec<TxId>,
+    /// The transactions that are never in the txpool, like `Mint`,
+    /// along with their index in the block.
+    pub prefilled: Vec<(u32, Transaction)>,
+}
+
+impl CompactBlock {
+    /// Creates the compact representation of the `block`.
+    pub fn new(block: &SealedBlock, chain_id: &ChainId) -> Self {
+        let transactions = block.entity.transactions();
+        let tx_ids = transactions.iter().map(|tx| tx.id(chain_id)).collect();
+        let prefilled = (0u32..)
+            .zip(transactions)
+            .filter(|(_, tx)| matches!(tx, Transaction::Mint(_)))
+            .map(|(index, tx)| (index, tx.clone()))
+            .collect();
+        Self {
+            header: SealedBlockHeader {
+                entity: block.entity.header().clone(),
+                consensus: block.consensus.clone(),
+            },
+            tx_ids,
+            prefilled,
+        }
+    }
+
+    /// Returns the ids of the transactions that are not prefilled.
+    pub fn pooled_tx_ids(&self) -> Vec<TxId> {
+        (0u32..)
+            .zip(&self.tx_ids)
+            .filter(|(index, _)| !self.prefilled.iter().any(|(i, _)| i == index))
+            .map(|(_, tx_id)| *tx_id)
+            .collect()
+    }
+
+    /// Rebuilds the block from the `transactions` ordered as [`Self::pooled_tx_ids`].
+    /// Returns `None` if the transactions don't match the header.
+    pub fn into_block(self, transactions: Vec<Transaction>) -> Option<SealedBlock> {
+        let mut prefilled = self.prefilled.into_iter().peekable();
+        let mut pooled = transactions.into_iter();
+        let mut block_transactions = Vec::with_capacity(self.tx_ids.len());
+        for (index, _) in (0u32..).zip(&self.tx_ids) {
+            let tx = match prefilled.next_if(|(i, _)| *i == index) {
+                Some((_, tx)) => tx,
+                None => pooled.next()?,
+            };
+            block_transactions.push(tx);
+        }
+        if prefilled.next().is_some() || pooled.next().is_some() {
+            return None
+        }
+
+        let block = Block::try_from_executed(self.header.entity, block_transactions)?;
+        Some(SealedBlock {
+            entity: block,
+            consensus: self.header.consensus,
+        })
+    }
+}
+
 /// Lightweight representation of gossipped data that only includes IDs
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -63,6 +146,12 @@ pub struct GossipData<T> {
 /// Transactions gossiped by peers for inclusion into a block
 pub type TransactionGossipData = GossipData<Transaction>;
 
+/// Consensus messages gossiped by validators
+pub type ConsensusGossipData = GossipData<ConsensusMessage>;
+
+/// Compact blocks gossiped by the block producer
+pub type BlockGossipData = GossipData<CompactBlock>;
+
 #[derive(Default, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 /// The source of some network data.
 pub struct SourcePeer<T> {
@@ -183,6 +272,22 @@ pub struct PeerInfo {
     pub app_score: f64,
 }
 
+/// The known peer persisted across the restarts of the node
+#[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PeerRecord {
+    /// the known multi-addresses of the peer in the binary format
+    pub addresses: Vec<Vec<u8>>,
+    /// the last time the peer was seen connected, as seconds since the Unix epoch
+    pub last_seen: u64,
+    /// the last application reputation score of the peer
+    pub app_score: AppScore,
+    /// whether the peer is banned
+    pub banned: bool,
+    /// the end of the ban as seconds since the Unix epoch, the ban is permanent if not set
+    pub banned_until: Option<u64>,
+}
+
 /// Contains information from the most recent heartbeat received by the peer
 pub struct HeartbeatData {
     /// The currently reported block height of the peer
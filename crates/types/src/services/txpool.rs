@@ -23,6 +23,7 @@ use crate::{
         UtxoId,
     },
     fuel_types::{
+        Address,
         ContractId,
         Nonce,
     },
@@ -293,11 +294,25 @@ pub enum Error {
     NotInsertedMaxDepth,
     #[error("Transaction exceeds the max gas per block limit. Tx gas: {tx_gas}, block limit {block_limit}")]
     NotInsertedMaxGasLimit { tx_gas: Word, block_limit: Word },
+    #[error(
+        "Transaction is not inserted. Replacing tx {0:#x} requires a gas price of at least {1}"
+    )]
+    NotInsertedReplacementUnderpriced(TxId, Word),
+    #[error("Transaction is not inserted. The fee {fee} doesn't exceed the fee {replaced_fee} of the replaced transactions and their dependents")]
+    NotInsertedReplacementFeeTooLow { fee: Word, replaced_fee: Word },
+    #[error("Transaction is not inserted. The owner {0:#x} has reached the limit of transactions in the pool")]
+    NotInsertedOwnerTxLimitHit(Address),
+    #[error("Transaction is not inserted. The owner {0:#x} has reached the limit of gas in the pool")]
+    NotInsertedOwnerGasLimitHit(Address),
     // small todo for now it can pass but in future we should include better messages
     #[error("Transaction removed.")]
     Removed,
     #[error("Transaction expired because it exceeded the configured time to live `tx-pool-ttl`.")]
     TTLReason,
+    #[error("Transaction is replaced by the tx {0:#x} that pays a higher fee.")]
+    ReplacedByFee(TxId),
+    #[error("Transaction is removed because the tx it depends on is replaced by the tx {0:#x}.")]
+    DependencyReplacedByFee(TxId),
     #[error("Transaction squeezed out because {0}")]
     SqueezedOut(String),
     // TODO: We need it for now until channels are removed from TxPool.
@@ -0,0 +1,145 @@
+use fuel_core::p2p_test_helpers::*;
+use fuel_core_client::client::{
+    types::Consensus as ClientConsensus,
+    FuelClient,
+};
+use fuel_core_poa::ports::BlockImporter;
+use fuel_core_types::{
+    blockchain::consensus::Consensus,
+    fuel_crypto::SecretKey,
+    fuel_types::BlockHeight,
+};
+use futures::StreamExt;
+use rand::{
+    rngs::StdRng,
+    SeedableRng,
+};
+use std::time::Duration;
+
+const BLOCK_TIME: Duration = Duration::from_millis(200);
+
+async fn wait_for_height(node: &Node, height: BlockHeight) {
+    let mut stream = node.node.shared.block_importer.block_stream();
+    tokio::time::timeout(Duration::from_secs(60), async {
+        while node.db.latest_height().unwrap() < height {
+            stream.next().await;
+        }
+    })
+    .await
+    .unwrap_or_else(|_| {
+        panic!("{:?} failed to reach the height {height}", node.config.name)
+    });
+}
+
+fn assert_same_bft_blocks(nodes: &[Node], height: BlockHeight) {
+    let validators: Vec<_> = match &nodes[0].config.chain_conf.consensus {
+        fuel_core::chain_config::ConsensusConfig::Bft { validators } => {
+            validators.clone()
+        }
+        _ => panic!("Expected the BFT consensus config"),
+    };
+
+    for height in 1..=*height {
+        let height = BlockHeight::from(height);
+        let blocks: Vec<_> = nodes
+            .iter()
+            .map(|node| {
+                node.db
+                    .get_sealed_block_by_height(&height)
+                    .unwrap()
+                    .unwrap()
+            })
+            .collect();
+
+        for block in &blocks {
+            assert_eq!(block.entity.id(), blocks[0].entity.id());
+            match &block.consensus {
+                Consensus::Bft(consensus) => {
+                    assert!(fuel_core_bft::verifier::verify_consensus(
+                        &nodes[0].config.chain_conf.consensus_parameters.chain_id,
+                        &validators,
+                        block.entity.header(),
+                        consensus,
+                    ));
+                }
+                _ => panic!("Expected the BFT consensus for the block {height}"),
+            }
+        }
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn bft_validators_commit_same_blocks() {
+    let mut rng = StdRng::seed_from_u64(line!() as u64);
+    let secrets: Vec<_> = (0..4).map(|_| SecretKey::random(&mut rng)).collect();
+
+    let BftNodes {
+        validators,
+        bootstrap: _dont_drop,
+    } = make_bft_nodes(secrets, BLOCK_TIME, None).await;
+
+    let height = BlockHeight::from(5);
+    for validator in &validators {
+        wait_for_height(validator, height).await;
+    }
+
+    assert_same_bft_blocks(&validators, height);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn bft_consensus_is_returned_by_the_client() {
+    let mut rng = StdRng::seed_from_u64(line!() as u64);
+    let secrets: Vec<_> = (0..4).map(|_| SecretKey::random(&mut rng)).collect();
+
+    let BftNodes {
+        validators,
+        bootstrap: _dont_drop,
+    } = make_bft_nodes(secrets, BLOCK_TIME, None).await;
+
+    let height = BlockHeight::from(1);
+    let validator = &validators[0];
+    wait_for_height(validator, height).await;
+
+    let expected = match validator
+        .db
+        .get_sealed_block_by_height(&height)
+        .unwrap()
+        .unwrap()
+        .consensus
+    {
+        Consensus::Bft(consensus) => consensus,
+        _ => panic!("Expected the BFT consensus for the block {height}"),
+    };
+    let client = FuelClient::from(validator.node.bound_address);
+    let block = client.block_by_height(*height).await.unwrap().unwrap();
+    match block.consensus {
+        ClientConsensus::BftConsensus(consensus) => {
+            assert_eq!(consensus.round, expected.round);
+            assert_eq!(consensus.certificate, expected.certificate);
+        }
+        _ => panic!("Expected the BFT consensus from the client"),
+    }
+    assert_eq!(block.block_producer, None);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn bft_validators_make_progress_with_one_validator_offline() {
+    let mut rng = StdRng::seed_from_u64(line!() as u64);
+    let secrets: Vec<_> = (0..4).map(|_| SecretKey::random(&mut rng)).collect();
+
+    let BftNodes {
+        mut validators,
+        bootstrap: _dont_drop,
+    } = make_bft_nodes(secrets, BLOCK_TIME, None).await;
+
+    // Shut down one of four validators, the rest are still a quorum.
+    let mut offline_validator = validators.pop().unwrap();
+    offline_validator.shutdown().await;
+
+    let height = BlockHeight::from(5);
+    for validator in &validators {
+        wait_for_height(validator, height).await;
+    }
+
+    assert_same_bft_blocks(&validators, height);
+}
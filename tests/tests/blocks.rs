@@ -31,6 +31,7 @@ use fuel_core_types::{
     secrecy::ExposeSecret,
     tai64::Tai64,
 };
+use futures::StreamExt;
 use itertools::{
     rev,
     Itertools,
@@ -149,6 +150,54 @@ async fn produce_block_manually() {
     assert_eq!(*actual_pub_key, expected_pub_key);
 }
 
+#[tokio::test]
+async fn subscribe_blocks_replays_history_and_streams_new_blocks() {
+    let srv = FuelService::from_database(Database::default(), Config::local_node())
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    client.produce_blocks(3, None).await.unwrap();
+    let stream = client.subscribe_blocks(Some(1u32.into())).await.unwrap();
+    client.produce_blocks(2, None).await.unwrap();
+
+    let heights: Vec<u32> = tokio::time::timeout(
+        Duration::from_secs(10),
+        stream
+            .take(5)
+            .map(|block| block.unwrap().header.height)
+            .collect::<Vec<_>>(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(heights, vec![1, 2, 3, 4, 5]);
+}
+
+#[tokio::test]
+async fn subscribe_blocks_without_height_starts_from_next_block() {
+    let srv = FuelService::from_database(Database::default(), Config::local_node())
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    client.produce_blocks(2, None).await.unwrap();
+    let mut stream = Box::pin(client.subscribe_blocks(None).await.unwrap());
+    // Establish the subscription before producing new blocks.
+    let next_block = tokio::spawn(async move { stream.next().await });
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    client.produce_blocks(1, None).await.unwrap();
+
+    let block = tokio::time::timeout(Duration::from_secs(10), next_block)
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(block.header.height, 3);
+}
+
 #[tokio::test]
 async fn produce_block_negative() {
     let db = Database::default();
@@ -0,0 +1,53 @@
+use fuel_core::service::{
+    Config,
+    FuelService,
+};
+use fuel_core_client::client::{
+    types::GasPriceEstimate,
+    FuelClient,
+};
+use fuel_core_types::fuel_tx::TransactionBuilder;
+
+#[tokio::test]
+async fn estimate_gas_price_without_transactions_is_min_gas_price() {
+    let mut config = Config::local_node();
+    config.txpool.min_gas_price = 7;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let GasPriceEstimate { low, medium, high } =
+        client.estimate_gas_price(None).await.unwrap();
+
+    assert_eq!((low, medium, high), (7, 7, 7));
+}
+
+#[tokio::test]
+async fn estimate_gas_price_uses_prices_of_included_transactions() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    for gas_price in [10, 20, 30] {
+        let tx = TransactionBuilder::script(vec![], vec![])
+            .gas_price(gas_price)
+            .script_gas_limit(10000)
+            .add_random_fee_input()
+            .finalize_as_transaction();
+        client.submit_and_await_commit(&tx).await.unwrap();
+    }
+
+    let GasPriceEstimate { low, medium, high } =
+        client.estimate_gas_price(Some(10)).await.unwrap();
+
+    // With three samples, the 90th percentile is the median one.
+    assert_eq!((low, medium, high), (10, 20, 20));
+}
+
+#[tokio::test]
+async fn estimate_gas_price_rejects_too_big_block_horizon() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let result = client.estimate_gas_price(Some(1000)).await;
+
+    assert!(result.is_err());
+}
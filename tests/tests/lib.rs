@@ -2,6 +2,8 @@
 #![deny(warnings)]
 
 mod balances;
+#[cfg(feature = "p2p")]
+mod bft;
 mod blocks;
 mod chain;
 mod coin;
@@ -11,12 +13,15 @@ mod dap;
 mod debugger;
 mod deployment;
 mod fee_collection_contract;
+mod gas_price;
 mod health;
 mod helpers;
 mod messages;
 mod metrics;
 mod node_info;
+mod owner_events;
 mod poa;
+mod pruning;
 #[cfg(feature = "relayer")]
 mod relayer;
 mod snapshot;
@@ -0,0 +1,110 @@
+use fuel_core::service::{
+    Config,
+    FuelService,
+};
+use fuel_core_client::client::{
+    types::{
+        OwnerChange,
+        OwnerEvent,
+    },
+    FuelClient,
+};
+use fuel_core_types::{
+    fuel_tx::{
+        Address,
+        AssetId,
+        Input,
+        Output,
+        TransactionBuilder,
+        UniqueIdentifier,
+        UtxoId,
+    },
+    fuel_types::ChainId,
+};
+use futures::StreamExt;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+
+async fn collect_events(
+    client: &FuelClient,
+    owner: Address,
+    asset_id: Option<AssetId>,
+    count: usize,
+) -> JoinHandle<Vec<OwnerEvent>> {
+    let stream = client
+        .subscribe_owner_events(&owner, asset_id.as_ref())
+        .await
+        .unwrap();
+    tokio::spawn(async move {
+        stream
+            .take(count)
+            .map(|event| event.unwrap())
+            .collect()
+            .await
+    })
+}
+
+#[tokio::test]
+async fn owner_events_contain_spent_and_created_coins() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let owner = Address::new([1; 32]);
+    let recipient = Address::new([2; 32]);
+    let asset_id = AssetId::BASE;
+    let spent_utxo_id = UtxoId::new([3; 32].into(), 0);
+
+    let owner_events = collect_events(&client, owner, None, 2).await;
+    let recipient_events = collect_events(&client, recipient, Some(asset_id), 1).await;
+    // Give subscriptions time to be established before the block is produced.
+    tokio::time::sleep(Duration::from_millis(500)).await;
+
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(1_000_000)
+        .add_input(Input::coin_signed(
+            spent_utxo_id,
+            owner,
+            100,
+            asset_id,
+            Default::default(),
+            0,
+            Default::default(),
+        ))
+        .add_output(Output::coin(recipient, 10, asset_id))
+        .add_output(Output::change(owner, 0, asset_id))
+        .add_witness(Default::default())
+        .finalize_as_transaction();
+    let tx_id = tx.id(&ChainId::default());
+    client.submit_and_await_commit(&tx).await.unwrap();
+
+    let owner_events = tokio::time::timeout(Duration::from_secs(10), owner_events)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(owner_events.len(), 2);
+    assert!(owner_events.iter().all(|event| event.owner == owner
+        && event.transaction_id == Some(tx_id)
+        && event.block_height == 1));
+    assert_eq!(
+        owner_events[0].change,
+        OwnerChange::CoinSpent(spent_utxo_id)
+    );
+    assert_eq!(owner_events[0].amount, 100);
+    assert_eq!(
+        owner_events[1].change,
+        OwnerChange::CoinCreated(UtxoId::new(tx_id, 1))
+    );
+
+    let recipient_events =
+        tokio::time::timeout(Duration::from_secs(10), recipient_events)
+            .await
+            .unwrap()
+            .unwrap();
+    assert_eq!(recipient_events.len(), 1);
+    assert_eq!(
+        recipient_events[0].change,
+        OwnerChange::CoinCreated(UtxoId::new(tx_id, 0))
+    );
+    assert_eq!(recipient_events[0].amount, 10);
+    assert_eq!(recipient_events[0].asset_id, asset_id);
+}
@@ -0,0 +1,69 @@
+use fuel_core::{
+    database::Database,
+    service::{
+        Config,
+        FuelService,
+    },
+};
+use fuel_core_client::client::FuelClient;
+use fuel_core_types::{
+    fuel_tx::{
+        TransactionBuilder,
+        UniqueIdentifier,
+    },
+    fuel_types::{
+        BlockHeight,
+        ChainId,
+    },
+};
+use std::time::Duration;
+
+#[tokio::test]
+async fn pruned_blocks_return_pruned_error() {
+    let db = Database::default();
+    let mut config = Config::local_node();
+    config.block_importer.pruning_depth = Some(2);
+
+    let srv = FuelService::from_database(db.clone(), config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Each transaction is included into its own block.
+    let mut tx_ids = vec![];
+    for _ in 0..5 {
+        let tx = TransactionBuilder::script(vec![], vec![])
+            .add_random_fee_input()
+            .script_gas_limit(1_000_000)
+            .finalize_as_transaction();
+        client.submit_and_await_commit(&tx).await.unwrap();
+        tx_ids.push(tx.id(&ChainId::default()));
+    }
+
+    // The blocks are pruned during the import of the new blocks.
+    assert_eq!(db.pruned_below().unwrap(), Some(BlockHeight::from(3)));
+
+    let error = client.block_by_height(1).await.unwrap_err();
+    assert!(error.to_string().contains("pruned"), "{error}");
+
+    // The pruned transactions are not confused with the unknown ones.
+    let error = client.transaction(&tx_ids[0]).await.unwrap_err();
+    assert!(error.to_string().contains("pruned"), "{error}");
+    let unknown_tx = client.transaction(&[0; 32].into()).await.unwrap();
+    assert!(unknown_tx.is_none());
+
+    // The off-chain worker prunes the receipts after it processes the block.
+    tokio::time::timeout(Duration::from_secs(10), async {
+        while client.receipts(&tx_ids[0]).await.is_ok() {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("The receipts should be pruned");
+    let error = client.receipts(&tx_ids[0]).await.unwrap_err();
+    assert!(error.to_string().contains("pruned"), "{error}");
+
+    // The latest blocks keep their transactions: the script and the mint.
+    let block = client.block_by_height(5).await.unwrap().unwrap();
+    assert_eq!(block.transactions.len(), 2);
+}
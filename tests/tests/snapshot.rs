@@ -4,15 +4,25 @@ use fuel_core::{
         ContractConfig,
         MessageConfig,
         StateConfig,
+        StateEntry,
+        StateReader,
+        StateWriter,
     },
     database::Database,
     service::{
         Config,
         FuelService,
+        ServiceTrait,
     },
 };
+use fuel_core_client::client::FuelClient;
 use fuel_core_types::{
     blockchain::primitives::DaBlockHeight,
+    fuel_tx::{
+        Input,
+        Output,
+        TransactionBuilder,
+    },
     fuel_types::{
         BlockHeight,
         Nonce,
@@ -34,7 +44,229 @@ async fn snapshot_state_config() {
 
     // setup config
     let mut config = Config::local_node();
-    let starting_state = StateConfig {
+    let starting_state = test_state_config(&mut rng, owner);
+
+    config.chain_conf.initial_state = Some(starting_state.clone());
+
+    // setup server & client
+    let _ = FuelService::from_database(db.clone(), config)
+        .await
+        .unwrap();
+
+    let state_conf = StateConfig::generate_state_config(db).unwrap();
+
+    // initial state
+
+    let starting_coin = starting_state.clone().coins.unwrap();
+
+    let state_coin = state_conf.clone().coins.unwrap();
+
+    for i in 0..starting_coin.len() {
+        // all values are checked except tx_id and output_index as those are generated and not
+        // known at initialization
+        assert_eq!(state_coin[i].owner, starting_coin[i].owner);
+        assert_eq!(state_coin[i].asset_id, starting_coin[i].asset_id);
+        assert_eq!(state_coin[i].amount, starting_coin[i].amount);
+        assert_eq!(
+            state_coin[i].tx_pointer_block_height,
+            starting_coin[i].tx_pointer_block_height
+        );
+        assert_eq!(state_coin[i].maturity, starting_coin[i].maturity);
+    }
+
+    assert_eq!(state_conf.height, starting_state.height);
+
+    assert_eq!(state_conf.contracts, starting_state.contracts);
+
+    assert_eq!(state_conf.messages, starting_state.messages)
+}
+
+#[tokio::test]
+async fn snapshot_state_file_recreates_state() {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let db = Database::default();
+
+    let mut config = Config::local_node();
+    let starting_state = test_state_config(&mut rng, Address::default());
+    config.chain_conf.initial_state = Some(starting_state);
+
+    let _ = FuelService::from_database(db.clone(), config.clone())
+        .await
+        .unwrap();
+
+    // Write the state into the file in the streaming format.
+    let state_file = tempfile::NamedTempFile::new().unwrap();
+    let mut writer = StateWriter::new(state_file.reopen().unwrap());
+    let height = StateConfig::write_state_config(&db, &mut writer).unwrap();
+    writer.finish().unwrap();
+
+    // The storage and balances of the contract are separate entries.
+    let entries = StateReader::new(state_file.reopen().unwrap())
+        .collect::<anyhow::Result<Vec<_>>>()
+        .unwrap();
+    let contracts: Vec<_> = entries
+        .iter()
+        .filter_map(|entry| match entry {
+            StateEntry::Contract(contract) => Some(contract),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(contracts.len(), 1);
+    assert_eq!(contracts[0].state, None);
+    assert_eq!(contracts[0].balances, None);
+    let state_entries = entries
+        .iter()
+        .filter(|entry| matches!(entry, StateEntry::ContractState(state) if state.contract_id == contracts[0].contract_id))
+        .count();
+    let balance_entries = entries
+        .iter()
+        .filter(|entry| matches!(entry, StateEntry::ContractBalance(balance) if balance.contract_id == contracts[0].contract_id))
+        .count();
+    assert_eq!(state_entries, 2);
+    assert_eq!(balance_entries, 2);
+
+    // Start a new node from the state file.
+    let forked_db = Database::default();
+    let mut forked_config = config;
+    forked_config.chain_conf.initial_state = Some(StateConfig {
+        height: Some(height),
+        ..Default::default()
+    });
+    forked_config.genesis_state = Some(state_file.path().to_path_buf());
+    let _ = FuelService::from_database(forked_db.clone(), forked_config)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        StateConfig::generate_state_config(forked_db).unwrap(),
+        StateConfig::generate_state_config(db).unwrap()
+    );
+}
+
+#[tokio::test]
+async fn snapshot_state_at_height_ignores_later_blocks() {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let db = Database::default();
+
+    let mut config = Config::local_node();
+    config.block_importer.state_history = true;
+    let mut initial_state = test_state_config(&mut rng, Address::default());
+    // Messages above the DA height of the block are not a part of its state.
+    for message in initial_state.messages.iter_mut().flatten() {
+        message.da_height = DaBlockHeight(0);
+    }
+    config.chain_conf.initial_state = Some(initial_state);
+
+    let srv = FuelService::from_database(db.clone(), config)
+        .await
+        .unwrap();
+    let genesis_height = db.latest_height().unwrap();
+    let genesis_state = StateConfig::generate_state_config(db.clone()).unwrap();
+
+    // The new block adds a coin to the state.
+    let client = FuelClient::from(srv.bound_address);
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .add_random_fee_input()
+        .script_gas_limit(1_000_000)
+        .add_output(Output::coin(rng.gen(), 100, AssetId::BASE))
+        .finalize_as_transaction();
+    client.submit_and_await_commit(&tx).await.unwrap();
+    let latest_state = StateConfig::generate_state_config(db.clone()).unwrap();
+    assert_ne!(latest_state.coins, genesis_state.coins);
+
+    let state_at_genesis = db.state_at_height(genesis_height).unwrap();
+    assert_eq!(
+        StateConfig::generate_state_config(state_at_genesis).unwrap(),
+        genesis_state
+    );
+}
+
+#[tokio::test]
+async fn snapshot_state_at_height_keeps_messages_spent_later() {
+    let db = Database::default();
+    let message = MessageConfig {
+        sender: Address::from([1; 32]),
+        recipient: Address::from([2; 32]),
+        nonce: 1.into(),
+        amount: 1_000,
+        data: vec![],
+        da_height: DaBlockHeight(0),
+    };
+
+    let mut config = Config::local_node();
+    config.block_importer.state_history = true;
+    config.chain_conf.initial_state = Some(StateConfig {
+        messages: Some(vec![message.clone()]),
+        ..Default::default()
+    });
+
+    let srv = FuelService::from_database(db.clone(), config)
+        .await
+        .unwrap();
+    let genesis_height = db.latest_height().unwrap();
+
+    // The new block spends the message.
+    let client = FuelClient::from(srv.bound_address);
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(1_000_000)
+        .add_input(Input::message_coin_signed(
+            message.sender,
+            message.recipient,
+            message.amount,
+            message.nonce,
+            Default::default(),
+        ))
+        .add_output(Output::coin(
+            Address::from([3; 32]),
+            message.amount,
+            AssetId::BASE,
+        ))
+        .finalize_as_transaction();
+    client.submit_and_await_commit(&tx).await.unwrap();
+    let latest_state = StateConfig::generate_state_config(db.clone()).unwrap();
+    assert_eq!(latest_state.messages, Some(vec![]));
+
+    let state_at_genesis = db.state_at_height(genesis_height).unwrap();
+    assert_eq!(
+        StateConfig::generate_state_config(state_at_genesis)
+            .unwrap()
+            .messages,
+        Some(vec![message])
+    );
+}
+
+#[tokio::test]
+async fn snapshot_state_at_height_fails_before_the_gap_in_history() {
+    let db = Database::default();
+    let config = Config::local_node();
+
+    // Produces one block with the state history enabled or disabled and stops the node.
+    let produce_block = |state_history: bool| {
+        let db = db.clone();
+        let mut config = config.clone();
+        config.block_importer.state_history = state_history;
+        async move {
+            let srv = FuelService::from_database(db, config).await.unwrap();
+            let client = FuelClient::from(srv.bound_address);
+            client.produce_blocks(1, None).await.unwrap();
+            srv.stop_and_await().await.unwrap();
+        }
+    };
+
+    produce_block(true).await;
+    let before_gap = db.latest_height().unwrap();
+    produce_block(false).await;
+    assert_eq!(db.state_history_start().unwrap(), None);
+    let gap = db.latest_height().unwrap();
+    produce_block(true).await;
+    produce_block(true).await;
+
+    assert!(db.state_at_height(before_gap).is_err());
+    assert!(db.state_at_height(gap).is_ok());
+}
+
+fn test_state_config(rng: &mut StdRng, owner: Address) -> StateConfig {
+    StateConfig {
         height: Some(BlockHeight::from(10)),
         contracts: Some(vec![ContractConfig {
             contract_id: [11; 32].into(),
@@ -80,39 +312,5 @@ async fn snapshot_state_config() {
             data: vec![],
             da_height: DaBlockHeight(rng.gen_range(0..1000)),
         }]),
-    };
-
-    config.chain_conf.initial_state = Some(starting_state.clone());
-
-    // setup server & client
-    let _ = FuelService::from_database(db.clone(), config)
-        .await
-        .unwrap();
-
-    let state_conf = StateConfig::generate_state_config(db).unwrap();
-
-    // initial state
-
-    let starting_coin = starting_state.clone().coins.unwrap();
-
-    let state_coin = state_conf.clone().coins.unwrap();
-
-    for i in 0..starting_coin.len() {
-        // all values are checked except tx_id and output_index as those are generated and not
-        // known at initialization
-        assert_eq!(state_coin[i].owner, starting_coin[i].owner);
-        assert_eq!(state_coin[i].asset_id, starting_coin[i].asset_id);
-        assert_eq!(state_coin[i].amount, starting_coin[i].amount);
-        assert_eq!(
-            state_coin[i].tx_pointer_block_height,
-            starting_coin[i].tx_pointer_block_height
-        );
-        assert_eq!(state_coin[i].maturity, starting_coin[i].maturity);
     }
-
-    assert_eq!(state_conf.height, starting_state.height);
-
-    assert_eq!(state_conf.contracts, starting_state.contracts);
-
-    assert_eq!(state_conf.messages, starting_state.messages)
 }
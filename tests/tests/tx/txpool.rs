@@ -4,6 +4,14 @@ use crate::helpers::{
     TestContext,
     TestSetupBuilder,
 };
+use fuel_core_client::client::{
+    pagination::{
+        PageDirection,
+        PaginationRequest,
+    },
+    types::PoolTransactionsOrder,
+};
+use fuel_core_poa::Trigger;
 use fuel_core_types::{
     fuel_asm::*,
     fuel_crypto::*,
@@ -75,3 +83,115 @@ async fn txs_max_script_gas_limit() {
         transactions.len() + 1 // coinbase
     )
 }
+
+#[tokio::test]
+async fn pool_transactions_are_listed_by_gas_price_and_filtered_by_owner() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let mut test_builder = TestSetupBuilder::new(2322);
+    // keep the transactions inside of the txpool
+    test_builder.trigger = Trigger::Never;
+
+    let owner_key = SecretKey::random(&mut rng);
+    let owner = Input::owner(&owner_key.public_key());
+    let transactions = [
+        (1, owner_key),
+        (2, SecretKey::random(&mut rng)),
+        (3, owner_key),
+    ]
+    .into_iter()
+    .map(|(gas_price, key)| {
+        TransactionBuilder::script(
+            op::ret(RegId::ONE).to_bytes().into_iter().collect(),
+            vec![],
+        )
+        .script_gas_limit(10_000)
+        .gas_price(gas_price)
+        .add_unsigned_coin_input(
+            key,
+            rng.gen(),
+            1000,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        )
+        .finalize()
+    })
+    .collect_vec();
+    test_builder.config_coin_inputs_from_transactions(&transactions.iter().collect_vec());
+    let TestContext { client, .. } = test_builder.finalize().await;
+
+    let ids = transactions
+        .into_iter()
+        .map(|script| {
+            let tx = fuel_tx::Transaction::from(script);
+            let id = tx.id(&Default::default());
+            (tx, id)
+        })
+        .collect_vec();
+    for (tx, _) in ids.iter() {
+        client.submit(tx).await.unwrap();
+    }
+    let ids = ids.into_iter().map(|(_, id)| id).collect_vec();
+    let listed_ids = |txs: Vec<fuel_core_client::client::types::TransactionResponse>| {
+        txs.into_iter()
+            .map(|response| response.transaction.id(&Default::default()))
+            .collect_vec()
+    };
+
+    // first page, from the highest gas price
+    let page = client
+        .pool_transactions(
+            PoolTransactionsOrder::GasPrice,
+            None,
+            PaginationRequest {
+                cursor: None,
+                results: 2,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await
+        .unwrap();
+    assert!(page.has_next_page);
+    assert_eq!(listed_ids(page.results), vec![ids[2], ids[1]]);
+
+    // second page
+    let page = client
+        .pool_transactions(
+            PoolTransactionsOrder::GasPrice,
+            None,
+            PaginationRequest {
+                cursor: page.cursor,
+                results: 2,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await
+        .unwrap();
+    assert!(!page.has_next_page);
+    assert_eq!(listed_ids(page.results), vec![ids[0]]);
+
+    // only transactions of the owner, from the oldest
+    let page = client
+        .pool_transactions(
+            PoolTransactionsOrder::ArrivalTime,
+            Some(&owner),
+            PaginationRequest {
+                cursor: None,
+                results: 10,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(listed_ids(page.results), vec![ids[0], ids[2]]);
+
+    let stats = client.pool_stats().await.unwrap();
+    assert_eq!(stats.tx_count, 3);
+    assert!(stats.total_consumable_gas > 0);
+    let histogram = stats
+        .gas_price_histogram
+        .into_iter()
+        .map(|bucket| (bucket.gas_price, bucket.tx_count))
+        .collect_vec();
+    assert_eq!(histogram, vec![(3, 1), (2, 1), (1, 1)]);
+}